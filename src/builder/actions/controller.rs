@@ -1,15 +1,17 @@
-//! Controller action builders (ActivateControllerAction, AssignControllerAction)
+//! Controller action builders (ActivateControllerAction, AssignControllerAction,
+//! OverrideControllerValueAction)
 
 use crate::builder::actions::base::{ActionBuilder, ManeuverAction};
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
     actions::control::{
-        ActivateControllerAction, AssignControllerAction, ControllerAction, ManualGear,
-        OverrideBrakeAction, OverrideClutchAction, OverrideGearAction, OverrideParkingBrakeAction,
+        ActivateControllerAction, AssignControllerAction, AutomaticGearType, BrakeInput,
+        ControllerAction, Gear, OverrideBrakeAction, OverrideClutchAction,
+        OverrideControllerValueAction, OverrideGearAction, OverrideParkingBrakeAction,
         OverrideSteeringWheelAction, OverrideThrottleAction,
     },
     actions::wrappers::PrivateAction,
-    basic::{Boolean, Double, Int, Value},
+    basic::{Boolean, Double, Value},
     controllers::Controller,
     enums::ControllerType,
 };
@@ -90,12 +92,7 @@ impl ActionBuilder for ActivateControllerActionBuilder {
 
         Ok(PrivateAction::ControllerAction(ControllerAction {
             assign_controller_action: None,
-            override_throttle_action: None,
-            override_brake_action: None,
-            override_clutch_action: None,
-            override_parking_brake_action: None,
-            override_steering_wheel_action: None,
-            override_gear_action: None,
+            override_controller_value_action: None,
             activate_controller_action: Some(activate_action),
         }))
     }
@@ -160,12 +157,7 @@ impl ActionBuilder for AssignControllerActionBuilder {
 
         Ok(PrivateAction::ControllerAction(ControllerAction {
             assign_controller_action: Some(assign_action),
-            override_throttle_action: None,
-            override_brake_action: None,
-            override_clutch_action: None,
-            override_parking_brake_action: None,
-            override_steering_wheel_action: None,
-            override_gear_action: None,
+            override_controller_value_action: None,
             activate_controller_action: None,
         }))
     }
@@ -186,6 +178,166 @@ impl ManeuverAction for AssignControllerActionBuilder {
     }
 }
 
+/// Builder for override controller value actions
+///
+/// Each input is overridden independently with its own `active` flag;
+/// passing `false` releases a previous override of that input. Inputs that
+/// are not set keep their current state.
+#[derive(Debug, Default)]
+pub struct OverrideControllerValueActionBuilder {
+    entity_ref: Option<String>,
+    overrides: OverrideControllerValueAction,
+}
+
+impl OverrideControllerValueActionBuilder {
+    /// Create new override controller value action builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set target entity for this action
+    pub fn for_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Override the throttle pedal position (0..1)
+    pub fn throttle(mut self, active: bool, value: f64) -> Self {
+        self.overrides.throttle = Some(OverrideThrottleAction {
+            active: Boolean::literal(active),
+            value: Double::literal(value),
+            max_rate: None,
+        });
+        self
+    }
+
+    /// Override the brake pedal position (0..1)
+    pub fn brake(mut self, active: bool, value: f64) -> Self {
+        self.overrides.brake = Some(OverrideBrakeAction {
+            active: Boolean::literal(active),
+            value: None,
+            brake_input: Some(BrakeInput::percent(value)),
+        });
+        self
+    }
+
+    /// Override the clutch pedal position (0..1)
+    pub fn clutch(mut self, active: bool, value: f64) -> Self {
+        self.overrides.clutch = Some(OverrideClutchAction {
+            active: Boolean::literal(active),
+            value: Double::literal(value),
+            max_rate: None,
+        });
+        self
+    }
+
+    /// Override the parking brake position (0..1)
+    pub fn parking_brake(mut self, active: bool, value: f64) -> Self {
+        self.overrides.parking_brake = Some(OverrideParkingBrakeAction {
+            active: Boolean::literal(active),
+            value: None,
+            brake_input: Some(BrakeInput::percent(value)),
+        });
+        self
+    }
+
+    /// Override the steering wheel angle in radians
+    pub fn steering_wheel(mut self, active: bool, angle: f64) -> Self {
+        self.overrides.steering_wheel = Some(OverrideSteeringWheelAction {
+            active: Boolean::literal(active),
+            value: Double::literal(angle),
+            max_rate: None,
+            max_torque: None,
+        });
+        self
+    }
+
+    /// Override the gear of a manual transmission
+    pub fn manual_gear(mut self, active: bool, gear: i32) -> Self {
+        self.overrides.gear = Some(OverrideGearAction {
+            active: Boolean::literal(active),
+            number: None,
+            gear: Some(Gear::manual(gear)),
+        });
+        self
+    }
+
+    /// Override the gear of an automatic transmission
+    pub fn automatic_gear(mut self, active: bool, gear: AutomaticGearType) -> Self {
+        self.overrides.gear = Some(OverrideGearAction {
+            active: Boolean::literal(active),
+            number: None,
+            gear: Some(Gear::automatic(gear)),
+        });
+        self
+    }
+}
+
+impl ActionBuilder for OverrideControllerValueActionBuilder {
+    fn build_action(self) -> BuilderResult<PrivateAction> {
+        self.validate()?;
+
+        Ok(PrivateAction::ControllerAction(ControllerAction {
+            assign_controller_action: None,
+            override_controller_value_action: Some(self.overrides),
+            activate_controller_action: None,
+        }))
+    }
+
+    fn validate(&self) -> BuilderResult<()> {
+        let overrides = &self.overrides;
+        if overrides.throttle.is_none()
+            && overrides.brake.is_none()
+            && overrides.clutch.is_none()
+            && overrides.parking_brake.is_none()
+            && overrides.steering_wheel.is_none()
+            && overrides.gear.is_none()
+        {
+            return Err(BuilderError::validation_error(
+                "Override controller value action requires at least one overridden input",
+            ));
+        }
+
+        let pedals = [
+            ("throttle", overrides.throttle.as_ref().map(|t| &t.value)),
+            ("clutch", overrides.clutch.as_ref().map(|c| &c.value)),
+            (
+                "brake",
+                overrides
+                    .brake
+                    .as_ref()
+                    .and_then(|b| b.brake_input.as_ref())
+                    .map(BrakeInput::value),
+            ),
+            (
+                "parking brake",
+                overrides
+                    .parking_brake
+                    .as_ref()
+                    .and_then(|b| b.brake_input.as_ref())
+                    .map(BrakeInput::value),
+            ),
+        ];
+        for (name, value) in pedals {
+            if let Some(&value) = value.and_then(Double::as_literal) {
+                if !(0.0..=1.0).contains(&value) {
+                    return Err(BuilderError::validation_error(&format!(
+                        "Override {} value must be between 0 and 1, got {}",
+                        name, value
+                    )));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl ManeuverAction for OverrideControllerValueActionBuilder {
+    fn entity_ref(&self) -> Option<&str> {
+        self.entity_ref.as_deref()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -236,4 +388,60 @@ mod tests {
             panic!("Expected ControllerAction");
         }
     }
+
+    #[test]
+    fn test_override_controller_value_action_builder() {
+        let action = OverrideControllerValueActionBuilder::new()
+            .for_entity("ego")
+            .throttle(true, 0.3)
+            .brake(false, 0.0)
+            .build_action()
+            .unwrap();
+
+        let PrivateAction::ControllerAction(controller_action) = action else {
+            panic!("Expected ControllerAction");
+        };
+        let overrides = controller_action.override_controller_value_action.unwrap();
+        assert_eq!(overrides.throttle.unwrap().active, Boolean::literal(true));
+        assert_eq!(overrides.brake.unwrap().active, Boolean::literal(false));
+        assert!(overrides.steering_wheel.is_none());
+    }
+
+    #[test]
+    fn test_override_gear_round_trip() {
+        let manual = OverrideControllerValueActionBuilder::new()
+            .for_entity("ego")
+            .manual_gear(true, 2);
+        let automatic = OverrideControllerValueActionBuilder::new()
+            .for_entity("ego")
+            .automatic_gear(true, AutomaticGearType::Park);
+
+        for (builder, expected) in [
+            (manual, Gear::manual(2)),
+            (automatic, Gear::automatic(AutomaticGearType::Park)),
+        ] {
+            let PrivateAction::ControllerAction(controller_action) =
+                builder.build_action().unwrap()
+            else {
+                panic!("Expected ControllerAction");
+            };
+            let xml = quick_xml::se::to_string(&controller_action).unwrap();
+            let parsed: ControllerAction = quick_xml::de::from_str(&xml).unwrap();
+            let gear = parsed.override_controller_value_action.unwrap().gear.unwrap();
+            assert_eq!(gear.active, Boolean::literal(true));
+            assert_eq!(gear.gear, Some(expected));
+        }
+    }
+
+    #[test]
+    fn test_override_controller_value_action_validation() {
+        assert!(OverrideControllerValueActionBuilder::new()
+            .for_entity("ego")
+            .build_action()
+            .is_err());
+        assert!(OverrideControllerValueActionBuilder::new()
+            .throttle(true, 1.5)
+            .build_action()
+            .is_err());
+    }
 }
@@ -0,0 +1,335 @@
+//! Dynamics builders shared by movement actions
+//!
+//! Lane changes, lane offsets and speed changes describe how the target value
+//! is reached via `TransitionDynamics`: a dimension (time, distance or rate),
+//! a value in that dimension and a shape. Distance keeping and speed profile
+//! actions limit the movement via `DynamicConstraints`.
+//!
+//! # Usage Examples
+//!
+//! ```rust
+//! use openscenario_rs::builder::actions::{DynamicConstraintsBuilder, TransitionDynamicsBuilder};
+//!
+//! // Reach the target over 3 seconds following a sinusoidal profile
+//! let dynamics = TransitionDynamicsBuilder::new()
+//!     .over_time(3.0)
+//!     .sinusoidal()
+//!     .build()
+//!     .unwrap();
+//!
+//! // Comfortable limits for following a lead vehicle
+//! let constraints = DynamicConstraintsBuilder::new()
+//!     .max_acceleration(2.0)
+//!     .max_deceleration(4.0)
+//!     .max_speed(30.0)
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::builder::{BuilderError, BuilderResult};
+use crate::types::{
+    actions::movement::{DynamicConstraints, TransitionDynamics},
+    basic::Double,
+    enums::{DynamicsDimension, DynamicsShape, FollowingMode},
+};
+
+/// Builder for transition dynamics
+///
+/// The shape defaults to linear; a dimension and value must be given. The
+/// following mode is omitted unless set, leaving it to the simulator.
+#[derive(Debug, Clone, Default)]
+pub struct TransitionDynamicsBuilder {
+    dimension: Option<DynamicsDimension>,
+    value: Option<f64>,
+    shape: Option<DynamicsShape>,
+    following_mode: Option<FollowingMode>,
+}
+
+impl TransitionDynamicsBuilder {
+    /// Create new transition dynamics builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Complete the transition within the given time in seconds
+    pub fn over_time(mut self, seconds: f64) -> Self {
+        self.dimension = Some(DynamicsDimension::Time);
+        self.value = Some(seconds);
+        self
+    }
+
+    /// Complete the transition within the given travelled distance in meters
+    pub fn over_distance(mut self, meters: f64) -> Self {
+        self.dimension = Some(DynamicsDimension::Distance);
+        self.value = Some(meters);
+        self
+    }
+
+    /// Change the value at the given rate (unit per second)
+    pub fn at_rate(mut self, rate: f64) -> Self {
+        self.dimension = Some(DynamicsDimension::Rate);
+        self.value = Some(rate);
+        self
+    }
+
+    /// Set the transition shape
+    pub fn shape(mut self, shape: DynamicsShape) -> Self {
+        self.shape = Some(shape);
+        self
+    }
+
+    /// Use a linear transition
+    pub fn linear(self) -> Self {
+        self.shape(DynamicsShape::Linear)
+    }
+
+    /// Use a cubic transition
+    pub fn cubic(self) -> Self {
+        self.shape(DynamicsShape::Cubic)
+    }
+
+    /// Use a sinusoidal transition
+    pub fn sinusoidal(self) -> Self {
+        self.shape(DynamicsShape::Sinusoidal)
+    }
+
+    /// Jump to the target value immediately
+    pub fn step(self) -> Self {
+        self.shape(DynamicsShape::Step)
+    }
+
+    /// Set whether the target is reached exactly or with vehicle dynamics
+    pub fn following_mode(mut self, mode: FollowingMode) -> Self {
+        self.following_mode = Some(mode);
+        self
+    }
+
+    /// Build the transition dynamics
+    pub fn build(self) -> BuilderResult<TransitionDynamics> {
+        let (dimension, value) = match (self.dimension, self.value) {
+            (Some(dimension), Some(value)) => (dimension, value),
+            _ => {
+                return Err(BuilderError::missing_field(
+                    "dynamics_dimension",
+                    "Call .over_time(), .over_distance() or .at_rate()",
+                ))
+            }
+        };
+        if !value.is_finite() || value < 0.0 {
+            return Err(BuilderError::validation_error(&format!(
+                "Transition dynamics value must be a non-negative number, got {}",
+                value
+            )));
+        }
+
+        Ok(TransitionDynamics {
+            dynamics_dimension: dimension,
+            dynamics_shape: self.shape.unwrap_or(DynamicsShape::Linear),
+            following_mode: self.following_mode,
+            value: Double::literal(value),
+        })
+    }
+}
+
+/// Builder for dynamic constraints
+///
+/// All limits are optional; unset limits are left to the simulator.
+#[derive(Debug, Clone, Default)]
+pub struct DynamicConstraintsBuilder {
+    max_acceleration: Option<f64>,
+    max_acceleration_rate: Option<f64>,
+    max_deceleration: Option<f64>,
+    max_deceleration_rate: Option<f64>,
+    max_speed: Option<f64>,
+}
+
+impl DynamicConstraintsBuilder {
+    /// Create new dynamic constraints builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the maximum acceleration in m/s²
+    pub fn max_acceleration(mut self, value: f64) -> Self {
+        self.max_acceleration = Some(value);
+        self
+    }
+
+    /// Set the maximum acceleration rate in m/s³
+    pub fn max_acceleration_rate(mut self, value: f64) -> Self {
+        self.max_acceleration_rate = Some(value);
+        self
+    }
+
+    /// Set the maximum deceleration in m/s²
+    pub fn max_deceleration(mut self, value: f64) -> Self {
+        self.max_deceleration = Some(value);
+        self
+    }
+
+    /// Set the maximum deceleration rate in m/s³
+    pub fn max_deceleration_rate(mut self, value: f64) -> Self {
+        self.max_deceleration_rate = Some(value);
+        self
+    }
+
+    /// Set the maximum speed in m/s
+    pub fn max_speed(mut self, value: f64) -> Self {
+        self.max_speed = Some(value);
+        self
+    }
+
+    /// Build the dynamic constraints
+    pub fn build(self) -> BuilderResult<DynamicConstraints> {
+        let limits = [
+            ("max_acceleration", self.max_acceleration),
+            ("max_acceleration_rate", self.max_acceleration_rate),
+            ("max_deceleration", self.max_deceleration),
+            ("max_deceleration_rate", self.max_deceleration_rate),
+            ("max_speed", self.max_speed),
+        ];
+        for (name, value) in limits {
+            if let Some(value) = value {
+                if !value.is_finite() || value < 0.0 {
+                    return Err(BuilderError::validation_error(&format!(
+                        "Dynamic constraint {} must be a non-negative number, got {}",
+                        name, value
+                    )));
+                }
+            }
+        }
+
+        Ok(DynamicConstraints {
+            max_lateral_acc: None,
+            max_speed: self.max_speed.map(Double::literal),
+            max_acceleration: self.max_acceleration.map(Double::literal),
+            max_acceleration_rate: self.max_acceleration_rate.map(Double::literal),
+            max_deceleration: self.max_deceleration.map(Double::literal),
+            max_deceleration_rate: self.max_deceleration_rate.map(Double::literal),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_transition_dynamics_builder() {
+        let dynamics = TransitionDynamicsBuilder::new()
+            .over_distance(50.0)
+            .cubic()
+            .build()
+            .unwrap();
+        assert_eq!(dynamics.dynamics_dimension, DynamicsDimension::Distance);
+        assert_eq!(dynamics.dynamics_shape, DynamicsShape::Cubic);
+        assert_eq!(dynamics.value.as_literal(), Some(&50.0));
+        assert!(dynamics.following_mode.is_none());
+        let xml = quick_xml::se::to_string(&dynamics).unwrap();
+        assert!(!xml.contains("followingMode"));
+
+        let dynamics = TransitionDynamicsBuilder::new()
+            .at_rate(1.5)
+            .following_mode(FollowingMode::Position)
+            .build()
+            .unwrap();
+        assert_eq!(dynamics.dynamics_shape, DynamicsShape::Linear);
+        let xml = quick_xml::se::to_string(&dynamics).unwrap();
+        assert!(xml.contains(r#"followingMode="position""#));
+        let deserialized: TransitionDynamics = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, dynamics);
+    }
+
+    #[test]
+    fn test_transition_dynamics_builder_validation() {
+        assert!(TransitionDynamicsBuilder::new()
+            .sinusoidal()
+            .build()
+            .is_err());
+        assert!(TransitionDynamicsBuilder::new()
+            .over_time(-1.0)
+            .build()
+            .is_err());
+        assert!(TransitionDynamicsBuilder::new()
+            .over_time(f64::NAN)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_dynamic_constraints_on_longitudinal_distance_action() {
+        use crate::builder::actions::{ActionBuilder, LongitudinalDistanceActionBuilder};
+        use crate::types::actions::movement::LongitudinalActionChoice;
+        use crate::types::actions::wrappers::PrivateAction;
+
+        let constraints = DynamicConstraintsBuilder::new()
+            .max_acceleration(2.0)
+            .max_acceleration_rate(1.0)
+            .max_deceleration(6.0)
+            .max_deceleration_rate(3.0)
+            .max_speed(25.0)
+            .build()
+            .unwrap();
+        assert!(constraints.max_lateral_acc.is_none());
+
+        let action = LongitudinalDistanceActionBuilder::new()
+            .for_entity("ego")
+            .to_entity("lead")
+            .distance(20.0)
+            .continuous(true)
+            .with_dynamic_constraints(constraints.clone())
+            .build_action()
+            .unwrap();
+        let PrivateAction::LongitudinalAction(action) = action else {
+            panic!("Expected LongitudinalAction");
+        };
+        let LongitudinalActionChoice::LongitudinalDistanceAction(distance) =
+            action.longitudinal_action_choice
+        else {
+            panic!("Expected LongitudinalDistanceAction");
+        };
+        assert_eq!(distance.dynamic_constraints, Some(constraints));
+
+        let xml = quick_xml::se::to_string(&distance).unwrap();
+        assert!(xml.contains("maxAcceleration=\"2\""));
+        assert!(xml.contains("maxDecelerationRate=\"3\""));
+        assert!(!xml.contains("maxLateralAcc"));
+    }
+
+    #[test]
+    fn test_following_mode_on_speed_action() {
+        use crate::builder::actions::{ActionBuilder, SpeedActionBuilder};
+        use crate::types::actions::movement::LongitudinalActionChoice;
+        use crate::types::actions::wrappers::PrivateAction;
+
+        let action = SpeedActionBuilder::new()
+            .for_entity("ego")
+            .to_speed(20.0)
+            .following_mode(FollowingMode::Follow)
+            .build_action()
+            .unwrap();
+        let PrivateAction::LongitudinalAction(action) = action else {
+            panic!("Expected LongitudinalAction");
+        };
+        let LongitudinalActionChoice::SpeedAction(speed) = action.longitudinal_action_choice else {
+            panic!("Expected SpeedAction");
+        };
+        assert_eq!(
+            speed.speed_action_dynamics.following_mode,
+            Some(FollowingMode::Follow)
+        );
+    }
+
+    #[test]
+    fn test_dynamic_constraints_builder_validation() {
+        assert!(DynamicConstraintsBuilder::new().build().is_ok());
+        assert!(DynamicConstraintsBuilder::new()
+            .max_deceleration(-4.0)
+            .build()
+            .is_err());
+        assert!(DynamicConstraintsBuilder::new()
+            .max_speed(f64::INFINITY)
+            .build()
+            .is_err());
+    }
+}
@@ -3,8 +3,13 @@
 use crate::builder::actions::base::ActionBuilder;
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
-    actions::wrappers::PrivateAction,
-    environment::Environment,
+    actions::wrappers::{
+        AddEntityAction, DeleteEntityAction, EntityAction, EntityActionChoice, PrivateAction,
+    },
+    basic::{Boolean, Double, OSString},
+    enums::PrecipitationType,
+    environment::{Environment, Fog, Precipitation, Sun, TimeOfDay},
+    positions::Position,
     scenario::init::{EnvironmentAction, GlobalAction},
 };
 
@@ -33,6 +38,67 @@ impl EnvironmentActionBuilder {
         self
     }
 
+    /// Set the environment name
+    ///
+    /// This and the other environment setters start from
+    /// `Environment::default()` if no environment was given yet.
+    pub fn named(mut self, name: &str) -> Self {
+        self.environment_mut().name = OSString::literal(name.to_string());
+        self
+    }
+
+    /// Set the time of day (ISO 8601, e.g. "2021-12-10T11:00:00")
+    pub fn time_of_day(mut self, date_time: &str, animation: bool) -> Self {
+        self.environment_mut().time_of_day = TimeOfDay {
+            animation: Boolean::literal(animation),
+            date_time: date_time.to_string(),
+        };
+        self
+    }
+
+    /// Set the cloud state ("free", "cloudy", "overcast", "rainy", ...)
+    pub fn cloud_state(mut self, cloud_state: &str) -> Self {
+        self.environment_mut().weather.cloud_state = cloud_state.to_string();
+        self
+    }
+
+    /// Set sun intensity (lux) and position (radians)
+    pub fn sun(mut self, intensity: f64, azimuth: f64, elevation: f64) -> Self {
+        self.environment_mut().weather.sun = Sun {
+            intensity: Double::literal(intensity),
+            azimuth: Double::literal(azimuth),
+            elevation: Double::literal(elevation),
+        };
+        self
+    }
+
+    /// Set fog visual range in meters
+    pub fn fog(mut self, visual_range: f64) -> Self {
+        self.environment_mut().weather.fog = Fog {
+            visual_range: Double::literal(visual_range),
+        };
+        self
+    }
+
+    /// Set precipitation type and intensity
+    pub fn precipitation(mut self, precipitation_type: PrecipitationType, intensity: f64) -> Self {
+        self.environment_mut().weather.precipitation = Precipitation {
+            precipitation_type: precipitation_type.to_string(),
+            intensity: Double::literal(intensity),
+        };
+        self
+    }
+
+    /// Set the road friction scale factor
+    pub fn friction_scale_factor(mut self, factor: f64) -> Self {
+        self.environment_mut().road_condition.friction_scale_factor = Double::literal(factor);
+        self
+    }
+
+    fn environment_mut(&mut self) -> &mut Environment {
+        self.environment.get_or_insert_with(Environment::default)
+    }
+
     /// Build the environment action
     pub fn build(self) -> BuilderResult<GlobalAction> {
         self.validate()?;
@@ -43,6 +109,8 @@ impl EnvironmentActionBuilder {
 
         Ok(GlobalAction {
             environment_action: Some(environment_action),
+            parameter_action: None,
+            entity_action: None,
         })
     }
 
@@ -74,6 +142,7 @@ pub struct EntityActionBuilder {
 
 #[derive(Debug)]
 enum EntityActionType {
+    Add(Box<Position>),
     Delete,
 }
 
@@ -89,18 +158,59 @@ impl EntityActionBuilder {
         self
     }
 
+    /// Configure to add the entity at the given position
+    ///
+    /// The entity must still be declared in the scenario's `Entities`.
+    pub fn add_entity(mut self, entity_ref: &str, position: Position) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self.action_type = Some(EntityActionType::Add(Box::new(position)));
+        self
+    }
+
     /// Configure to delete the entity
-    pub fn delete_entity(mut self) -> Self {
+    pub fn delete_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
         self.action_type = Some(EntityActionType::Delete);
         self
     }
 
-    /// Build the entity action as a private action (placeholder)
+    /// Build the entity action
+    pub fn build(self) -> BuilderResult<GlobalAction> {
+        let entity_ref = self
+            .entity_ref
+            .ok_or_else(|| BuilderError::missing_field("entity_ref", ".for_entity()"))?;
+        let action = match self.action_type {
+            Some(EntityActionType::Add(position)) => {
+                EntityActionChoice::AddEntityAction(AddEntityAction {
+                    position: *position,
+                })
+            }
+            Some(EntityActionType::Delete) => {
+                EntityActionChoice::DeleteEntityAction(DeleteEntityAction::default())
+            }
+            None => {
+                return Err(BuilderError::validation_error_with_suggestion(
+                    "Entity action type is required",
+                    "Call .add_entity() or .delete_entity()",
+                ))
+            }
+        };
+
+        Ok(GlobalAction {
+            environment_action: None,
+            parameter_action: None,
+            entity_action: Some(EntityAction {
+                entity_ref: OSString::literal(entity_ref),
+                action,
+            }),
+        })
+    }
+
+    /// Build the entity action as a private action (for compatibility)
     pub fn build_action(self) -> BuilderResult<PrivateAction> {
-        // Entity actions are typically global actions, not private actions
-        // For now, return an error indicating this is not supported
+        // Entity actions are global actions, not private actions
         Err(BuilderError::validation_error(
-            "Entity actions are not yet implemented as private actions",
+            "Entity actions are global actions and cannot be used as private actions",
         ))
     }
 }
@@ -148,7 +258,7 @@ impl VariableActionBuilder {
 mod tests {
     use super::*;
     use crate::types::basic::Value;
-    use crate::types::environment::{RoadCondition, TimeOfDay, Weather};
+    use crate::types::environment::{RoadCondition, Weather};
 
     #[test]
     fn test_environment_action_builder() {
@@ -178,4 +288,80 @@ mod tests {
             "TestEnvironment"
         );
     }
+
+    #[test]
+    fn test_environment_action_builder_weather_setters() {
+        let action = EnvironmentActionBuilder::new()
+            .named("Storm")
+            .time_of_day("2021-12-10T18:30:00", true)
+            .cloud_state("overcast")
+            .fog(250.0)
+            .precipitation(PrecipitationType::Snow, 0.4)
+            .friction_scale_factor(0.3)
+            .build()
+            .unwrap();
+
+        let environment = action.environment_action.unwrap().environment;
+        assert_eq!(environment.name.as_literal().unwrap(), "Storm");
+        assert_eq!(environment.time_of_day.date_time, "2021-12-10T18:30:00");
+        assert_eq!(environment.weather.cloud_state, "overcast");
+        assert_eq!(
+            environment.weather.fog.visual_range.as_literal(),
+            Some(&250.0)
+        );
+        assert_eq!(environment.weather.precipitation.precipitation_type, "snow");
+        assert_eq!(
+            environment
+                .road_condition
+                .friction_scale_factor
+                .as_literal(),
+            Some(&0.3)
+        );
+        // Untouched parts keep their defaults
+        assert_eq!(environment.weather.sun, Sun::default());
+    }
+
+    #[test]
+    fn test_entity_action_builder_add_entity() {
+        let position = crate::builder::positions::WorldPositionBuilder::new()
+            .at_coordinates(50.0, 3.5, 0.0)
+            .build()
+            .unwrap();
+        let action = EntityActionBuilder::new()
+            .add_entity("merger", position)
+            .build()
+            .unwrap();
+
+        let entity_action = action.entity_action.as_ref().unwrap();
+        assert_eq!(entity_action.entity_ref.as_literal().unwrap(), "merger");
+        assert!(matches!(
+            entity_action.action,
+            EntityActionChoice::AddEntityAction(_)
+        ));
+
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert!(xml.contains(r#"<EntityAction entityRef="merger"><AddEntityAction><Position>"#));
+        assert!(xml.contains(r#"<WorldPosition x="50" y="3.5""#));
+        assert!(!xml.contains("EnvironmentAction"));
+    }
+
+    #[test]
+    fn test_entity_action_builder_delete_entity() {
+        let action = EntityActionBuilder::new()
+            .delete_entity("obstacle")
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert!(xml.contains(r#"<EntityAction entityRef="obstacle"><DeleteEntityAction/>"#));
+    }
+
+    #[test]
+    fn test_entity_action_builder_requires_action_type() {
+        assert!(EntityActionBuilder::new()
+            .for_entity("ego")
+            .build()
+            .is_err());
+        assert!(EntityActionBuilder::new().build().is_err());
+    }
 }
@@ -1,6 +1,7 @@
 //! Lateral action builders (LaneChangeAction, LateralDistanceAction, LaneOffsetAction)
 
 use crate::builder::actions::base::{ActionBuilder, ManeuverAction};
+use crate::builder::actions::dynamics::TransitionDynamicsBuilder;
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
     actions::movement::{
@@ -20,6 +21,7 @@ pub struct LaneChangeActionBuilder {
     entity_ref: Option<String>,
     target_lane_offset: Option<f64>,
     dynamics: Option<TransitionDynamics>,
+    dynamics_builder: Option<TransitionDynamicsBuilder>,
     target: Option<LaneChangeTargetChoice>,
 }
 
@@ -35,28 +37,36 @@ impl LaneChangeActionBuilder {
         self
     }
 
-    /// Set target lane offset
-    pub fn with_lane_offset(mut self, offset: f64) -> Self {
+    /// Set the lateral offset from the target lane's centerline in meters
+    pub fn target_lane_offset(mut self, offset: f64) -> Self {
         self.target_lane_offset = Some(offset);
         self
     }
 
+    /// Set target lane offset
+    pub fn with_lane_offset(self, offset: f64) -> Self {
+        self.target_lane_offset(offset)
+    }
+
     /// Set transition dynamics
     pub fn with_dynamics(mut self, dynamics: TransitionDynamics) -> Self {
         self.dynamics = Some(dynamics);
+        self.dynamics_builder = None;
         self
     }
 
-    /// Set dynamics with simple parameters
-    pub fn with_simple_dynamics(mut self, duration: f64) -> Self {
-        self.dynamics = Some(TransitionDynamics {
-            dynamics_dimension: DynamicsDimension::Time,
-            dynamics_shape: DynamicsShape::Linear,
-            value: Double::literal(duration),
-        });
+    /// Set transition dynamics from a builder, validated when the action is built
+    pub fn with_transition_dynamics(mut self, dynamics: TransitionDynamicsBuilder) -> Self {
+        self.dynamics_builder = Some(dynamics);
+        self.dynamics = None;
         self
     }
 
+    /// Set dynamics with simple parameters
+    pub fn with_simple_dynamics(self, duration: f64) -> Self {
+        self.with_transition_dynamics(TransitionDynamicsBuilder::new().over_time(duration))
+    }
+
     /// Target relative lane change (relative to another entity)
     pub fn to_relative_lane(mut self, entity_ref: &str, lane_offset: i32) -> Self {
         self.target = Some(LaneChangeTargetChoice::RelativeTargetLane(
@@ -83,13 +93,19 @@ impl ActionBuilder for LaneChangeActionBuilder {
     fn build_action(self) -> BuilderResult<PrivateAction> {
         self.validate()?;
 
-        let lane_change_action = LaneChangeAction {
-            target_lane_offset: self.target_lane_offset.map(Double::literal),
-            lane_change_action_dynamics: self.dynamics.unwrap_or_else(|| TransitionDynamics {
+        let dynamics = match self.dynamics_builder {
+            Some(builder) => builder.build()?,
+            None => self.dynamics.unwrap_or_else(|| TransitionDynamics {
                 dynamics_dimension: DynamicsDimension::Time,
                 dynamics_shape: DynamicsShape::Linear,
+                following_mode: None,
                 value: Double::literal(2.0),
             }),
+        };
+
+        let lane_change_action = LaneChangeAction {
+            target_lane_offset: self.target_lane_offset.map(Double::literal),
+            lane_change_action_dynamics: dynamics,
             lane_change_target: LaneChangeTarget {
                 target_choice: self.target.unwrap(),
             },
@@ -102,10 +118,18 @@ impl ActionBuilder for LaneChangeActionBuilder {
 
     fn validate(&self) -> BuilderResult<()> {
         if self.target.is_none() {
-            return Err(BuilderError::validation_error(
+            return Err(BuilderError::validation_error_with_suggestion(
                 "Lane change target is required",
+                "Call .to_relative_lane() or .to_absolute_lane()",
             ));
         }
+        if let Some(offset) = self.target_lane_offset {
+            if !offset.is_finite() {
+                return Err(BuilderError::validation_error(
+                    "Target lane offset must be a finite number",
+                ));
+            }
+        }
         Ok(())
     }
 }
@@ -352,6 +376,51 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_relative_lane_change_with_sinusoidal_dynamics() {
+        let action = LaneChangeActionBuilder::new()
+            .for_entity("ego")
+            .to_relative_lane("ego", -1)
+            .target_lane_offset(0.25)
+            .with_transition_dynamics(TransitionDynamicsBuilder::new().over_time(3.0).sinusoidal())
+            .build_action()
+            .unwrap();
+
+        let PrivateAction::LateralAction(lateral_action) = action else {
+            panic!("Expected LateralAction");
+        };
+        let crate::types::actions::movement::LateralActionChoice::LaneChangeAction(lane_change) =
+            lateral_action.lateral_choice
+        else {
+            panic!("Expected LaneChangeAction");
+        };
+        assert_eq!(lane_change.target_lane_offset, Some(Double::literal(0.25)));
+        let dynamics = &lane_change.lane_change_action_dynamics;
+        assert_eq!(dynamics.dynamics_dimension, DynamicsDimension::Time);
+        assert_eq!(dynamics.dynamics_shape, DynamicsShape::Sinusoidal);
+        assert_eq!(dynamics.value.as_literal(), Some(&3.0));
+        match lane_change.lane_change_target.target_choice {
+            LaneChangeTargetChoice::RelativeTargetLane(target) => {
+                assert_eq!(target.entity_ref.as_literal().unwrap(), "ego");
+                assert_eq!(target.value.as_literal(), Some(&-1));
+            }
+            other => panic!("Expected RelativeTargetLane, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_lane_change_requires_target_and_valid_dynamics() {
+        assert!(LaneChangeActionBuilder::new()
+            .with_simple_dynamics(3.0)
+            .build_action()
+            .is_err());
+        assert!(LaneChangeActionBuilder::new()
+            .to_absolute_lane("-2")
+            .with_transition_dynamics(TransitionDynamicsBuilder::new().sinusoidal())
+            .build_action()
+            .is_err());
+    }
+
     #[test]
     fn test_lateral_distance_action_builder() {
         let action = LateralDistanceActionBuilder::new()
@@ -18,27 +18,30 @@
 //! // Create a longitudinal distance action to maintain 10m from lead vehicle
 //! let distance_action = LongitudinalDistanceActionBuilder::new()
 //!     .for_entity("ego_vehicle")
-//!     .from_entity("lead_vehicle")
-//!     .at_distance(10.0)
-//!     .with_freespace(true);
+//!     .to_entity("lead_vehicle")
+//!     .distance(10.0)
+//!     .with_freespace(true)
+//!     .continuous(true)
+//!     .with_max_speed(30.0);
 //!
 //! // Create a speed profile action with multiple time/speed points
 //! let profile_action = SpeedProfileActionBuilder::new()
 //!     .for_entity("ego_vehicle")
-//!     .add_entry_direct(0.0, 0.0)
-//!     .add_entry_direct(5.0, 30.0)
-//!     .add_entry_direct(10.0, 50.0);
+//!     .add_entry(0.0, 0.0)
+//!     .add_entry(5.0, 30.0)
+//!     .add_entry(10.0, 50.0);
 //! ```
 
 use crate::builder::actions::base::{ActionBuilder, ManeuverAction};
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
     actions::movement::{
-        LongitudinalAction, LongitudinalActionChoice, LongitudinalDistanceAction,
-        SpeedProfileAction, SpeedProfileEntry,
+        DynamicConstraints, LongitudinalAction, LongitudinalActionChoice,
+        LongitudinalDistanceAction, SpeedProfileAction, SpeedProfileEntry,
     },
     actions::wrappers::PrivateAction,
     basic::{Boolean, Double, OSString},
+    enums::{CoordinateSystem, FollowingMode, LongitudinalDisplacement},
 };
 
 /// Builder for longitudinal distance actions
@@ -50,6 +53,9 @@ pub struct LongitudinalDistanceActionBuilder {
     time_gap: Option<f64>,
     freespace: bool,
     continuous: bool,
+    coordinate_system: Option<CoordinateSystem>,
+    displacement: Option<LongitudinalDisplacement>,
+    dynamic_constraints: Option<DynamicConstraints>,
 }
 
 impl LongitudinalDistanceActionBuilder {
@@ -70,6 +76,16 @@ impl LongitudinalDistanceActionBuilder {
         self
     }
 
+    /// Set the entity to keep distance to (alias for `from_entity`)
+    pub fn to_entity(self, target_entity: &str) -> Self {
+        self.from_entity(target_entity)
+    }
+
+    /// Set fixed distance value (alias for `at_distance`)
+    pub fn distance(self, distance: f64) -> Self {
+        self.at_distance(distance)
+    }
+
     /// Set fixed distance value (mutually exclusive with time_gap)
     pub fn at_distance(mut self, distance: f64) -> Self {
         self.distance = Some(distance);
@@ -95,6 +111,32 @@ impl LongitudinalDistanceActionBuilder {
         self.continuous = continuous;
         self
     }
+
+    /// Set the coordinate system in which the distance is measured
+    pub fn with_coordinate_system(mut self, coordinate_system: CoordinateSystem) -> Self {
+        self.coordinate_system = Some(coordinate_system);
+        self
+    }
+
+    /// Set whether the actor should trail or lead the referenced entity
+    pub fn with_displacement(mut self, displacement: LongitudinalDisplacement) -> Self {
+        self.displacement = Some(displacement);
+        self
+    }
+
+    /// Set dynamic constraints for keeping the distance
+    pub fn with_dynamic_constraints(mut self, constraints: DynamicConstraints) -> Self {
+        self.dynamic_constraints = Some(constraints);
+        self
+    }
+
+    /// Limit the speed while keeping the distance
+    pub fn with_max_speed(mut self, max_speed: f64) -> Self {
+        self.dynamic_constraints
+            .get_or_insert_with(DynamicConstraints::default)
+            .max_speed = Some(Double::literal(max_speed));
+        self
+    }
 }
 
 impl ActionBuilder for LongitudinalDistanceActionBuilder {
@@ -105,11 +147,15 @@ impl ActionBuilder for LongitudinalDistanceActionBuilder {
             entity_ref: OSString::literal(self.target_entity.unwrap()),
             distance: self.distance.map(Double::literal),
             time_gap: self.time_gap.map(Double::literal),
-            coordinate_system: None,
-            displacement: None,
+            coordinate_system: self
+                .coordinate_system
+                .map(|system| OSString::literal(system.to_string())),
+            displacement: self
+                .displacement
+                .map(|displacement| OSString::literal(displacement.to_string())),
             freespace: Some(Boolean::literal(self.freespace)),
             continuous: Boolean::literal(self.continuous),
-            dynamic_constraints: None,
+            dynamic_constraints: self.dynamic_constraints,
         };
 
         Ok(PrivateAction::LongitudinalAction(LongitudinalAction {
@@ -132,6 +178,15 @@ impl ActionBuilder for LongitudinalDistanceActionBuilder {
             ));
         }
 
+        if let Some(value) = self.distance.or(self.time_gap) {
+            if !value.is_finite() || value < 0.0 {
+                return Err(BuilderError::validation_error(&format!(
+                    "Distance and time gap must be non-negative, got {}",
+                    value
+                )));
+            }
+        }
+
         Ok(())
     }
 }
@@ -143,9 +198,14 @@ impl ManeuverAction for LongitudinalDistanceActionBuilder {
 }
 
 /// Builder for speed profile actions
+///
+/// The following mode defaults to `follow`.
 #[derive(Debug, Default)]
 pub struct SpeedProfileActionBuilder {
     entity_ref: Option<String>,
+    reference_entity: Option<String>,
+    following_mode: Option<FollowingMode>,
+    dynamic_constraints: Option<DynamicConstraints>,
     entries: Vec<SpeedProfileEntry>,
 }
 
@@ -161,19 +221,42 @@ impl SpeedProfileActionBuilder {
         self
     }
 
-    /// Add a speed profile entry using builder pattern
-    pub fn add_entry(self) -> SpeedProfileEntryBuilder {
-        SpeedProfileEntryBuilder::new(self)
+    /// Make the entry speeds relative to another entity's speed
+    pub fn relative_to(mut self, entity_ref: &str) -> Self {
+        self.reference_entity = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Set how the profile is followed
+    pub fn following_mode(mut self, mode: FollowingMode) -> Self {
+        self.following_mode = Some(mode);
+        self
+    }
+
+    /// Limit the movement with dynamic constraints
+    pub fn with_dynamic_constraints(mut self, constraints: DynamicConstraints) -> Self {
+        self.dynamic_constraints = Some(constraints);
+        self
     }
 
-    /// Add a speed profile entry directly (convenience method)
-    pub fn add_entry_direct(mut self, time: f64, speed: f64) -> Self {
+    /// Add an entry reaching `speed` (m/s) within `time` seconds
+    pub fn add_entry(mut self, time: f64, speed: f64) -> Self {
         self.entries.push(SpeedProfileEntry {
-            time: Double::literal(time),
+            time: Some(Double::literal(time)),
             speed: Double::literal(speed),
         });
         self
     }
+
+    /// Add a speed profile entry directly, same as [`add_entry`](Self::add_entry)
+    pub fn add_entry_direct(self, time: f64, speed: f64) -> Self {
+        self.add_entry(time, speed)
+    }
+
+    /// Add a speed profile entry using builder pattern
+    pub fn entry(self) -> SpeedProfileEntryBuilder {
+        SpeedProfileEntryBuilder::new(self)
+    }
 }
 
 impl ActionBuilder for SpeedProfileActionBuilder {
@@ -181,12 +264,10 @@ impl ActionBuilder for SpeedProfileActionBuilder {
         self.validate()?;
 
         let action = SpeedProfileAction {
-            entity_ref: self
-                .entity_ref
-                .as_ref()
-                .map(|s| OSString::literal(s.clone())),
+            entity_ref: self.reference_entity.map(OSString::literal),
+            following_mode: self.following_mode.unwrap_or(FollowingMode::Follow),
             entries: self.entries,
-            dynamic_constraints: None,
+            dynamic_constraints: self.dynamic_constraints,
         };
 
         Ok(PrivateAction::LongitudinalAction(LongitudinalAction {
@@ -201,14 +282,16 @@ impl ActionBuilder for SpeedProfileActionBuilder {
             ));
         }
 
-        // Verify chronological order
-        for i in 1..self.entries.len() {
-            let prev_time = self.entries[i - 1].time.as_literal().unwrap();
-            let curr_time = self.entries[i].time.as_literal().unwrap();
-            if curr_time <= prev_time {
-                return Err(BuilderError::validation_error(
-                    "Speed profile entries must be in chronological order",
-                ));
+        // Verify chronological order of literal entry times
+        for pair in self.entries.windows(2) {
+            let prev_time = pair[0].time.as_ref().and_then(Double::as_literal);
+            let curr_time = pair[1].time.as_ref().and_then(Double::as_literal);
+            if let (Some(prev_time), Some(curr_time)) = (prev_time, curr_time) {
+                if curr_time <= prev_time {
+                    return Err(BuilderError::validation_error(
+                        "Speed profile entries must be in chronological order",
+                    ));
+                }
             }
         }
 
@@ -238,7 +321,7 @@ impl SpeedProfileEntryBuilder {
         }
     }
 
-    /// Set the time for this entry
+    /// Set the time to reach the speed (optional)
     pub fn at_time(mut self, time: f64) -> Self {
         self.time = Some(time);
         self
@@ -252,15 +335,12 @@ impl SpeedProfileEntryBuilder {
 
     /// Finish building this entry and return to parent builder
     pub fn finish(mut self) -> BuilderResult<SpeedProfileActionBuilder> {
-        if self.time.is_none() {
-            return Err(BuilderError::validation_error("Entry time is required"));
-        }
         if self.speed.is_none() {
             return Err(BuilderError::validation_error("Entry speed is required"));
         }
 
         let entry = SpeedProfileEntry {
-            time: Double::literal(self.time.unwrap()),
+            time: self.time.map(Double::literal),
             speed: Double::literal(self.speed.unwrap()),
         };
 
@@ -320,6 +400,50 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_longitudinal_distance_continuous_with_constraints() {
+        let action = LongitudinalDistanceActionBuilder::new()
+            .for_entity("ego")
+            .to_entity("lead")
+            .distance(25.0)
+            .continuous(true)
+            .with_freespace(true)
+            .with_coordinate_system(CoordinateSystem::Lane)
+            .with_displacement(LongitudinalDisplacement::TrailingReferencedEntity)
+            .with_max_speed(33.0)
+            .build_action()
+            .unwrap();
+
+        let PrivateAction::LongitudinalAction(action) = action else {
+            panic!("Expected LongitudinalAction");
+        };
+        let LongitudinalActionChoice::LongitudinalDistanceAction(dist) =
+            action.longitudinal_action_choice
+        else {
+            panic!("Expected LongitudinalDistanceAction");
+        };
+        assert_eq!(dist.entity_ref.as_literal().unwrap(), "lead");
+        assert_eq!(dist.distance.unwrap().as_literal(), Some(&25.0));
+        assert_eq!(dist.continuous.as_literal(), Some(&true));
+        assert_eq!(dist.freespace.unwrap().as_literal(), Some(&true));
+        assert_eq!(
+            dist.coordinate_system.unwrap().as_literal().unwrap(),
+            "lane"
+        );
+        assert_eq!(
+            dist.displacement.unwrap().as_literal().unwrap(),
+            "trailingReferencedEntity"
+        );
+        let constraints = dist.dynamic_constraints.unwrap();
+        assert_eq!(constraints.max_speed.unwrap().as_literal(), Some(&33.0));
+
+        assert!(LongitudinalDistanceActionBuilder::new()
+            .to_entity("lead")
+            .distance(-1.0)
+            .build_action()
+            .is_err());
+    }
+
     #[test]
     fn test_validation_requires_target() {
         let result = LongitudinalDistanceActionBuilder::new()
@@ -358,9 +482,9 @@ mod tests {
                 match &action.longitudinal_action_choice {
                     LongitudinalActionChoice::SpeedProfileAction(ref profile) => {
                         assert_eq!(profile.entries.len(), 3);
-                        assert_eq!(profile.entries[0].time.as_literal(), Some(&0.0));
+                        assert_eq!(profile.entries[0].time, Some(Double::literal(0.0)));
                         assert_eq!(profile.entries[0].speed.as_literal(), Some(&0.0));
-                        assert_eq!(profile.entries[2].time.as_literal(), Some(&10.0));
+                        assert_eq!(profile.entries[2].time, Some(Double::literal(10.0)));
                         assert_eq!(profile.entries[2].speed.as_literal(), Some(&50.0));
                     }
                     _ => panic!("Expected SpeedProfileAction"),
@@ -374,12 +498,12 @@ mod tests {
     fn test_speed_profile_entry_builder() {
         let builder = SpeedProfileActionBuilder::new()
             .for_entity("ego")
-            .add_entry()
+            .entry()
             .at_time(0.0)
             .with_speed(0.0)
             .finish()
             .unwrap()
-            .add_entry()
+            .entry()
             .at_time(5.0)
             .with_speed(25.0)
             .finish()
@@ -400,6 +524,37 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_speed_profile_round_trip() {
+        let action = SpeedProfileActionBuilder::new()
+            .for_entity("ego")
+            .relative_to("lead")
+            .following_mode(FollowingMode::Position)
+            .add_entry(2.0, 10.0)
+            .add_entry(6.0, 25.0)
+            .build_action()
+            .unwrap();
+        let PrivateAction::LongitudinalAction(action) = action else {
+            panic!("Expected LongitudinalAction");
+        };
+        let LongitudinalActionChoice::SpeedProfileAction(profile) =
+            action.longitudinal_action_choice
+        else {
+            panic!("Expected SpeedProfileAction");
+        };
+
+        let xml = quick_xml::se::to_string(&profile).unwrap();
+        assert_eq!(
+            xml,
+            "<SpeedProfileAction entityRef=\"lead\" followingMode=\"position\">\
+             <SpeedProfileEntry time=\"2\" speed=\"10\"/>\
+             <SpeedProfileEntry time=\"6\" speed=\"25\"/>\
+             </SpeedProfileAction>"
+        );
+        let parsed: SpeedProfileAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, profile);
+    }
+
     #[test]
     fn test_speed_profile_validation_min_entries() {
         let result = SpeedProfileActionBuilder::new()
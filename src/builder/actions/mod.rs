@@ -14,16 +14,22 @@
 //! - [`LaneChangeActionBuilder`] - Execute lane change maneuvers
 //! - [`LateralDistanceActionBuilder`] - Maintain lateral distance to targets
 //! - [`LaneOffsetActionBuilder`] - Apply lateral offset from lane center
+//! - [`TransitionDynamicsBuilder`] - Shape of the transition to the target
+//! - [`DynamicConstraintsBuilder`] - Acceleration, deceleration and speed limits
 //!
 //! ## Controller Actions
 //! - [`ActivateControllerActionBuilder`] - Activate entity controllers
 //! - [`AssignControllerActionBuilder`] - Assign controllers to entities
+//! - [`OverrideControllerValueActionBuilder`] - Override throttle, brake, steering or gear
 //!
 //! ## Global Actions
 //! - [`EnvironmentActionBuilder`] - Modify weather, time of day, lighting
 //! - [`EntityActionBuilder`] - Add/remove entities during scenario
 //! - [`VariableActionBuilder`] - Modify scenario variables
 //!
+//! ## User-Defined Actions
+//! - [`UserDefinedActionBuilder`] - Simulator specific custom commands
+//!
 //! # Basic Usage
 //!
 //! ```rust
@@ -46,26 +52,35 @@
 
 pub mod base;
 pub mod controller;
+pub mod dynamics;
 pub mod global;
 pub mod lateral;
 pub mod longitudinal;
 pub mod movement;
 pub mod routing;
 pub mod synchronize;
+pub mod traffic;
 pub mod trajectory;
+pub mod user_defined;
 pub mod visibility;
 
 pub use base::{ActionBuilder, ManeuverAction};
-pub use controller::{ActivateControllerActionBuilder, AssignControllerActionBuilder};
+pub use controller::{
+    ActivateControllerActionBuilder, AssignControllerActionBuilder,
+    OverrideControllerValueActionBuilder,
+};
+pub use dynamics::{DynamicConstraintsBuilder, TransitionDynamicsBuilder};
 pub use global::{EntityActionBuilder, EnvironmentActionBuilder, VariableActionBuilder};
 pub use lateral::{LaneChangeActionBuilder, LaneOffsetActionBuilder, LateralDistanceActionBuilder};
 pub use longitudinal::{LongitudinalDistanceActionBuilder, SpeedProfileActionBuilder};
 pub use movement::{SpeedActionBuilder, TeleportActionBuilder};
 pub use routing::{AssignRouteActionBuilder, FollowRouteActionBuilder};
 pub use synchronize::SynchronizeActionBuilder;
+pub use traffic::TrafficDefinitionBuilder;
 pub use trajectory::{
     FollowTrajectoryActionBuilder, PolylineBuilder, TrajectoryBuilder, VertexBuilder,
 };
+pub use user_defined::UserDefinedActionBuilder;
 pub use visibility::VisibilityActionBuilder;
 
 use crate::builder::BuilderResult;
@@ -28,7 +28,7 @@ use crate::types::{
     },
     actions::wrappers::PrivateAction,
     basic::Double,
-    enums::{DynamicsDimension, DynamicsShape},
+    enums::{DynamicsDimension, DynamicsShape, FollowingMode},
     positions::Position,
 };
 
@@ -37,6 +37,7 @@ use crate::types::{
 pub struct SpeedActionBuilder {
     entity_ref: Option<String>,
     target_speed: Option<f64>,
+    following_mode: Option<FollowingMode>,
 }
 
 impl SpeedActionBuilder {
@@ -62,6 +63,12 @@ impl SpeedActionBuilder {
         self.target_speed = Some(delta);
         self
     }
+
+    /// Set whether the target speed is reached exactly or with vehicle dynamics
+    pub fn following_mode(mut self, mode: FollowingMode) -> Self {
+        self.following_mode = Some(mode);
+        self
+    }
 }
 
 impl ActionBuilder for SpeedActionBuilder {
@@ -72,6 +79,7 @@ impl ActionBuilder for SpeedActionBuilder {
             speed_action_dynamics: TransitionDynamics {
                 dynamics_dimension: DynamicsDimension::Time,
                 dynamics_shape: DynamicsShape::Linear,
+                following_mode: self.following_mode,
                 value: Double::literal(1.0),
             },
             speed_action_target: SpeedActionTarget {
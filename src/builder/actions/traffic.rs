@@ -0,0 +1,226 @@
+//! Traffic definition builder shared by traffic actions
+//!
+//! Traffic source, sink, swarm and area actions describe the traffic they
+//! spawn with a `TrafficDefinition`: a weighted distribution of vehicle
+//! categories and a weighted distribution of controllers. Weights are given
+//! in any non-negative scale and normalized to sum to one on build.
+//!
+//! # Usage Examples
+//!
+//! ```rust
+//! use openscenario_rs::builder::actions::TrafficDefinitionBuilder;
+//! use openscenario_rs::types::actions::traffic::VehicleCategory;
+//! use openscenario_rs::types::controllers::Controller;
+//! use openscenario_rs::types::enums::ControllerType;
+//!
+//! let definition = TrafficDefinitionBuilder::new()
+//!     .named("HighwayTraffic")
+//!     .vehicle_category(VehicleCategory::Car, 70.0)
+//!     .vehicle_category(VehicleCategory::Truck, 30.0)
+//!     .controller(
+//!         Controller::new("DefaultDriver".to_string(), ControllerType::Movement),
+//!         1.0,
+//!     )
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::builder::{BuilderError, BuilderResult};
+use crate::types::{
+    actions::traffic::{
+        ControllerDistribution, ControllerDistributionEntry, TrafficDefinition, VehicleCategory,
+        VehicleCategoryDistribution, VehicleCategoryDistributionEntry,
+    },
+    basic::{Double, OSString},
+    catalogs::references::ControllerCatalogReference,
+    controllers::Controller,
+};
+
+/// Builder for traffic definitions
+///
+/// A name, at least one vehicle category and at least one controller are
+/// required.
+#[derive(Debug, Clone, Default)]
+pub struct TrafficDefinitionBuilder {
+    name: Option<String>,
+    categories: Vec<(VehicleCategory, f64)>,
+    controllers: Vec<(ControllerDistributionEntry, f64)>,
+}
+
+impl TrafficDefinitionBuilder {
+    /// Create new traffic definition builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the traffic definition name
+    pub fn named(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Add a vehicle category with a relative weight
+    pub fn vehicle_category(mut self, category: VehicleCategory, weight: f64) -> Self {
+        self.categories.push((category, weight));
+        self
+    }
+
+    /// Add a controller definition with a relative weight
+    pub fn controller(self, controller: Controller, weight: f64) -> Self {
+        self.controller_entry(Some(controller), None, weight)
+    }
+
+    /// Add a controller catalog reference with a relative weight
+    pub fn controller_from_catalog(
+        self,
+        reference: ControllerCatalogReference,
+        weight: f64,
+    ) -> Self {
+        self.controller_entry(None, Some(reference), weight)
+    }
+
+    fn controller_entry(
+        mut self,
+        controller: Option<Controller>,
+        catalog_reference: Option<ControllerCatalogReference>,
+        weight: f64,
+    ) -> Self {
+        let entry = ControllerDistributionEntry {
+            weight: Double::literal(weight),
+            controller,
+            catalog_reference,
+        };
+        self.controllers.push((entry, weight));
+        self
+    }
+
+    /// Build the traffic definition with normalized weights
+    pub fn build(self) -> BuilderResult<TrafficDefinition> {
+        let name = self.name.ok_or_else(|| {
+            BuilderError::missing_field("name", "Call .named() to name the traffic definition")
+        })?;
+
+        let category_weights = normalize(
+            "vehicle category",
+            self.categories.iter().map(|(_, weight)| *weight),
+        )?;
+        let controller_weights = normalize(
+            "controller",
+            self.controllers.iter().map(|(_, weight)| *weight),
+        )?;
+
+        let entries = self
+            .categories
+            .into_iter()
+            .zip(category_weights)
+            .map(|((category, _), weight)| VehicleCategoryDistributionEntry {
+                category,
+                weight: Double::literal(weight),
+            })
+            .collect();
+        let controller_entries = self
+            .controllers
+            .into_iter()
+            .zip(controller_weights)
+            .map(|((entry, _), weight)| ControllerDistributionEntry {
+                weight: Double::literal(weight),
+                ..entry
+            })
+            .collect();
+
+        Ok(TrafficDefinition {
+            name: OSString::literal(name),
+            vehicle_category_distribution: Some(VehicleCategoryDistribution { entries }),
+            controller_distribution: Some(ControllerDistribution {
+                entries: controller_entries,
+            }),
+        })
+    }
+}
+
+/// Scale weights to sum to one, rejecting negative or all-zero weights
+fn normalize(kind: &str, weights: impl Iterator<Item = f64>) -> BuilderResult<Vec<f64>> {
+    let weights: Vec<f64> = weights.collect();
+    if let Some(weight) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+        return Err(BuilderError::validation_error(&format!(
+            "Traffic {} weight must be a non-negative number, got {}",
+            kind, weight
+        )));
+    }
+    let total: f64 = weights.iter().sum();
+    if total <= 0.0 {
+        return Err(BuilderError::validation_error(&format!(
+            "Traffic definition needs at least one {} with a positive weight",
+            kind
+        )));
+    }
+    Ok(weights.into_iter().map(|w| w / total).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::enums::ControllerType;
+
+    fn driver(name: &str) -> Controller {
+        Controller::new(name.to_string(), ControllerType::Movement)
+    }
+
+    #[test]
+    fn test_traffic_definition_builder() {
+        let definition = TrafficDefinitionBuilder::new()
+            .named("HighwayTraffic")
+            .vehicle_category(VehicleCategory::Car, 70.0)
+            .vehicle_category(VehicleCategory::Truck, 30.0)
+            .controller(driver("Calm"), 3.0)
+            .controller(driver("Aggressive"), 1.0)
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&definition).unwrap();
+        assert!(xml.contains(r#"<Controller name="Calm""#));
+        let deserialized: TrafficDefinition = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, definition);
+
+        assert_eq!(definition.name.as_literal().unwrap(), "HighwayTraffic");
+        let categories = definition.vehicle_category_distribution.unwrap().entries;
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].category, VehicleCategory::Car);
+        assert!((categories[0].weight.as_literal().unwrap() - 0.7).abs() < 1e-12);
+        assert_eq!(categories[1].category, VehicleCategory::Truck);
+        assert!((categories[1].weight.as_literal().unwrap() - 0.3).abs() < 1e-12);
+
+        let controllers = definition.controller_distribution.unwrap().entries;
+        assert_eq!(controllers[0].weight.as_literal(), Some(&0.75));
+        assert_eq!(controllers[1].weight.as_literal(), Some(&0.25));
+        let aggressive = controllers[1].controller.as_ref().unwrap();
+        assert_eq!(aggressive.name.as_literal().unwrap(), "Aggressive");
+    }
+
+    #[test]
+    fn test_traffic_definition_builder_validation() {
+        let valid = || {
+            TrafficDefinitionBuilder::new()
+                .named("Traffic")
+                .vehicle_category(VehicleCategory::Car, 1.0)
+        };
+        assert!(valid().controller(driver("Driver"), 1.0).build().is_ok());
+
+        // Missing controllers
+        assert!(valid().build().is_err());
+        // Negative weight
+        assert!(valid()
+            .vehicle_category(VehicleCategory::Bus, -0.5)
+            .controller(driver("Driver"), 1.0)
+            .build()
+            .is_err());
+        // All-zero weights cannot be normalized
+        assert!(valid().controller(driver("Driver"), 0.0).build().is_err());
+        // Missing name
+        assert!(TrafficDefinitionBuilder::new()
+            .vehicle_category(VehicleCategory::Car, 1.0)
+            .controller(driver("Driver"), 1.0)
+            .build()
+            .is_err());
+    }
+}
@@ -20,8 +20,7 @@ use crate::builder::actions::base::{ActionBuilder, ManeuverAction};
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
     actions::movement::{
-        FollowTrajectoryAction, RoutingAction, TimeReference, Timing, Trajectory,
-        TrajectoryFollowingMode,
+        FollowTrajectoryAction, RoutingAction, TimeReference, Trajectory, TrajectoryFollowingMode,
     },
     actions::wrappers::PrivateAction,
     basic::{Boolean, Double, OSString},
@@ -172,12 +171,27 @@ impl VertexBuilder {
         self
     }
 
+    /// Set the absolute time at this vertex (in seconds)
+    ///
+    /// Equivalent to [`VertexBuilder::time`]; reads more naturally alongside
+    /// [`VertexBuilder::at_position`].
+    pub fn at_time(self, time: f64) -> Self {
+        self.time(time)
+    }
+
     /// Set position using an existing Position object
     pub fn position(mut self, position: Position) -> Self {
         self.position = Some(position);
         self
     }
 
+    /// Set the position of this vertex
+    ///
+    /// Equivalent to [`VertexBuilder::position`].
+    pub fn at_position(self, position: Position) -> Self {
+        self.position(position)
+    }
+
     /// Set position using world coordinates
     ///
     /// # Arguments
@@ -222,14 +236,27 @@ impl VertexBuilder {
     }
 
     fn validate(&self) -> BuilderResult<()> {
-        if self.time.is_none() {
+        let Some(time) = self.time else {
             return Err(BuilderError::validation_error("Vertex time is required"));
-        }
+        };
         if self.position.is_none() {
             return Err(BuilderError::validation_error(
                 "Vertex position is required",
             ));
         }
+        if let Some(previous) = self
+            .parent
+            .vertices
+            .last()
+            .and_then(|vertex| vertex.time.as_literal())
+        {
+            if time <= *previous {
+                return Err(BuilderError::validation_error(&format!(
+                    "Vertex times must be strictly increasing: {} follows {}",
+                    time, previous
+                )));
+            }
+        }
         Ok(())
     }
 }
@@ -270,16 +297,20 @@ impl FollowTrajectoryActionBuilder {
         self
     }
 
-    /// Set following mode to "follow" (entity follows trajectory timing)
-    pub fn following_mode_follow(mut self) -> Self {
-        self.following_mode = Some(FollowingMode::Follow);
+    /// Set how the trajectory is followed
+    pub fn following_mode(mut self, mode: FollowingMode) -> Self {
+        self.following_mode = Some(mode);
         self
     }
 
+    /// Set following mode to "follow" (entity follows trajectory timing)
+    pub fn following_mode_follow(self) -> Self {
+        self.following_mode(FollowingMode::Follow)
+    }
+
     /// Set following mode to "position" (entity reaches positions at specified times)
-    pub fn following_mode_position(mut self) -> Self {
-        self.following_mode = Some(FollowingMode::Position);
-        self
+    pub fn following_mode_position(self) -> Self {
+        self.following_mode(FollowingMode::Position)
     }
 
     /// Set initial distance offset along trajectory (in meters)
@@ -296,13 +327,7 @@ impl ActionBuilder for FollowTrajectoryActionBuilder {
         let follow_trajectory_action = FollowTrajectoryAction {
             trajectory: self.trajectory,
             catalog_reference: None,
-            time_reference: TimeReference {
-                timing: Timing {
-                    domain_absolute_relative: OSString::literal("absolute".to_string()),
-                    scale: Double::literal(1.0),
-                    offset: Double::literal(0.0),
-                },
-            },
+            time_reference: TimeReference::default(),
             trajectory_ref: None,
             trajectory_following_mode: TrajectoryFollowingMode {
                 following_mode: self.following_mode.unwrap(),
@@ -473,6 +498,59 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_timed_polyline_serializes_in_order() {
+        let trajectory = TrajectoryBuilder::new()
+            .name("timed_path")
+            .polyline()
+            .add_vertex()
+            .at_time(0.0)
+            .at_position(Position::default())
+            .finish()
+            .unwrap()
+            .add_vertex()
+            .at_time(1.5)
+            .world_position(15.0, 0.0, 0.0, 0.0)
+            .finish()
+            .unwrap()
+            .add_vertex()
+            .at_time(3.25)
+            .world_position(30.0, 2.0, 0.0, 0.1)
+            .finish()
+            .unwrap()
+            .finish()
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&trajectory).unwrap();
+        let first = xml.find(r#"time="0""#).unwrap();
+        let second = xml.find(r#"time="1.5""#).unwrap();
+        let third = xml.find(r#"time="3.25""#).unwrap();
+        assert!(first < second && second < third);
+    }
+
+    #[test]
+    fn test_polyline_rejects_non_monotonic_times() {
+        let result = TrajectoryBuilder::new()
+            .name("backwards")
+            .polyline()
+            .add_vertex()
+            .at_time(2.0)
+            .world_position(0.0, 0.0, 0.0, 0.0)
+            .finish()
+            .unwrap()
+            .add_vertex()
+            .at_time(1.0)
+            .world_position(10.0, 0.0, 0.0, 0.0)
+            .finish();
+
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("strictly increasing"));
+    }
+
     #[test]
     fn test_vertex_validation_fails_without_time() {
         let result = TrajectoryBuilder::new()
@@ -579,6 +657,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_follow_trajectory_action_follow_mode_serialization() {
+        let trajectory = TrajectoryBuilder::new()
+            .name("follow_mode_path")
+            .polyline()
+            .add_vertex()
+            .time(0.0)
+            .world_position(0.0, 0.0, 0.0, 0.0)
+            .finish()
+            .unwrap()
+            .finish()
+            .build()
+            .unwrap();
+
+        let action = FollowTrajectoryActionBuilder::new()
+            .with_trajectory(trajectory)
+            .following_mode(FollowingMode::Follow)
+            .build_action()
+            .unwrap();
+        let PrivateAction::RoutingAction(routing) = action else {
+            panic!("Expected RoutingAction");
+        };
+        let follow_action = routing.follow_trajectory_action.unwrap();
+
+        let xml = quick_xml::se::to_string(&follow_action).unwrap();
+        assert!(xml.contains(r#"<TrajectoryFollowingMode followingMode="follow"/>"#));
+        let deserialized: FollowTrajectoryAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, follow_action);
+    }
+
     #[test]
     fn test_follow_trajectory_action_with_offset() {
         let trajectory = TrajectoryBuilder::new()
@@ -0,0 +1,88 @@
+//! User-defined action builder (UserDefinedAction with CustomCommandAction)
+
+use crate::builder::{BuilderError, BuilderResult};
+use crate::types::{
+    actions::wrappers::{Action, CustomCommandAction, UserDefinedAction},
+    basic::OSString,
+};
+
+/// Builder for user-defined actions
+///
+/// The command type and content are passed to the simulator unchanged, so
+/// integrators can trigger behavior OpenSCENARIO has no action for.
+#[derive(Debug, Default)]
+pub struct UserDefinedActionBuilder {
+    command_type: Option<String>,
+    content: String,
+}
+
+impl UserDefinedActionBuilder {
+    /// Create new user-defined action builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the custom command type
+    pub fn command_type(mut self, command_type: &str) -> Self {
+        self.command_type = Some(command_type.to_string());
+        self
+    }
+
+    /// Set the custom command content
+    pub fn content(mut self, content: &str) -> Self {
+        self.content = content.to_string();
+        self
+    }
+
+    /// Build the user-defined action
+    pub fn build(self) -> BuilderResult<UserDefinedAction> {
+        let command_type = self
+            .command_type
+            .ok_or_else(|| BuilderError::missing_field("command_type", ".command_type()"))?;
+
+        Ok(UserDefinedAction {
+            custom_command_action: CustomCommandAction {
+                command_type: OSString::literal(command_type),
+                content: self.content,
+            },
+        })
+    }
+
+    /// Build the user-defined action wrapped as an [`Action`]
+    pub fn build_wrapped(self) -> BuilderResult<Action> {
+        Ok(Action::UserDefinedAction(self.build()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_defined_action_builder() {
+        let action = UserDefinedActionBuilder::new()
+            .command_type("carlaCommand")
+            .content("set_weather rain=0.8")
+            .build()
+            .unwrap();
+
+        let command = &action.custom_command_action;
+        assert_eq!(command.command_type.as_literal().unwrap(), "carlaCommand");
+        assert_eq!(command.content, "set_weather rain=0.8");
+
+        let xml = quick_xml::se::to_string_with_root("UserDefinedAction", &action).unwrap();
+        assert_eq!(
+            xml,
+            "<UserDefinedAction><CustomCommandAction type=\"carlaCommand\">\
+             set_weather rain=0.8</CustomCommandAction></UserDefinedAction>"
+        );
+
+        let parsed: UserDefinedAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, action);
+
+        assert!(UserDefinedActionBuilder::new()
+            .content("noop")
+            .build()
+            .is_err());
+    }
+}
@@ -2,12 +2,16 @@
 
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
-    basic::{Double, OSString},
+    basic::{Boolean, Double, Int, OSString},
     conditions::entity::{
         AccelerationCondition, ByEntityCondition, EndOfRoadCondition, EntityCondition,
-        ReachPositionCondition, SpeedCondition, TraveledDistanceCondition,
+        ReachPositionCondition, RelativeClearanceCondition, RelativeLaneRange, SpeedCondition,
+        TimeHeadwayCondition, TraveledDistanceCondition,
+    },
+    enums::{
+        ConditionEdge, CoordinateSystem, DirectionalDimension, RelativeDistanceType, Rule,
+        TriggeringEntitiesRule,
     },
-    enums::{ConditionEdge, DirectionalDimension, Rule, TriggeringEntitiesRule},
     positions::Position,
     scenario::triggers::{Condition, EntityRef, TriggeringEntities},
 };
@@ -19,6 +23,7 @@ pub struct AccelerationConditionBuilder {
     value: Option<f64>,
     rule: Rule,
     direction: Option<DirectionalDimension>,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for AccelerationConditionBuilder {
@@ -28,6 +33,7 @@ impl Default for AccelerationConditionBuilder {
             value: None,
             rule: Rule::GreaterThan,
             direction: None,
+            edge: None,
         }
     }
 }
@@ -71,6 +77,12 @@ impl AccelerationConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -102,7 +114,7 @@ impl AccelerationConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("AccelerationCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(by_entity_condition),
@@ -116,6 +128,7 @@ pub struct EnhancedSpeedConditionBuilder {
     entity_ref: Option<String>,
     value: Option<f64>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for EnhancedSpeedConditionBuilder {
@@ -124,6 +137,7 @@ impl Default for EnhancedSpeedConditionBuilder {
             entity_ref: None,
             value: None,
             rule: Rule::GreaterThan,
+            edge: None,
         }
     }
 }
@@ -161,6 +175,12 @@ impl EnhancedSpeedConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -191,7 +211,7 @@ impl EnhancedSpeedConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("SpeedCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(by_entity_condition),
@@ -205,6 +225,7 @@ pub struct TraveledDistanceConditionBuilder {
     entity_ref: Option<String>,
     value: Option<f64>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for TraveledDistanceConditionBuilder {
@@ -213,6 +234,7 @@ impl Default for TraveledDistanceConditionBuilder {
             entity_ref: None,
             value: None,
             rule: Rule::GreaterThan,
+            edge: None,
         }
     }
 }
@@ -250,6 +272,12 @@ impl TraveledDistanceConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -277,7 +305,7 @@ impl TraveledDistanceConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("TraveledDistanceCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(by_entity_condition),
@@ -291,6 +319,7 @@ pub struct ReachPositionConditionBuilder {
     entity_ref: Option<String>,
     position: Option<Position>,
     tolerance: Option<f64>,
+    edge: Option<ConditionEdge>,
 }
 
 impl ReachPositionConditionBuilder {
@@ -311,9 +340,25 @@ impl ReachPositionConditionBuilder {
         self
     }
 
-    /// Set tolerance for position matching
-    pub fn with_tolerance(mut self, tolerance: f64) -> Self {
-        self.tolerance = Some(tolerance);
+    /// Set target position (alias for at_position, e.g. from `UnifiedPositionBuilder`)
+    pub fn to_position(self, position: Position) -> Self {
+        self.at_position(position)
+    }
+
+    /// Set tolerance radius in meters (defaults to 1.0)
+    pub fn tolerance(mut self, meters: f64) -> Self {
+        self.tolerance = Some(meters);
+        self
+    }
+
+    /// Set tolerance for position matching (alias for tolerance)
+    pub fn with_tolerance(self, tolerance: f64) -> Self {
+        self.tolerance(tolerance)
+    }
+
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
         self
     }
 
@@ -327,13 +372,20 @@ impl ReachPositionConditionBuilder {
         if self.position.is_none() {
             return Err(BuilderError::validation_error("Position is required"));
         }
+        let tolerance = self.tolerance.unwrap_or(1.0);
+        if !tolerance.is_finite() || tolerance < 0.0 {
+            return Err(BuilderError::validation_error_with_suggestion(
+                &format!(
+                    "Tolerance must be a non-negative distance, got {}",
+                    tolerance
+                ),
+                "Use .tolerance() with a radius in meters, e.g. 2.0",
+            ));
+        }
 
         let reach_position_condition = ReachPositionCondition {
             position: self.position.unwrap(),
-            tolerance: self
-                .tolerance
-                .map(Double::literal)
-                .unwrap_or(Double::literal(1.0)),
+            tolerance: Double::literal(tolerance),
         };
 
         let by_entity_condition = ByEntityCondition {
@@ -348,7 +400,7 @@ impl ReachPositionConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("ReachPositionCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(by_entity_condition),
@@ -361,6 +413,7 @@ impl ReachPositionConditionBuilder {
 pub struct EndOfRoadConditionBuilder {
     entity_ref: Option<String>,
     duration: Option<f64>,
+    edge: Option<ConditionEdge>,
 }
 
 impl EndOfRoadConditionBuilder {
@@ -381,6 +434,12 @@ impl EndOfRoadConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -408,7 +467,308 @@ impl EndOfRoadConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("EndOfRoadCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
+            delay: Some(Double::literal(0.0)),
+            by_value_condition: None,
+            by_entity_condition: Some(by_entity_condition),
+        })
+    }
+}
+
+/// Builder for time headway conditions
+///
+/// The headway is measured from the triggering entity to the target entity.
+/// Defaults to a `lessThan` rule with freespace measurement.
+#[derive(Debug)]
+pub struct TimeHeadwayConditionBuilder {
+    entity_ref: Option<String>,
+    target_entity: Option<String>,
+    value: Option<f64>,
+    rule: Rule,
+    freespace: bool,
+    along_route: Option<bool>,
+    coordinate_system: Option<CoordinateSystem>,
+    relative_distance_type: Option<RelativeDistanceType>,
+    edge: Option<ConditionEdge>,
+}
+
+impl Default for TimeHeadwayConditionBuilder {
+    fn default() -> Self {
+        Self {
+            entity_ref: None,
+            target_entity: None,
+            value: None,
+            rule: Rule::LessThan,
+            freespace: true,
+            along_route: None,
+            coordinate_system: None,
+            relative_distance_type: None,
+            edge: None,
+        }
+    }
+}
+
+impl TimeHeadwayConditionBuilder {
+    /// Create new time headway condition builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the triggering entity
+    pub fn for_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Set the entity the headway is measured to
+    pub fn target_entity(mut self, target_entity: &str) -> Self {
+        self.target_entity = Some(target_entity.to_string());
+        self
+    }
+
+    /// Set the headway threshold in seconds
+    pub fn value(mut self, seconds: f64) -> Self {
+        self.value = Some(seconds);
+        self
+    }
+
+    /// Set the comparison rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Measure between bounding boxes (true) or reference points (false)
+    pub fn freespace(mut self, freespace: bool) -> Self {
+        self.freespace = freespace;
+        self
+    }
+
+    /// Measure along the route (deprecated in favor of the coordinate system)
+    pub fn along_route(mut self, along_route: bool) -> Self {
+        self.along_route = Some(along_route);
+        self
+    }
+
+    /// Set the coordinate system for measurement
+    pub fn coordinate_system(mut self, system: CoordinateSystem) -> Self {
+        self.coordinate_system = Some(system);
+        self
+    }
+
+    /// Set the relative distance type for measurement
+    pub fn relative_distance_type(mut self, distance_type: RelativeDistanceType) -> Self {
+        self.relative_distance_type = Some(distance_type);
+        self
+    }
+
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
+    /// Build the condition
+    pub fn build(self) -> BuilderResult<Condition> {
+        let Some(entity_ref) = self.entity_ref else {
+            return Err(BuilderError::validation_error(
+                "Entity reference is required",
+            ));
+        };
+        let Some(target_entity) = self.target_entity else {
+            return Err(BuilderError::validation_error("Target entity is required"));
+        };
+        let Some(value) = self.value else {
+            return Err(BuilderError::validation_error(
+                "Time headway value is required",
+            ));
+        };
+        if !value.is_finite() || value < 0.0 {
+            return Err(BuilderError::validation_error(&format!(
+                "Time headway must be a non-negative number of seconds, got {}",
+                value
+            )));
+        }
+
+        let headway_condition = TimeHeadwayCondition {
+            along_route: self.along_route.map(Boolean::literal),
+            coordinate_system: self.coordinate_system,
+            relative_distance_type: self.relative_distance_type,
+            ..TimeHeadwayCondition::new(&target_entity, value, self.rule, self.freespace)
+        };
+
+        let by_entity_condition = ByEntityCondition {
+            triggering_entities: TriggeringEntities {
+                triggering_entities_rule: TriggeringEntitiesRule::Any,
+                entity_refs: vec![EntityRef {
+                    entity_ref: OSString::literal(entity_ref),
+                }],
+            },
+            entity_condition: EntityCondition::TimeHeadway(headway_condition),
+        };
+
+        Ok(Condition {
+            name: OSString::literal("TimeHeadwayCondition".to_string()),
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
+            delay: Some(Double::literal(0.0)),
+            by_value_condition: None,
+            by_entity_condition: Some(by_entity_condition),
+        })
+    }
+}
+
+/// Builder for relative clearance conditions
+///
+/// Checks that the lanes around the triggering entity are free within the
+/// given forward and backward distances. Defaults to freespace measurement
+/// without opposite lanes.
+#[derive(Debug)]
+pub struct RelativeClearanceConditionBuilder {
+    entity_ref: Option<String>,
+    lane_ranges: Vec<(Option<i32>, Option<i32>)>,
+    entities: Vec<String>,
+    opposite_lanes: bool,
+    distance_forward: Option<f64>,
+    distance_backward: Option<f64>,
+    free_space: bool,
+    edge: Option<ConditionEdge>,
+}
+
+impl Default for RelativeClearanceConditionBuilder {
+    fn default() -> Self {
+        Self {
+            entity_ref: None,
+            lane_ranges: Vec::new(),
+            entities: Vec::new(),
+            opposite_lanes: false,
+            distance_forward: None,
+            distance_backward: None,
+            free_space: true,
+            edge: None,
+        }
+    }
+}
+
+impl RelativeClearanceConditionBuilder {
+    /// Create new relative clearance condition builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the triggering entity
+    pub fn for_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Check the lanes from `from` to `to`, relative to the entity's lane
+    pub fn lane_range(mut self, from: i32, to: i32) -> Self {
+        self.lane_ranges.push((Some(from), Some(to)));
+        self
+    }
+
+    /// Add an entity to check clearance against
+    pub fn against_entity(mut self, entity_ref: &str) -> Self {
+        self.entities.push(entity_ref.to_string());
+        self
+    }
+
+    /// Include lanes with the opposite driving direction
+    pub fn opposite_lanes(mut self, opposite_lanes: bool) -> Self {
+        self.opposite_lanes = opposite_lanes;
+        self
+    }
+
+    /// Set the distance to check ahead of the entity in meters
+    pub fn distance_forward(mut self, meters: f64) -> Self {
+        self.distance_forward = Some(meters);
+        self
+    }
+
+    /// Set the distance to check behind the entity in meters
+    pub fn distance_backward(mut self, meters: f64) -> Self {
+        self.distance_backward = Some(meters);
+        self
+    }
+
+    /// Measure between bounding boxes (true) or reference points (false)
+    pub fn freespace(mut self, free_space: bool) -> Self {
+        self.free_space = free_space;
+        self
+    }
+
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
+    /// Build the condition
+    pub fn build(self) -> BuilderResult<Condition> {
+        let Some(entity_ref) = self.entity_ref else {
+            return Err(BuilderError::validation_error(
+                "Entity reference is required",
+            ));
+        };
+        for (name, distance) in [
+            ("forward", self.distance_forward),
+            ("backward", self.distance_backward),
+        ] {
+            if let Some(distance) = distance {
+                if !distance.is_finite() || distance < 0.0 {
+                    return Err(BuilderError::validation_error(&format!(
+                        "Clearance distance {} must be a non-negative number, got {}",
+                        name, distance
+                    )));
+                }
+            }
+        }
+        for (from, to) in &self.lane_ranges {
+            if let (Some(from), Some(to)) = (from, to) {
+                if from > to {
+                    return Err(BuilderError::validation_error(&format!(
+                        "Lane range start {} is greater than end {}",
+                        from, to
+                    )));
+                }
+            }
+        }
+
+        let clearance_condition = RelativeClearanceCondition {
+            relative_lane_ranges: self
+                .lane_ranges
+                .into_iter()
+                .map(|(from, to)| RelativeLaneRange {
+                    from: from.map(Int::literal),
+                    to: to.map(Int::literal),
+                })
+                .collect(),
+            entity_refs: self
+                .entities
+                .into_iter()
+                .map(|entity_ref| EntityRef {
+                    entity_ref: OSString::literal(entity_ref),
+                })
+                .collect(),
+            opposite_lanes: Boolean::literal(self.opposite_lanes),
+            distance_forward: self.distance_forward.map(Double::literal),
+            distance_backward: self.distance_backward.map(Double::literal),
+            free_space: Boolean::literal(self.free_space),
+        };
+
+        let by_entity_condition = ByEntityCondition {
+            triggering_entities: TriggeringEntities {
+                triggering_entities_rule: TriggeringEntitiesRule::Any,
+                entity_refs: vec![EntityRef {
+                    entity_ref: OSString::literal(entity_ref),
+                }],
+            },
+            entity_condition: EntityCondition::RelativeClearance(clearance_condition),
+        };
+
+        Ok(Condition {
+            name: OSString::literal("RelativeClearanceCondition".to_string()),
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(by_entity_condition),
@@ -440,6 +800,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_lateral_acceleration_condition_builder_roundtrip() {
+        let condition = AccelerationConditionBuilder::new()
+            .for_entity("ego")
+            .acceleration_above(3.0)
+            .with_direction(DirectionalDimension::Lateral)
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&condition).unwrap();
+        assert!(xml.contains(r#"direction="lateral""#));
+
+        let deserialized: Condition = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(condition, deserialized);
+    }
+
     #[test]
     fn test_speed_condition_builder() {
         let condition = EnhancedSpeedConditionBuilder::new()
@@ -541,4 +917,158 @@ mod tests {
             panic!("Expected ByEntityCondition");
         }
     }
+
+    #[test]
+    fn test_reach_position_condition_toward_world_position() {
+        use crate::builder::positions::UnifiedPositionBuilder;
+
+        let position = UnifiedPositionBuilder::world()
+            .at_coordinates(100.0, 5.0, 0.0)
+            .build()
+            .unwrap();
+
+        let condition = ReachPositionConditionBuilder::new()
+            .for_entity("ego")
+            .to_position(position)
+            .tolerance(2.0)
+            .build()
+            .unwrap();
+
+        let by_entity = condition.by_entity_condition.unwrap();
+        let EntityCondition::ReachPosition(reach) = by_entity.entity_condition else {
+            panic!("Expected ReachPosition condition");
+        };
+        assert_eq!(*reach.tolerance.as_literal().unwrap(), 2.0);
+        let world = reach.position.world_position.unwrap();
+        assert_eq!(*world.x.as_literal().unwrap(), 100.0);
+        assert_eq!(*world.y.as_literal().unwrap(), 5.0);
+    }
+
+    #[test]
+    fn test_reach_position_condition_tolerance_default_and_validation() {
+        let position = Position::default();
+
+        let condition = ReachPositionConditionBuilder::new()
+            .for_entity("ego")
+            .to_position(position.clone())
+            .build()
+            .unwrap();
+        let EntityCondition::ReachPosition(reach) =
+            condition.by_entity_condition.unwrap().entity_condition
+        else {
+            panic!("Expected ReachPosition condition");
+        };
+        assert_eq!(*reach.tolerance.as_literal().unwrap(), 1.0);
+
+        let result = ReachPositionConditionBuilder::new()
+            .for_entity("ego")
+            .to_position(position)
+            .tolerance(-0.5)
+            .build();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_time_headway_condition_builder() {
+        use crate::builder::conditions::TriggerBuilder;
+
+        let condition = TimeHeadwayConditionBuilder::new()
+            .for_entity("ego")
+            .target_entity("lead")
+            .rule(Rule::LessThan)
+            .value(1.5)
+            .freespace(false)
+            .along_route(false)
+            .coordinate_system(CoordinateSystem::Road)
+            .relative_distance_type(RelativeDistanceType::Longitudinal)
+            .build()
+            .unwrap();
+        let trigger = TriggerBuilder::new()
+            .add_condition(condition)
+            .build()
+            .unwrap();
+
+        let condition = &trigger.condition_groups[0].conditions[0];
+        let by_entity = condition.by_entity_condition.as_ref().unwrap();
+        assert_eq!(
+            by_entity.triggering_entities.entity_refs[0].entity_ref,
+            OSString::literal("ego".to_string())
+        );
+        let EntityCondition::TimeHeadway(headway) = &by_entity.entity_condition else {
+            panic!("Expected TimeHeadway condition");
+        };
+        assert_eq!(headway.entity_ref, OSString::literal("lead".to_string()));
+        assert_eq!(headway.value.as_literal(), Some(&1.5));
+        assert_eq!(headway.rule, Rule::LessThan);
+        assert_eq!(headway.freespace.as_literal(), Some(&false));
+
+        let xml = quick_xml::se::to_string(condition).unwrap();
+        assert!(xml.contains(r#"alongRoute="false""#));
+        assert!(xml.contains(r#"coordinateSystem="road""#));
+        let deserialized: Condition = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(*condition, deserialized);
+
+        assert!(TimeHeadwayConditionBuilder::new()
+            .for_entity("ego")
+            .value(1.5)
+            .build()
+            .is_err());
+        assert!(TimeHeadwayConditionBuilder::new()
+            .for_entity("ego")
+            .target_entity("lead")
+            .value(-1.0)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_relative_clearance_condition_builder() {
+        let condition = RelativeClearanceConditionBuilder::new()
+            .for_entity("ego")
+            .lane_range(-1, 1)
+            .against_entity("merging")
+            .distance_forward(40.0)
+            .distance_backward(15.0)
+            .freespace(false)
+            .build()
+            .unwrap();
+
+        let by_entity = condition.by_entity_condition.as_ref().unwrap();
+        let EntityCondition::RelativeClearance(clearance) = &by_entity.entity_condition else {
+            panic!("Expected RelativeClearance condition");
+        };
+        assert_eq!(clearance.relative_lane_ranges.len(), 1);
+        assert_eq!(
+            clearance.relative_lane_ranges[0].from,
+            Some(Int::literal(-1))
+        );
+        assert_eq!(
+            clearance.entity_refs[0].entity_ref.as_literal().unwrap(),
+            "merging"
+        );
+        assert_eq!(clearance.opposite_lanes.as_literal(), Some(&false));
+        assert_eq!(clearance.free_space.as_literal(), Some(&false));
+
+        let xml = quick_xml::se::to_string(&condition).unwrap();
+        assert!(xml.contains(r#"distanceForward="40""#));
+        assert!(xml.contains(r#"distanceBackward="15""#));
+        assert!(xml.contains(r#"<RelativeLaneRange from="-1" to="1"/>"#));
+        let deserialized: Condition = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(condition, deserialized);
+
+        assert!(RelativeClearanceConditionBuilder::new()
+            .distance_forward(10.0)
+            .build()
+            .is_err());
+        assert!(RelativeClearanceConditionBuilder::new()
+            .for_entity("ego")
+            .distance_backward(-5.0)
+            .build()
+            .is_err());
+        assert!(RelativeClearanceConditionBuilder::new()
+            .for_entity("ego")
+            .lane_range(2, -2)
+            .build()
+            .is_err());
+    }
 }
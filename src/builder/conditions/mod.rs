@@ -23,7 +23,8 @@ pub mod value;
 
 pub use entity::{
     AccelerationConditionBuilder, EndOfRoadConditionBuilder, EnhancedSpeedConditionBuilder,
-    ReachPositionConditionBuilder, TraveledDistanceConditionBuilder,
+    ReachPositionConditionBuilder, RelativeClearanceConditionBuilder, TimeHeadwayConditionBuilder,
+    TraveledDistanceConditionBuilder,
 };
 pub use spatial::{
     CollisionConditionBuilder, DistanceConditionBuilder, RelativeDistanceConditionBuilder,
@@ -31,7 +32,8 @@ pub use spatial::{
 pub use value::{
     ParameterConditionBuilder, SpeedConditionBuilder,
     SpeedConditionBuilder as ValueSpeedConditionBuilder, StoryboardElementStateConditionBuilder,
-    TimeConditionBuilder, VariableConditionBuilder,
+    TimeConditionBuilder, TimeOfDayConditionBuilder, UserDefinedValueConditionBuilder,
+    VariableConditionBuilder,
 };
 
 use crate::builder::{BuilderError, BuilderResult};
@@ -70,6 +72,31 @@ impl TriggerBuilder {
         self
     }
 
+    /// Add a group whose conditions must all be true (AND logic)
+    ///
+    /// The closure receives an empty condition group to add conditions to.
+    /// Nothing is added if it returns the group without conditions.
+    pub fn all_of(
+        self,
+        group: impl FnOnce(ConditionGroupBuilder) -> ConditionGroupBuilder,
+    ) -> Self {
+        group(self.add_condition_group()).finish_group()
+    }
+
+    /// Add conditions of which any one fires the trigger (OR logic)
+    ///
+    /// The closure receives an empty condition group; each condition added to
+    /// it becomes a group of its own.
+    pub fn any_of(
+        self,
+        group: impl FnOnce(ConditionGroupBuilder) -> ConditionGroupBuilder,
+    ) -> Self {
+        group(ConditionGroupBuilder::new(TriggerBuilder::new()))
+            .conditions
+            .into_iter()
+            .fold(self, Self::add_condition)
+    }
+
     /// Build the trigger
     pub fn build(self) -> BuilderResult<Trigger> {
         if self.condition_groups.is_empty() {
@@ -235,4 +262,41 @@ mod tests {
         assert_eq!(trigger.condition_groups.len(), 1);
         assert_eq!(trigger.condition_groups[0].conditions.len(), 2);
     }
+
+    #[test]
+    fn test_nested_groups_with_closures() {
+        let a = TimeConditionBuilder::new().at_time(1.0).build().unwrap();
+        let b = ValueSpeedConditionBuilder::new()
+            .for_entity("ego")
+            .speed_above(10.0)
+            .build()
+            .unwrap();
+        let c = TimeConditionBuilder::new().at_time(20.0).build().unwrap();
+
+        // (A AND B) OR (C)
+        let trigger = TriggerBuilder::new()
+            .all_of(|g| g.add_condition(a.clone()).add_condition(b.clone()))
+            .any_of(|g| g.add_condition(c.clone()))
+            .build()
+            .unwrap();
+
+        assert_eq!(trigger.condition_groups.len(), 2);
+        assert_eq!(
+            trigger.condition_groups[0].conditions,
+            vec![a.clone(), b.clone()]
+        );
+        assert_eq!(trigger.condition_groups[1].conditions, vec![c]);
+
+        // Each condition of any_of is a separate group, empty groups are dropped
+        let trigger = TriggerBuilder::new()
+            .any_of(|g| g.add_condition(a).add_condition(b))
+            .all_of(|g| g)
+            .build()
+            .unwrap();
+        assert_eq!(trigger.condition_groups.len(), 2);
+        assert!(trigger
+            .condition_groups
+            .iter()
+            .all(|group| group.conditions.len() == 1));
+    }
 }
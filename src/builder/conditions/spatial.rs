@@ -30,6 +30,7 @@ pub struct DistanceConditionBuilder {
     distance: Option<f64>,
     rule: Rule,
     freespace: bool,
+    edge: Option<ConditionEdge>,
 }
 
 impl DistanceConditionBuilder {
@@ -41,6 +42,7 @@ impl DistanceConditionBuilder {
             distance: None,
             rule: Rule::LessThan,
             freespace: false,
+            edge: None,
         }
     }
 
@@ -83,6 +85,12 @@ impl DistanceConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -103,7 +111,7 @@ impl DistanceConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("DistanceCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(ByEntityCondition {
@@ -137,6 +145,7 @@ pub struct RelativeDistanceConditionBuilder {
     rule: Rule,
     freespace: bool,
     relative_distance_type: RelativeDistanceType,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for RelativeDistanceConditionBuilder {
@@ -148,6 +157,7 @@ impl Default for RelativeDistanceConditionBuilder {
             rule: Rule::LessThan,
             freespace: true,
             relative_distance_type: RelativeDistanceType::Cartesian,
+            edge: None,
         }
     }
 }
@@ -206,6 +216,12 @@ impl RelativeDistanceConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -225,7 +241,7 @@ impl RelativeDistanceConditionBuilder {
         // Create a relative distance condition using entity condition structure
         Ok(Condition {
             name: OSString::literal("RelativeDistanceCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(ByEntityCondition {
@@ -257,6 +273,7 @@ pub struct CollisionConditionBuilder {
     entity_ref: Option<String>,
     target_entity: Option<String>,
     collision_type: Option<String>,
+    edge: Option<ConditionEdge>,
 }
 
 impl CollisionConditionBuilder {
@@ -283,6 +300,12 @@ impl CollisionConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -293,7 +316,7 @@ impl CollisionConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("CollisionCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(ByEntityCondition {
@@ -6,9 +6,11 @@
 //! # Supported Conditions
 //!
 //! - **SimulationTimeCondition**: Triggers at specific simulation times
+//! - **TimeOfDayCondition**: Triggers at a simulated date and time
 //! - **SpeedCondition**: Triggers when entity speed meets criteria
 //! - **ParameterCondition**: Triggers based on parameter values
 //! - **VariableCondition**: Triggers based on variable state changes
+//! - **UserDefinedValueCondition**: Triggers on simulator specific values
 //!
 //! # Usage
 //!
@@ -23,13 +25,14 @@
 
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
-    basic::{Double, OSString},
+    basic::{DateTime, Double, OSString},
     conditions::entity::{
         ByEntityCondition, EntityCondition, SpeedCondition as EntitySpeedCondition,
     },
     conditions::value::{
         ByValueCondition, ParameterCondition, SimulationTimeCondition,
-        StoryboardElementStateCondition, VariableCondition,
+        StoryboardElementStateCondition, TimeOfDayCondition, UserDefinedValueCondition,
+        VariableCondition,
     },
     enums::{
         ConditionEdge, Rule, StoryboardElementState, StoryboardElementType, TriggeringEntitiesRule,
@@ -45,6 +48,7 @@ use crate::types::{
 pub struct TimeConditionBuilder {
     time: Option<f64>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl TimeConditionBuilder {
@@ -53,6 +57,7 @@ impl TimeConditionBuilder {
         Self {
             time: None,
             rule: Rule::GreaterThan,
+            edge: None,
         }
     }
 
@@ -70,6 +75,12 @@ impl TimeConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.time.is_none() {
@@ -78,7 +89,7 @@ impl TimeConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("TimeCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: Some(ByValueCondition {
                 parameter_condition: None,
@@ -98,6 +109,94 @@ impl TimeConditionBuilder {
     }
 }
 
+/// Builder for time of day conditions
+///
+/// Creates conditions that compare the simulated date and time against an
+/// ISO 8601 timestamp. Timestamps without an offset are taken as UTC.
+#[derive(Debug)]
+pub struct TimeOfDayConditionBuilder {
+    date_time: Option<String>,
+    rule: Rule,
+    edge: Option<ConditionEdge>,
+}
+
+impl Default for TimeOfDayConditionBuilder {
+    fn default() -> Self {
+        Self {
+            date_time: None,
+            rule: Rule::GreaterThan,
+            edge: None,
+        }
+    }
+}
+
+impl TimeOfDayConditionBuilder {
+    /// Create new time of day condition builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the comparison rule (defaults to greater than)
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the date and time, e.g. `2024-06-01T12:00:00`
+    pub fn datetime(mut self, date_time: &str) -> Self {
+        self.date_time = Some(date_time.to_string());
+        self
+    }
+
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
+    /// Build the condition
+    pub fn build(self) -> BuilderResult<Condition> {
+        let date_time = self
+            .date_time
+            .ok_or_else(|| BuilderError::validation_error("Date and time are required"))?;
+        let date_time = parse_date_time(&date_time)?;
+
+        Ok(Condition {
+            name: OSString::literal("TimeOfDayCondition".to_string()),
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
+            delay: Some(Double::literal(0.0)),
+            by_value_condition: Some(ByValueCondition {
+                parameter_condition: None,
+                time_of_day_condition: Some(TimeOfDayCondition {
+                    date_time: DateTime::literal(date_time),
+                    rule: self.rule,
+                }),
+                simulation_time_condition: None,
+                storyboard_element_state_condition: None,
+                user_defined_value_condition: None,
+                traffic_signal_condition: None,
+                traffic_signal_controller_condition: None,
+                variable_condition: None,
+            }),
+            by_entity_condition: None,
+        })
+    }
+}
+
+fn parse_date_time(date_time: &str) -> BuilderResult<chrono::DateTime<chrono::Utc>> {
+    if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(date_time) {
+        return Ok(parsed.with_timezone(&chrono::Utc));
+    }
+    chrono::NaiveDateTime::parse_from_str(date_time, "%Y-%m-%dT%H:%M:%S%.f")
+        .map(|naive| naive.and_utc())
+        .map_err(|_| {
+            BuilderError::validation_error_with_suggestion(
+                &format!("Invalid date and time '{}'", date_time),
+                "Use ISO 8601 format, e.g. 2024-06-01T12:00:00 or 2024-06-01T12:00:00+02:00",
+            )
+        })
+}
+
 /// Builder for speed conditions
 ///
 /// Creates conditions that trigger when an entity's speed meets certain criteria.
@@ -108,6 +207,7 @@ pub struct SpeedConditionBuilder {
     entity_ref: Option<String>,
     speed: Option<f64>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for SpeedConditionBuilder {
@@ -116,6 +216,7 @@ impl Default for SpeedConditionBuilder {
             entity_ref: None,
             speed: None,
             rule: Rule::GreaterThan,
+            edge: None,
         }
     }
 }
@@ -127,6 +228,7 @@ impl SpeedConditionBuilder {
             entity_ref: None,
             speed: None,
             rule: Rule::GreaterThan,
+            edge: None,
         }
     }
 
@@ -157,6 +259,12 @@ impl SpeedConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.entity_ref.is_none() {
@@ -172,7 +280,7 @@ impl SpeedConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("SpeedCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: None,
             by_entity_condition: Some(ByEntityCondition {
@@ -197,8 +305,9 @@ impl SpeedConditionBuilder {
 #[derive(Debug)]
 pub struct ParameterConditionBuilder {
     parameter_ref: Option<String>,
-    value: Option<f64>,
+    value: Option<String>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for ParameterConditionBuilder {
@@ -207,6 +316,7 @@ impl Default for ParameterConditionBuilder {
             parameter_ref: None,
             value: None,
             rule: Rule::EqualTo,
+            edge: None,
         }
     }
 }
@@ -223,27 +333,48 @@ impl ParameterConditionBuilder {
         self
     }
 
+    /// Set the comparison rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the value to compare against
+    ///
+    /// The value is kept as text, so string and boolean parameters can be
+    /// compared as well as numeric ones.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
     /// Set parameter value threshold (above)
     pub fn value_above(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::GreaterThan;
         self
     }
 
     /// Set parameter value threshold (below)
     pub fn value_below(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::LessThan;
         self
     }
 
     /// Set exact parameter value
     pub fn value_equals(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::EqualTo;
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.parameter_ref.is_none() {
@@ -259,12 +390,12 @@ impl ParameterConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("ParameterCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: Some(ByValueCondition {
                 parameter_condition: Some(ParameterCondition {
                     parameter_ref: OSString::literal(self.parameter_ref.unwrap()),
-                    value: OSString::literal(self.value.unwrap().to_string()),
+                    value: OSString::literal(self.value.unwrap()),
                     rule: self.rule,
                 }),
                 time_of_day_condition: None,
@@ -284,8 +415,9 @@ impl ParameterConditionBuilder {
 #[derive(Debug)]
 pub struct VariableConditionBuilder {
     variable_ref: Option<String>,
-    value: Option<f64>,
+    value: Option<String>,
     rule: Rule,
+    edge: Option<ConditionEdge>,
 }
 
 impl Default for VariableConditionBuilder {
@@ -294,6 +426,7 @@ impl Default for VariableConditionBuilder {
             variable_ref: None,
             value: None,
             rule: Rule::EqualTo,
+            edge: None,
         }
     }
 }
@@ -310,27 +443,48 @@ impl VariableConditionBuilder {
         self
     }
 
+    /// Set the comparison rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the value to compare against
+    ///
+    /// The value is kept as text, so string and boolean variables can be
+    /// compared as well as numeric ones.
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
     /// Set variable value threshold (above)
     pub fn value_above(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::GreaterThan;
         self
     }
 
     /// Set variable value threshold (below)
     pub fn value_below(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::LessThan;
         self
     }
 
     /// Set exact variable value
     pub fn value_equals(mut self, value: f64) -> Self {
-        self.value = Some(value);
+        self.value = Some(value.to_string());
         self.rule = Rule::EqualTo;
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.variable_ref.is_none() {
@@ -344,7 +498,7 @@ impl VariableConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("VariableCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: Some(ByValueCondition {
                 parameter_condition: None,
@@ -356,9 +510,98 @@ impl VariableConditionBuilder {
                 traffic_signal_controller_condition: None,
                 variable_condition: Some(VariableCondition {
                     variable_ref: OSString::literal(self.variable_ref.unwrap()),
-                    value: OSString::literal(self.value.unwrap().to_string()),
+                    value: OSString::literal(self.value.unwrap()),
+                    rule: self.rule,
+                }),
+            }),
+            by_entity_condition: None,
+        })
+    }
+}
+
+/// Builder for user-defined value conditions
+///
+/// Compares a value provided by the simulator under the given name, for
+/// signals OpenSCENARIO has no dedicated condition for.
+#[derive(Debug)]
+pub struct UserDefinedValueConditionBuilder {
+    name: Option<String>,
+    value: Option<String>,
+    rule: Rule,
+    edge: Option<ConditionEdge>,
+}
+
+impl Default for UserDefinedValueConditionBuilder {
+    fn default() -> Self {
+        Self {
+            name: None,
+            value: None,
+            rule: Rule::EqualTo,
+            edge: None,
+        }
+    }
+}
+
+impl UserDefinedValueConditionBuilder {
+    /// Create new user-defined value condition builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the name of the user-defined value
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the comparison rule
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rule = rule;
+        self
+    }
+
+    /// Set the value to compare against
+    pub fn value(mut self, value: &str) -> Self {
+        self.value = Some(value.to_string());
+        self
+    }
+
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
+    /// Build the condition
+    pub fn build(self) -> BuilderResult<Condition> {
+        if self.name.is_none() {
+            return Err(BuilderError::validation_error(
+                "User-defined value name is required",
+            ));
+        }
+        if self.value.is_none() {
+            return Err(BuilderError::validation_error(
+                "User-defined value is required",
+            ));
+        }
+
+        Ok(Condition {
+            name: OSString::literal("UserDefinedValueCondition".to_string()),
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
+            delay: Some(Double::literal(0.0)),
+            by_value_condition: Some(ByValueCondition {
+                parameter_condition: None,
+                time_of_day_condition: None,
+                simulation_time_condition: None,
+                storyboard_element_state_condition: None,
+                user_defined_value_condition: Some(UserDefinedValueCondition {
+                    name: OSString::literal(self.name.unwrap()),
                     rule: self.rule,
+                    value: OSString::literal(self.value.unwrap()),
                 }),
+                traffic_signal_condition: None,
+                traffic_signal_controller_condition: None,
+                variable_condition: None,
             }),
             by_entity_condition: None,
         })
@@ -371,6 +614,7 @@ pub struct StoryboardElementStateConditionBuilder {
     storyboard_element_type: Option<StoryboardElementType>,
     storyboard_element_ref: Option<String>,
     state: Option<StoryboardElementState>,
+    edge: Option<ConditionEdge>,
 }
 
 impl StoryboardElementStateConditionBuilder {
@@ -379,6 +623,13 @@ impl StoryboardElementStateConditionBuilder {
         Self::default()
     }
 
+    /// Set the referenced storyboard element by type and name
+    pub fn element(mut self, element_type: StoryboardElementType, element_ref: &str) -> Self {
+        self.storyboard_element_type = Some(element_type);
+        self.storyboard_element_ref = Some(element_ref.to_string());
+        self
+    }
+
     /// Set story element
     pub fn story(mut self, story_ref: &str) -> Self {
         self.storyboard_element_type = Some(StoryboardElementType::Story);
@@ -400,6 +651,12 @@ impl StoryboardElementStateConditionBuilder {
         self
     }
 
+    /// Set the state or transition to wait for
+    pub fn state(mut self, state: StoryboardElementState) -> Self {
+        self.state = Some(state);
+        self
+    }
+
     /// Set state to complete
     pub fn complete(mut self) -> Self {
         self.state = Some(StoryboardElementState::CompleteState);
@@ -418,6 +675,12 @@ impl StoryboardElementStateConditionBuilder {
         self
     }
 
+    /// Set the condition edge (defaults to rising)
+    pub fn edge(mut self, edge: ConditionEdge) -> Self {
+        self.edge = Some(edge);
+        self
+    }
+
     /// Build the condition
     pub fn build(self) -> BuilderResult<Condition> {
         if self.storyboard_element_type.is_none() {
@@ -436,7 +699,7 @@ impl StoryboardElementStateConditionBuilder {
 
         Ok(Condition {
             name: OSString::literal("StoryboardElementStateCondition".to_string()),
-            condition_edge: ConditionEdge::Rising,
+            condition_edge: self.edge.unwrap_or(ConditionEdge::Rising),
             delay: Some(Double::literal(0.0)),
             by_value_condition: Some(ByValueCondition {
                 parameter_condition: None,
@@ -475,6 +738,68 @@ mod tests {
         assert_eq!(time_condition.rule, Rule::GreaterThan);
     }
 
+    #[test]
+    fn test_falling_edge_speed_condition() {
+        let condition = SpeedConditionBuilder::new()
+            .for_entity("ego")
+            .speed_above(20.0)
+            .edge(ConditionEdge::Falling)
+            .build()
+            .unwrap();
+
+        assert_eq!(condition.condition_edge, ConditionEdge::Falling);
+
+        let xml = quick_xml::se::to_string(&condition).unwrap();
+        assert!(xml.contains(r#"conditionEdge="falling""#));
+    }
+
+    #[test]
+    fn test_condition_edge_defaults_to_rising_and_accepts_none() {
+        let condition = TimeConditionBuilder::new().at_time(1.0).build().unwrap();
+        assert_eq!(condition.condition_edge, ConditionEdge::Rising);
+
+        let condition = TimeConditionBuilder::new()
+            .at_time(1.0)
+            .edge(ConditionEdge::None)
+            .build()
+            .unwrap();
+        assert_eq!(condition.condition_edge, ConditionEdge::None);
+        assert_eq!(ConditionEdge::None.to_string(), "none");
+    }
+
+    #[test]
+    fn test_storyboard_element_state_condition_waits_for_act() {
+        let condition = StoryboardElementStateConditionBuilder::new()
+            .element(StoryboardElementType::Act, "CutInAct")
+            .state(StoryboardElementState::CompleteState)
+            .build()
+            .unwrap();
+
+        let state_condition = condition
+            .by_value_condition
+            .unwrap()
+            .storyboard_element_state_condition
+            .unwrap();
+        assert_eq!(
+            state_condition.storyboard_element_type,
+            StoryboardElementType::Act
+        );
+        assert_eq!(
+            state_condition.storyboard_element_ref.as_literal().unwrap(),
+            "CutInAct"
+        );
+        assert_eq!(state_condition.state, StoryboardElementState::CompleteState);
+
+        let xml = quick_xml::se::to_string(&state_condition).unwrap();
+        assert!(xml.contains("storyboardElementType=\"act\""));
+        assert!(xml.contains("state=\"completeState\""));
+
+        let missing_state = StoryboardElementStateConditionBuilder::new()
+            .element(StoryboardElementType::Maneuver, "Overtake")
+            .build();
+        assert!(missing_state.is_err());
+    }
+
     #[test]
     fn test_time_condition_with_rule() {
         let condition = TimeConditionBuilder::new()
@@ -555,4 +880,132 @@ mod tests {
             .to_string()
             .contains("Speed value is required"));
     }
+
+    #[test]
+    fn test_parameter_equals_condition() {
+        let condition = ParameterConditionBuilder::new()
+            .parameter("Weather")
+            .rule(Rule::EqualTo)
+            .value("rain")
+            .build()
+            .unwrap();
+
+        let parameter = condition
+            .by_value_condition
+            .unwrap()
+            .parameter_condition
+            .unwrap();
+        assert_eq!(parameter.parameter_ref.as_literal().unwrap(), "Weather");
+        assert_eq!(parameter.value.as_literal().unwrap(), "rain");
+        assert_eq!(parameter.rule, Rule::EqualTo);
+    }
+
+    #[test]
+    fn test_variable_greater_than_condition() {
+        let condition = VariableConditionBuilder::new()
+            .variable("LapCount")
+            .value("3")
+            .rule(Rule::GreaterThan)
+            .build()
+            .unwrap();
+
+        let by_value = condition.by_value_condition.unwrap();
+        assert!(by_value.parameter_condition.is_none());
+        let variable = by_value.variable_condition.unwrap();
+        assert_eq!(variable.variable_ref.as_literal().unwrap(), "LapCount");
+        assert_eq!(variable.value.as_literal().unwrap(), "3");
+        assert_eq!(variable.rule, Rule::GreaterThan);
+
+        assert!(VariableConditionBuilder::new()
+            .variable("LapCount")
+            .rule(Rule::GreaterThan)
+            .build()
+            .is_err());
+    }
+
+    #[test]
+    fn test_time_of_day_condition_round_trip() {
+        let condition = TimeOfDayConditionBuilder::new()
+            .datetime("2024-06-01T12:30:00")
+            .rule(Rule::GreaterOrEqual)
+            .build()
+            .unwrap();
+        let trigger = crate::builder::conditions::TriggerBuilder::new()
+            .add_condition(condition)
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string_with_root("StartTrigger", &trigger).unwrap();
+        assert!(xml.contains("<TimeOfDayCondition"));
+        assert!(xml.contains("rule=\"greaterOrEqual\""));
+
+        let parsed: crate::types::scenario::triggers::Trigger =
+            quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, trigger);
+
+        let time_of_day = parsed.condition_groups[0].conditions[0]
+            .by_value_condition
+            .as_ref()
+            .unwrap()
+            .time_of_day_condition
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            time_of_day.date_time.as_literal().unwrap().to_rfc3339(),
+            "2024-06-01T12:30:00+00:00"
+        );
+
+        // Offsets are normalized to UTC
+        let condition = TimeOfDayConditionBuilder::new()
+            .datetime("2024-06-01T14:30:00+02:00")
+            .build()
+            .unwrap();
+        let time_of_day = condition
+            .by_value_condition
+            .unwrap()
+            .time_of_day_condition
+            .unwrap();
+        assert_eq!(time_of_day.rule, Rule::GreaterThan);
+        assert_eq!(
+            time_of_day.date_time.as_literal().unwrap().to_rfc3339(),
+            "2024-06-01T12:30:00+00:00"
+        );
+
+        assert!(TimeOfDayConditionBuilder::new()
+            .datetime("June 1st")
+            .build()
+            .is_err());
+        assert!(TimeOfDayConditionBuilder::new().build().is_err());
+    }
+
+    #[test]
+    fn test_user_defined_value_condition_builder() {
+        let condition = UserDefinedValueConditionBuilder::new()
+            .name("driverAttention")
+            .rule(Rule::LessThan)
+            .value("0.3")
+            .build()
+            .unwrap();
+
+        let user_defined = condition
+            .by_value_condition
+            .as_ref()
+            .unwrap()
+            .user_defined_value_condition
+            .as_ref()
+            .unwrap();
+        assert_eq!(user_defined.name.as_literal().unwrap(), "driverAttention");
+        assert_eq!(user_defined.rule, Rule::LessThan);
+        assert_eq!(user_defined.value.as_literal().unwrap(), "0.3");
+
+        let xml = quick_xml::se::to_string(&condition).unwrap();
+        assert!(xml.contains(
+            r#"<UserDefinedValueCondition name="driverAttention" rule="lessThan" value="0.3"/>"#
+        ));
+
+        assert!(UserDefinedValueConditionBuilder::new()
+            .name("driverAttention")
+            .build()
+            .is_err());
+    }
 }
@@ -0,0 +1,127 @@
+//! Controller builder for programmatic controller definitions
+//!
+//! Controllers are assigned to entities through `ObjectController` or stored
+//! in controller catalogs. The builder collects the controller name, type,
+//! parameter declarations and properties and produces a `Controller`.
+//!
+//! # Usage Examples
+//!
+//! ```rust
+//! use openscenario_rs::builder::ControllerBuilder;
+//! use openscenario_rs::types::enums::{ControllerType, ParameterType};
+//!
+//! let controller = ControllerBuilder::new()
+//!     .name("DefaultDriver")
+//!     .controller_type(ControllerType::Movement)
+//!     .add_parameter("MaxSpeed", ParameterType::Double, "30.0")
+//!     .add_property("esminiController", "DefaultController")
+//!     .build()
+//!     .unwrap();
+//! ```
+
+use crate::builder::{BuilderError, BuilderResult};
+use crate::types::{
+    basic::{OSString, ParameterDeclaration, ParameterDeclarations},
+    controllers::Controller,
+    entities::vehicle::{Properties, Property},
+    enums::{ControllerType, ParameterType},
+};
+
+/// Builder for controller definitions
+///
+/// A name is required; the controller type, parameters and properties are
+/// optional.
+#[derive(Debug, Clone, Default)]
+pub struct ControllerBuilder {
+    name: Option<String>,
+    controller_type: Option<ControllerType>,
+    parameter_declarations: Option<ParameterDeclarations>,
+    properties: Option<Properties>,
+}
+
+impl ControllerBuilder {
+    /// Create new controller builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the controller name
+    pub fn name(mut self, name: &str) -> Self {
+        self.name = Some(name.to_string());
+        self
+    }
+
+    /// Set the controller type
+    pub fn controller_type(mut self, controller_type: ControllerType) -> Self {
+        self.controller_type = Some(controller_type);
+        self
+    }
+
+    /// Add a parameter declaration to this controller
+    pub fn add_parameter(mut self, name: &str, param_type: ParameterType, value: &str) -> Self {
+        self.parameter_declarations
+            .get_or_insert_with(ParameterDeclarations::default)
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                name.to_string(),
+                param_type,
+                value.to_string(),
+            ));
+        self
+    }
+
+    /// Add a name/value property passed to the simulator's controller
+    pub fn add_property(mut self, name: &str, value: &str) -> Self {
+        self.properties
+            .get_or_insert_with(Properties::default)
+            .properties
+            .push(Property {
+                name: name.to_string(),
+                value: value.to_string(),
+            });
+        self
+    }
+
+    /// Build the controller
+    pub fn build(self) -> BuilderResult<Controller> {
+        let name = self.name.ok_or_else(|| {
+            BuilderError::missing_field("name", "Call .name() to name the controller")
+        })?;
+
+        Ok(Controller {
+            name: OSString::literal(name),
+            controller_type: self.controller_type,
+            parameter_declarations: self.parameter_declarations,
+            properties: self.properties,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_controller_builder_with_properties() {
+        let controller = ControllerBuilder::new()
+            .name("ACC")
+            .controller_type(ControllerType::Movement)
+            .add_property("timeGap", "1.5")
+            .add_property("setSpeed", "30.0")
+            .build()
+            .unwrap();
+
+        assert_eq!(controller.name.as_literal().unwrap(), "ACC");
+        assert_eq!(controller.controller_type, Some(ControllerType::Movement));
+        assert!(controller.parameter_declarations.is_none());
+
+        let properties = &controller.properties.as_ref().unwrap().properties;
+        assert_eq!(properties.len(), 2);
+        assert_eq!(properties[0].name, "timeGap");
+        assert_eq!(properties[0].value, "1.5");
+        assert_eq!(properties[1].name, "setSpeed");
+        assert_eq!(properties[1].value, "30.0");
+
+        assert!(ControllerBuilder::new().build().is_err());
+    }
+}
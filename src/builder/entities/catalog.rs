@@ -100,7 +100,7 @@ impl<'parent> CatalogVehicleReferenceBuilder<'parent> {
             entity_catalog_reference: None,
             vehicle: Some(vehicle),
             pedestrian: None,
-            object_controller: None,
+            object_controllers: Vec::new(),
         };
 
         // Add to parent's entities
@@ -201,7 +201,7 @@ impl<'parent> CatalogPedestrianReferenceBuilder<'parent> {
             entity_catalog_reference: None,
             vehicle: None,
             pedestrian: Some(pedestrian),
-            object_controller: None,
+            object_controllers: Vec::new(),
         };
 
         // Add to parent's entities
@@ -2,6 +2,8 @@
 
 use crate::types::{
     basic::{Double, OSString},
+    catalogs::references::ControllerCatalogReference,
+    controllers::ObjectController,
     entities::axles::Axles,
     entities::vehicle::{Performance, Properties},
     entities::{ScenarioObject, Vehicle},
@@ -32,6 +34,7 @@ struct PartialVehicleData {
     bounding_box: Option<BoundingBox>,
     performance: Option<Performance>,
     axles: Option<Axles>,
+    object_controllers: Vec<ObjectController>,
 }
 
 impl<'parent> VehicleBuilder<'parent> {
@@ -139,6 +142,16 @@ impl<'parent> VehicleBuilder<'parent> {
         self
     }
 
+    /// Attach a controller from a controller catalog
+    pub fn with_controller_catalog_ref(mut self, catalog: &str, entry: &str) -> Self {
+        self.vehicle_data
+            .object_controllers
+            .push(ObjectController::with_catalog_reference(
+                ControllerCatalogReference::new(catalog.to_string(), entry.to_string()),
+            ));
+        self
+    }
+
     /// Finish vehicle and add to scenario
     pub fn finish(
         self,
@@ -160,7 +173,10 @@ impl<'parent> VehicleBuilder<'parent> {
             properties: self.vehicle_data.properties,
         };
 
-        let scenario_object = ScenarioObject::new_vehicle(self.name.clone(), vehicle);
+        let mut scenario_object = ScenarioObject::new_vehicle(self.name.clone(), vehicle);
+        if !self.vehicle_data.object_controllers.is_empty() {
+            scenario_object.object_controllers = self.vehicle_data.object_controllers;
+        }
 
         // Add to parent's entities
         if let Some(ref mut entities) = self.parent.data.entities {
@@ -279,6 +295,16 @@ impl DetachedVehicleBuilder {
         self
     }
 
+    /// Attach a controller from a controller catalog
+    pub fn with_controller_catalog_ref(mut self, catalog: &str, entry: &str) -> Self {
+        self.vehicle_data
+            .object_controllers
+            .push(ObjectController::with_catalog_reference(
+                ControllerCatalogReference::new(catalog.to_string(), entry.to_string()),
+            ));
+        self
+    }
+
     /// Build the vehicle object
     pub fn build(self) -> ScenarioObject {
         let vehicle = Vehicle {
@@ -297,7 +323,11 @@ impl DetachedVehicleBuilder {
             properties: self.vehicle_data.properties,
         };
 
-        ScenarioObject::new_vehicle(self.name.clone(), vehicle)
+        let mut scenario_object = ScenarioObject::new_vehicle(self.name.clone(), vehicle);
+        if !self.vehicle_data.object_controllers.is_empty() {
+            scenario_object.object_controllers = self.vehicle_data.object_controllers;
+        }
+        scenario_object
     }
 }
 
@@ -356,4 +386,31 @@ mod tests {
         assert_eq!(v.performance.max_acceleration.as_literal(), Some(&5.0));
         assert_eq!(v.performance.max_deceleration.as_literal(), Some(&10.0));
     }
+
+    #[test]
+    fn test_with_controller_catalog_ref() {
+        let obj = DetachedVehicleBuilder::new("ego")
+            .car()
+            .with_controller_catalog_ref("ControllerCatalog", "DefaultDriver")
+            .build();
+        assert_eq!(obj.object_controllers.len(), 1);
+        let controller = &obj.object_controllers[0];
+        assert!(controller.controller.is_none());
+        let reference = controller.catalog_reference.as_ref().unwrap();
+        assert_eq!(
+            reference.catalog_name.as_literal(),
+            Some(&"ControllerCatalog".to_string())
+        );
+        assert_eq!(
+            reference.entry_name.as_literal(),
+            Some(&"DefaultDriver".to_string())
+        );
+
+        let xml = quick_xml::se::to_string(&obj).unwrap();
+        assert!(xml.contains(
+            r#"<ObjectController><CatalogReference catalogName="ControllerCatalog" entryName="DefaultDriver"/></ObjectController>"#
+        ));
+        let parsed: ScenarioObject = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.object_controllers, obj.object_controllers);
+    }
 }
@@ -1,10 +1,11 @@
 //! Main init action builder implementation
 
 use super::private::{GlobalActionBuilder, PrivateActionBuilder};
+use crate::builder::actions::EnvironmentActionBuilder;
 use crate::builder::BuilderResult;
 use crate::types::{
     environment::Environment,
-    positions::Position,
+    positions::{LanePosition, Position, WorldPosition},
     scenario::init::{Actions, EnvironmentAction, GlobalAction, Init, Private},
 };
 
@@ -27,11 +28,46 @@ impl InitActionBuilder {
             environment_action: Some(EnvironmentAction {
                 environment: Environment::default(),
             }),
+            parameter_action: None,
+            entity_action: None,
         };
         self.global_actions.push(global_action);
         self
     }
 
+    /// Use a custom environment for the scenario start
+    ///
+    /// Replaces the environment of an existing global environment action
+    /// (e.g. the default one), or adds a new environment action.
+    pub fn set_environment(mut self, environment: Environment) -> Self {
+        match self
+            .global_actions
+            .iter_mut()
+            .find_map(|action| action.environment_action.as_mut())
+        {
+            Some(existing) => existing.environment = environment,
+            None => self.global_actions.push(GlobalAction {
+                environment_action: Some(EnvironmentAction { environment }),
+                parameter_action: None,
+                entity_action: None,
+            }),
+        }
+        self
+    }
+
+    /// Use the environment configured by an environment action builder
+    ///
+    /// Like [`set_environment`](Self::set_environment), this replaces any
+    /// existing environment action.
+    pub fn with_environment_action(self, action: EnvironmentActionBuilder) -> BuilderResult<Self> {
+        let environment = action
+            .build()?
+            .environment_action
+            .map(|action| action.environment)
+            .unwrap_or_default();
+        Ok(self.set_environment(environment))
+    }
+
     /// Add a custom global action
     pub fn add_global_action(mut self, action: GlobalAction) -> Self {
         self.global_actions.push(action);
@@ -85,6 +121,39 @@ impl InitActionBuilder {
         self
     }
 
+    /// Teleport an entity onto a lane at the given s-coordinate and lane offset
+    pub fn add_teleport_lane(
+        self,
+        entity_ref: &str,
+        road_id: &str,
+        lane_id: &str,
+        s: f64,
+        offset: f64,
+    ) -> Self {
+        let position = Position {
+            lane_position: Some(LanePosition::new(
+                road_id.to_string(),
+                lane_id.to_string(),
+                s,
+                offset,
+            )),
+            ..Position::empty()
+        };
+        self.add_teleport_action(entity_ref, position)
+    }
+
+    /// Teleport an entity to world coordinates with the given heading in radians
+    pub fn add_teleport_world(self, entity_ref: &str, x: f64, y: f64, h: f64) -> Self {
+        let position = Position {
+            world_position: Some(WorldPosition {
+                h: Some(crate::types::basic::Double::literal(h)),
+                ..WorldPosition::new(x, y)
+            }),
+            ..Position::empty()
+        };
+        self.add_teleport_action(entity_ref, position)
+    }
+
     /// Add a speed action for an entity (convenience method)
     pub fn add_speed_action(mut self, entity_ref: &str, speed: f64) -> Self {
         // Find existing private action or create new one
@@ -102,6 +171,7 @@ impl InitActionBuilder {
                             crate::types::actions::movement::TransitionDynamics {
                                 dynamics_dimension: crate::types::enums::DynamicsDimension::Time,
                                 dynamics_shape: crate::types::enums::DynamicsShape::Step,
+                                following_mode: None,
                                 value: crate::types::basic::Double::literal(1.0),
                             },
                         speed_action_target: crate::types::actions::movement::SpeedActionTarget {
@@ -128,6 +198,7 @@ impl InitActionBuilder {
                             crate::types::actions::movement::TransitionDynamics {
                                 dynamics_dimension: crate::types::enums::DynamicsDimension::Time,
                                 dynamics_shape: crate::types::enums::DynamicsShape::Step,
+                                following_mode: None,
                                 value: crate::types::basic::Double::literal(1.0),
                             },
                         speed_action_target: crate::types::actions::movement::SpeedActionTarget {
@@ -176,6 +247,7 @@ impl InitActionBuilder {
 mod tests {
     use super::*;
     use crate::builder::positions::WorldPositionBuilder;
+    use crate::types::enums::PrecipitationType;
 
     #[test]
     fn test_init_action_builder_empty() {
@@ -222,6 +294,42 @@ mod tests {
             .is_some());
     }
 
+    #[test]
+    fn test_init_action_builder_with_teleport_shortcuts() {
+        let init = InitActionBuilder::new()
+            .add_teleport_lane("ego", "1", "-1", 50.0, 0.5)
+            .add_teleport_world("target", 100.0, 2.0, 1.57)
+            .build()
+            .unwrap();
+
+        assert_eq!(init.actions.private_actions.len(), 2);
+        let lane = init.actions.private_actions[0].private_actions[0]
+            .teleport_action
+            .as_ref()
+            .unwrap()
+            .position
+            .lane_position
+            .as_ref()
+            .unwrap();
+        assert_eq!(lane.road_id.as_literal().unwrap(), "1");
+        assert_eq!(lane.lane_id.as_literal().unwrap(), "-1");
+
+        let world = init.actions.private_actions[1].private_actions[0]
+            .teleport_action
+            .as_ref()
+            .unwrap()
+            .position
+            .world_position
+            .as_ref()
+            .unwrap();
+        assert_eq!(world.h.as_ref().unwrap().as_literal(), Some(&1.57));
+        assert!(world.z.is_none());
+
+        let xml = quick_xml::se::to_string(&init).unwrap();
+        assert!(xml.contains(r#"<LanePosition roadId="1" laneId="-1" s="50" offset="0.5"/>"#));
+        assert!(xml.contains(r#"<WorldPosition x="100" y="2" h="1.57"/>"#));
+    }
+
     #[test]
     fn test_init_action_builder_with_speed() {
         let init = InitActionBuilder::new()
@@ -327,4 +435,37 @@ mod tests {
         assert_eq!(target_private.entity_ref.as_literal().unwrap(), "target");
         assert_eq!(target_private.private_actions.len(), 2);
     }
+
+    #[test]
+    fn test_init_action_builder_with_rainy_environment() {
+        let init = InitActionBuilder::with_default_environment()
+            .with_environment_action(
+                EnvironmentActionBuilder::new()
+                    .named("RainyNoon")
+                    .time_of_day("2021-06-01T12:00:00", false)
+                    .cloud_state("rainy")
+                    .sun(0.2, 0.0, 1.2)
+                    .fog(800.0)
+                    .precipitation(PrecipitationType::Rain, 0.7),
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+
+        // The default environment is replaced rather than duplicated
+        assert_eq!(init.actions.global_actions.len(), 1);
+        let environment = &init.actions.global_actions[0]
+            .environment_action
+            .as_ref()
+            .unwrap()
+            .environment;
+        assert_eq!(environment.name.as_literal().unwrap(), "RainyNoon");
+        assert_eq!(environment.weather.precipitation.precipitation_type, "rain");
+
+        let xml = quick_xml::se::to_string(&init).unwrap();
+        assert!(xml.contains(r#"<Environment name="RainyNoon">"#));
+        assert!(xml.contains(r#"<Weather cloudState="rainy">"#));
+        assert!(xml.contains(r#"<Precipitation precipitationType="rain" intensity="0.7"/>"#));
+        assert!(xml.contains(r#"<Fog visualRange="800"/>"#));
+    }
 }
@@ -7,15 +7,19 @@ use crate::builder::actions::{
     LongitudinalDistanceActionBuilder, SpeedProfileActionBuilder, SynchronizeActionBuilder,
     VisibilityActionBuilder,
 };
-use crate::builder::BuilderResult;
+use crate::builder::{BuilderError, BuilderResult};
 use crate::types::{
     actions::appearance::VisibilityAction,
     actions::movement::{
         LateralAction, LongitudinalAction as LongitudinalActionType, RoutingAction, SpeedAction,
         SpeedActionTarget, SynchronizeAction, TeleportAction, TransitionDynamics,
     },
-    actions::wrappers::PrivateAction as PrivateActionWrapper,
-    basic::{Double, Value},
+    actions::wrappers::{
+        ModifyRule, ParameterAction, ParameterActionChoice, ParameterAddValueRule,
+        ParameterModifyAction, ParameterMultiplyByValueRule, ParameterSetAction,
+        PrivateAction as PrivateActionWrapper,
+    },
+    basic::{Double, OSString, Value},
     enums::{DynamicsDimension, DynamicsShape},
     environment::Environment,
     positions::Position,
@@ -54,6 +58,7 @@ impl PrivateActionBuilder {
             speed_action_dynamics: TransitionDynamics {
                 dynamics_dimension: DynamicsDimension::Time,
                 dynamics_shape: DynamicsShape::Step,
+                following_mode: None,
                 value: Double::literal(1.0),
             },
             speed_action_target: SpeedActionTarget {
@@ -286,6 +291,7 @@ impl PrivateActionBuilder {
 pub struct GlobalActionBuilder {
     parent: InitActionBuilder,
     environment_action: Option<EnvironmentAction>,
+    parameter_action: Option<ParameterAction>,
 }
 
 impl GlobalActionBuilder {
@@ -294,6 +300,7 @@ impl GlobalActionBuilder {
         Self {
             parent,
             environment_action: None,
+            parameter_action: None,
         }
     }
 
@@ -311,18 +318,79 @@ impl GlobalActionBuilder {
         self
     }
 
+    /// Add a parameter action that sets a parameter to a new value
+    pub fn add_parameter_set_action(mut self, parameter_ref: &str, value: &str) -> Self {
+        self.parameter_action = Some(ParameterAction {
+            parameter_ref: OSString::literal(parameter_ref.to_string()),
+            action: ParameterActionChoice::ParameterSetAction(ParameterSetAction {
+                value: OSString::literal(value.to_string()),
+            }),
+        });
+        self
+    }
+
+    /// Add a parameter action that adds a value to a numeric parameter
+    pub fn add_parameter_add_action(self, parameter_ref: &str, value: f64) -> Self {
+        self.with_parameter_modify_action(
+            parameter_ref,
+            ModifyRule::ParameterAddValueRule(ParameterAddValueRule {
+                value: Double::literal(value),
+            }),
+        )
+    }
+
+    /// Add a parameter action that multiplies a numeric parameter by a value
+    pub fn add_parameter_multiply_action(self, parameter_ref: &str, value: f64) -> Self {
+        self.with_parameter_modify_action(
+            parameter_ref,
+            ModifyRule::ParameterMultiplyByValueRule(ParameterMultiplyByValueRule {
+                value: Double::literal(value),
+            }),
+        )
+    }
+
+    fn with_parameter_modify_action(mut self, parameter_ref: &str, rule: ModifyRule) -> Self {
+        self.parameter_action = Some(ParameterAction {
+            parameter_ref: OSString::literal(parameter_ref.to_string()),
+            action: ParameterActionChoice::ParameterModifyAction(ParameterModifyAction { rule }),
+        });
+        self
+    }
+
     /// Finish building and return to parent
+    ///
+    /// Each configured action is added as its own `GlobalAction`, since the
+    /// XSD only allows one action type per `GlobalAction` element.
     pub fn finish(self) -> InitActionBuilder {
-        let global_action = GlobalAction {
-            environment_action: self.environment_action,
-        };
-        self.parent.add_global(global_action)
+        let mut parent = self.parent;
+        if let Some(environment_action) = self.environment_action {
+            parent = parent.add_global(GlobalAction {
+                environment_action: Some(environment_action),
+                parameter_action: None,
+                entity_action: None,
+            });
+        }
+        if let Some(parameter_action) = self.parameter_action {
+            parent = parent.add_global(GlobalAction {
+                environment_action: None,
+                parameter_action: Some(parameter_action),
+                entity_action: None,
+            });
+        }
+        parent
     }
 
     /// Build the global action
     pub fn build(self) -> BuilderResult<GlobalAction> {
+        if self.environment_action.is_some() && self.parameter_action.is_some() {
+            return Err(BuilderError::validation_error(
+                "GlobalAction must contain exactly one action type, found multiple",
+            ));
+        }
         Ok(GlobalAction {
             environment_action: self.environment_action,
+            parameter_action: self.parameter_action,
+            entity_action: None,
         })
     }
 }
@@ -422,6 +490,83 @@ mod tests {
         assert!(init.actions.global_actions[0].environment_action.is_some());
     }
 
+    #[test]
+    fn test_global_parameter_set_action_roundtrip() {
+        let init = InitActionBuilder::new()
+            .create_global_action()
+            .add_parameter_set_action("TargetSpeed", "42.0")
+            .finish()
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&init).unwrap();
+        assert!(xml.contains(r#"<ParameterAction parameterRef="TargetSpeed">"#));
+        assert!(xml.contains(r#"<SetAction value="42.0"/>"#));
+
+        let deserialized: crate::types::scenario::init::Init =
+            quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(init, deserialized);
+
+        let parameter_action = deserialized.actions.global_actions[0]
+            .parameter_action
+            .as_ref()
+            .unwrap();
+        assert_eq!(
+            parameter_action.parameter_ref.as_literal().unwrap(),
+            "TargetSpeed"
+        );
+        match &parameter_action.action {
+            ParameterActionChoice::ParameterSetAction(set) => {
+                assert_eq!(set.value.as_literal().unwrap(), "42.0")
+            }
+            other => panic!("expected SetAction, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_global_parameter_modify_action_roundtrip() {
+        let init = InitActionBuilder::new()
+            .create_global_action()
+            .add_parameter_add_action("Counter", 1.5)
+            .finish()
+            .create_global_action()
+            .add_parameter_multiply_action("Scale", 2.0)
+            .finish()
+            .build()
+            .unwrap();
+
+        let xml = quick_xml::se::to_string(&init).unwrap();
+        assert!(xml.contains(
+            r#"<ParameterAction parameterRef="Counter"><ModifyAction><Rule><AddValue value="1.5"/></Rule></ModifyAction></ParameterAction>"#
+        ));
+        assert!(xml.contains(
+            r#"<ParameterAction parameterRef="Scale"><ModifyAction><Rule><MultiplyByValue value="2"/></Rule></ModifyAction></ParameterAction>"#
+        ));
+
+        let deserialized: crate::types::scenario::init::Init =
+            quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(init, deserialized);
+    }
+
+    #[test]
+    fn test_global_action_builder_rejects_multiple_actions() {
+        let result = GlobalActionBuilder::new(InitActionBuilder::new())
+            .add_default_environment_action()
+            .add_parameter_add_action("Counter", 1.0)
+            .build();
+        assert!(result.is_err());
+
+        let init = InitActionBuilder::new()
+            .create_global_action()
+            .add_default_environment_action()
+            .add_parameter_multiply_action("Counter", 2.0)
+            .finish()
+            .build()
+            .unwrap();
+        assert_eq!(init.actions.global_actions.len(), 2);
+        assert!(init.actions.global_actions[1].parameter_action.is_some());
+    }
+
     #[test]
     fn test_combined_builders() {
         let position = WorldPositionBuilder::new()
@@ -165,6 +165,7 @@ pub use error::{BuilderError, BuilderResult};
 pub mod actions;
 pub mod catalog;
 pub mod conditions;
+pub mod controller;
 pub mod entities;
 pub mod init;
 pub mod parameters;
@@ -173,12 +174,14 @@ pub mod scenario;
 pub mod storyboard;
 pub mod templates;
 pub mod validation;
+pub mod variation;
 
 pub use actions::{
-    ActivateControllerActionBuilder, EntityActionBuilder, EnvironmentActionBuilder,
-    FollowTrajectoryActionBuilder, LaneChangeActionBuilder, LaneOffsetActionBuilder,
-    LateralDistanceActionBuilder, PolylineBuilder, SpeedActionBuilder, TeleportActionBuilder,
-    TrajectoryBuilder, VariableActionBuilder, VertexBuilder,
+    ActivateControllerActionBuilder, DynamicConstraintsBuilder, EntityActionBuilder,
+    EnvironmentActionBuilder, FollowTrajectoryActionBuilder, LaneChangeActionBuilder,
+    LaneOffsetActionBuilder, LateralDistanceActionBuilder, PolylineBuilder, SpeedActionBuilder,
+    TeleportActionBuilder, TrajectoryBuilder, TransitionDynamicsBuilder, UserDefinedActionBuilder,
+    VariableActionBuilder, VertexBuilder,
 };
 pub use catalog::{
     CatalogEntityBuilder, CatalogLocationsBuilder, PedestrianCatalogReferenceBuilder,
@@ -186,18 +189,22 @@ pub use catalog::{
 };
 pub use conditions::{
     AccelerationConditionBuilder, CollisionConditionBuilder, ParameterConditionBuilder,
-    ReachPositionConditionBuilder, RelativeDistanceConditionBuilder, SpeedConditionBuilder,
-    TimeConditionBuilder, TraveledDistanceConditionBuilder, TriggerBuilder,
-    ValueSpeedConditionBuilder, VariableConditionBuilder,
+    ReachPositionConditionBuilder, RelativeClearanceConditionBuilder,
+    RelativeDistanceConditionBuilder, SpeedConditionBuilder, TimeConditionBuilder,
+    TimeHeadwayConditionBuilder, TimeOfDayConditionBuilder, TraveledDistanceConditionBuilder,
+    TriggerBuilder, UserDefinedValueConditionBuilder, ValueSpeedConditionBuilder,
+    VariableConditionBuilder,
 };
+pub use controller::ControllerBuilder;
 pub use entities::{DetachedVehicleBuilder, VehicleBuilder};
 pub use init::{GlobalActionBuilder, InitActionBuilder, PrivateActionBuilder};
 pub use parameters::{ParameterContext, ParameterDeclarationsBuilder, ParameterizedValueBuilder};
 pub use scenario::ScenarioBuilder;
 pub use storyboard::{
-    ActBuilder, DetachedActBuilder, DetachedFollowTrajectoryActionBuilder, DetachedManeuverBuilder,
-    DetachedSpeedActionBuilder, DetachedStoryBuilder, ManeuverBuilder, StoryBuilder,
-    StoryboardBuilder,
+    ActBuilder, DetachedActBuilder, DetachedEventBuilder, DetachedFollowTrajectoryActionBuilder,
+    DetachedManeuverBuilder, DetachedSpeedActionBuilder, DetachedStoryBuilder, ManeuverBuilder,
+    StoryBuilder, StoryboardBuilder,
 };
 pub use templates::{BasicScenarioTemplate, ScenarioTemplate};
 pub use validation::{BuilderValidatable, BuilderValidationContext, ValidationContextBuilder};
+pub use variation::ParameterVariationBuilder;
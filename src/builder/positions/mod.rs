@@ -8,7 +8,9 @@ pub mod relative;
 pub mod world;
 
 pub use lane::LanePositionBuilder;
-pub use relative::RelativePositionBuilder;
+pub use relative::{
+    RelativeLanePositionBuilder, RelativePositionBuilder, RelativeRoadPositionBuilder,
+};
 pub use world::WorldPositionBuilder;
 
 use crate::builder::{BuilderError, BuilderResult};
@@ -33,6 +35,8 @@ pub enum PositionType {
     World(WorldPositionBuilder),
     Relative(RelativePositionBuilder),
     Lane(LanePositionBuilder),
+    RelativeRoad(RelativeRoadPositionBuilder),
+    RelativeLane(RelativeLanePositionBuilder),
 }
 
 impl UnifiedPositionBuilder {
@@ -50,6 +54,16 @@ impl UnifiedPositionBuilder {
     pub fn lane() -> LanePositionBuilder {
         LanePositionBuilder::new()
     }
+
+    /// Create a new road-relative position builder
+    pub fn relative_road() -> RelativeRoadPositionBuilder {
+        RelativeRoadPositionBuilder::new()
+    }
+
+    /// Create a new lane-relative position builder
+    pub fn relative_lane() -> RelativeLanePositionBuilder {
+        RelativeLanePositionBuilder::new()
+    }
 }
 
 /// Helper function to validate coordinate values
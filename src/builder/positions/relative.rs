@@ -1,9 +1,11 @@
 //! Relative position builder for entity-relative positions
 
-use super::PositionBuilder;
+use super::{validate_angle, validate_coordinate, validate_entity_ref, PositionBuilder};
 use crate::builder::{BuilderError, BuilderResult};
 use crate::types::basic::{Double, Int, OSString};
-use crate::types::positions::{Position, RelativeLanePosition, RelativeWorldPosition};
+use crate::types::positions::{
+    Orientation, Position, RelativeLanePosition, RelativeRoadPosition, RelativeWorldPosition,
+};
 
 /// Builder for relative positions
 #[derive(Debug, Clone, Default)]
@@ -71,6 +73,7 @@ impl PositionBuilder for RelativePositionBuilder {
                     dx: Double::literal(self.dx.unwrap()),
                     dy: Double::literal(self.dy.unwrap()),
                     dz: Double::literal(self.dz.unwrap()),
+                    orientation: None,
                 };
                 position.relative_world_position = Some(relative_world_position);
             }
@@ -117,6 +120,177 @@ impl PositionBuilder for RelativePositionBuilder {
     }
 }
 
+/// Builder for positions relative to an entity in road coordinates
+#[derive(Debug, Clone, Default)]
+pub struct RelativeRoadPositionBuilder {
+    entity_ref: Option<String>,
+    ds: Option<f64>,
+    dt: Option<f64>,
+    heading: Option<f64>,
+}
+
+impl RelativeRoadPositionBuilder {
+    /// Create a new relative road position builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the reference entity
+    pub fn to_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Set the offset along the road reference line
+    pub fn ds(mut self, ds: f64) -> Self {
+        self.ds = Some(ds);
+        self
+    }
+
+    /// Set the lateral offset from the road reference line
+    pub fn dt(mut self, dt: f64) -> Self {
+        self.dt = Some(dt);
+        self
+    }
+
+    /// Set the heading relative to the reference entity
+    pub fn heading(mut self, h: f64) -> Self {
+        self.heading = Some(h);
+        self
+    }
+}
+
+impl PositionBuilder for RelativeRoadPositionBuilder {
+    fn finish(self) -> BuilderResult<Position> {
+        self.validate()?;
+
+        let relative_road_position = RelativeRoadPosition {
+            entity_ref: OSString::literal(self.entity_ref.unwrap()),
+            ds: Double::literal(self.ds.unwrap()),
+            dt: Double::literal(self.dt.unwrap()),
+            orientation: self.heading.map(Orientation::heading),
+        };
+        Ok(Position::relative_road(relative_road_position))
+    }
+
+    fn validate(&self) -> BuilderResult<()> {
+        match &self.entity_ref {
+            Some(entity_ref) => validate_entity_ref(entity_ref)?,
+            None => {
+                return Err(BuilderError::validation_error(
+                    "Entity reference is required",
+                ))
+            }
+        }
+        match (self.ds, self.dt) {
+            (Some(ds), Some(dt)) => {
+                validate_coordinate(ds, "ds")?;
+                validate_coordinate(dt, "dt")?;
+            }
+            _ => {
+                return Err(BuilderError::validation_error(
+                    "Road offsets (ds, dt) are required",
+                ))
+            }
+        }
+        if let Some(h) = self.heading {
+            validate_angle(h, "heading")?;
+        }
+        Ok(())
+    }
+}
+
+/// Builder for positions relative to an entity in lane coordinates
+#[derive(Debug, Clone, Default)]
+pub struct RelativeLanePositionBuilder {
+    entity_ref: Option<String>,
+    d_lane: Option<i32>,
+    ds: Option<f64>,
+    offset: f64,
+    heading: Option<f64>,
+}
+
+impl RelativeLanePositionBuilder {
+    /// Create a new relative lane position builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the reference entity
+    pub fn to_entity(mut self, entity_ref: &str) -> Self {
+        self.entity_ref = Some(entity_ref.to_string());
+        self
+    }
+
+    /// Set the number of lanes from the lane of the reference entity
+    pub fn d_lane(mut self, d_lane: i32) -> Self {
+        self.d_lane = Some(d_lane);
+        self
+    }
+
+    /// Set the offset along the lane
+    pub fn ds(mut self, ds: f64) -> Self {
+        self.ds = Some(ds);
+        self
+    }
+
+    /// Set the lateral offset from the lane center (defaults to 0)
+    pub fn offset(mut self, offset: f64) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// Set the heading relative to the lane direction
+    pub fn heading(mut self, h: f64) -> Self {
+        self.heading = Some(h);
+        self
+    }
+}
+
+impl PositionBuilder for RelativeLanePositionBuilder {
+    fn finish(self) -> BuilderResult<Position> {
+        self.validate()?;
+
+        let relative_lane_position = RelativeLanePosition {
+            entity_ref: OSString::literal(self.entity_ref.unwrap()),
+            d_lane: Int::literal(self.d_lane.unwrap()),
+            ds: Double::literal(self.ds.unwrap()),
+            offset: Double::literal(self.offset),
+            orientation: self.heading.map(Orientation::heading),
+        };
+        Ok(Position::relative_lane(relative_lane_position))
+    }
+
+    fn validate(&self) -> BuilderResult<()> {
+        match &self.entity_ref {
+            Some(entity_ref) => validate_entity_ref(entity_ref)?,
+            None => {
+                return Err(BuilderError::validation_error(
+                    "Entity reference is required",
+                ))
+            }
+        }
+        if self.d_lane.is_none() {
+            return Err(BuilderError::validation_error(
+                "Lane delta (dLane) is required",
+            ));
+        }
+        match self.ds {
+            Some(ds) => validate_coordinate(ds, "ds")?,
+            None => {
+                return Err(BuilderError::validation_error(
+                    "Offset along the lane (ds) is required",
+                ))
+            }
+        }
+        validate_coordinate(self.offset, "offset")?;
+        if let Some(h) = self.heading {
+            validate_angle(h, "heading")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,4 +338,59 @@ mod tests {
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("World offsets"));
     }
+
+    #[test]
+    fn test_relative_road_position_builder() {
+        let pos = RelativeRoadPositionBuilder::new()
+            .to_entity("lead")
+            .ds(-15.0)
+            .dt(3.5)
+            .finish()
+            .unwrap();
+        let rrp = pos.relative_road_position.as_ref().unwrap();
+        assert_eq!(rrp.entity_ref.as_literal(), Some(&"lead".to_string()));
+        assert_eq!(rrp.ds.as_literal(), Some(&-15.0));
+        assert_eq!(rrp.dt.as_literal(), Some(&3.5));
+        assert!(rrp.orientation.is_none());
+
+        let xml = quick_xml::se::to_string(&pos).unwrap();
+        assert!(xml.contains(r#"<RelativeRoadPosition entityRef="lead" ds="-15" dt="3.5"/>"#));
+        let parsed: Position = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, pos);
+
+        let result = RelativeRoadPositionBuilder::new()
+            .to_entity("lead")
+            .ds(1.0)
+            .finish();
+        assert!(result.unwrap_err().to_string().contains("ds, dt"));
+    }
+
+    #[test]
+    fn test_relative_lane_position_builder() {
+        let pos = RelativeLanePositionBuilder::new()
+            .to_entity("ego")
+            .d_lane(-1)
+            .ds(10.0)
+            .offset(0.25)
+            .heading(0.1)
+            .finish()
+            .unwrap();
+        let rlp = pos.relative_lane_position.as_ref().unwrap();
+        assert_eq!(rlp.d_lane.as_literal(), Some(&-1));
+        assert_eq!(rlp.offset.as_literal(), Some(&0.25));
+        assert!(rlp.orientation.is_some());
+
+        let xml = quick_xml::se::to_string(&pos).unwrap();
+        assert!(xml.contains(
+            r#"<RelativeLanePosition entityRef="ego" dLane="-1" ds="10" offset="0.25">"#
+        ));
+        let parsed: Position = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, pos);
+
+        let result = RelativeLanePositionBuilder::new()
+            .to_entity("ego")
+            .ds(10.0)
+            .finish();
+        assert!(result.unwrap_err().to_string().contains("dLane"));
+    }
 }
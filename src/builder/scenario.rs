@@ -34,6 +34,7 @@ use crate::types::{
     entities::Entities,
     enums::ParameterType,
     road::RoadNetwork,
+    scenario::monitors::MonitorDeclarations,
     scenario::storyboard::{FileHeader, OpenScenario, Storyboard},
 };
 use std::marker::PhantomData;
@@ -82,6 +83,7 @@ pub struct ScenarioBuilder<S> {
 pub(crate) struct PartialScenarioData {
     pub(crate) file_header: Option<FileHeader>,
     pub(crate) parameter_declarations: Option<ParameterDeclarations>,
+    pub(crate) monitor_declarations: Option<MonitorDeclarations>,
     pub(crate) catalog_locations: Option<CatalogLocations>,
     pub(crate) road_network: Option<RoadNetwork>,
     pub(crate) entities: Option<Entities>,
@@ -152,6 +154,81 @@ impl ScenarioBuilder<Empty> {
             data: self.data,
         }
     }
+
+    /// Build a complete minimal scenario that a simulator can run as-is
+    ///
+    /// The scenario contains one car teleported to the world origin and a
+    /// single story whose act, maneuver and speed event all start at
+    /// simulation time 0. The storyboard stops once simulation time exceeds
+    /// 10 seconds.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openscenario_rs::ScenarioBuilder;
+    ///
+    /// let scenario = ScenarioBuilder::minimal("Smoke test", "Test Author", "ego").unwrap();
+    /// assert_eq!(scenario.storyboard.unwrap().stories.len(), 1);
+    /// ```
+    pub fn minimal(name: &str, author: &str, entity_name: &str) -> BuilderResult<OpenScenario> {
+        use crate::builder::conditions::{TimeConditionBuilder, TriggerBuilder};
+        use crate::builder::init::InitActionBuilder;
+        use crate::builder::positions::WorldPositionBuilder;
+        use crate::types::enums::Rule;
+
+        let at_start = || {
+            TriggerBuilder::new()
+                .add_condition(
+                    TimeConditionBuilder::new()
+                        .time_rule(0.0, Rule::GreaterOrEqual)
+                        .build()?,
+                )
+                .build()
+        };
+        let act_start = at_start()?;
+        let event_start = at_start()?;
+
+        let origin = WorldPositionBuilder::new()
+            .at_coordinates(0.0, 0.0, 0.0)
+            .build()?;
+        let init = InitActionBuilder::new()
+            .add_global_environment_action()
+            .add_teleport_action(entity_name, origin)
+            .build()?;
+
+        let maneuver = crate::builder::storyboard::DetachedManeuverBuilder::new(
+            "MinimalManeuver",
+            entity_name,
+        )
+        .add_speed_action(|speed| {
+            speed
+                .named("MinimalEvent")
+                .to_speed(10.0)
+                .with_trigger(event_start)
+        })?
+        .build();
+        let stop = TriggerBuilder::new()
+            .add_condition(TimeConditionBuilder::new().at_time(10.0).build()?)
+            .build()?;
+
+        Self::new()
+            .with_header(name, author)
+            .with_entities()
+            .add_vehicle(entity_name, |vehicle| vehicle.car())
+            .with_storyboard(|storyboard| {
+                storyboard
+                    .with_init_actions(init)
+                    .add_story("MinimalStory", |story| {
+                        story.add_act("MinimalAct", |act| {
+                            let mut act = act.with_start_trigger(act_start);
+                            act.add_completed_maneuver(maneuver, entity_name);
+                            act
+                        })
+                    })
+                    .with_stop_trigger(stop)
+            })
+            .build()
+    }
 }
 
 // Implementation for HasHeader state
@@ -216,6 +293,35 @@ impl ScenarioBuilder<HasHeader> {
         self
     }
 
+    /// Add a monitor declaration (convenience method)
+    ///
+    /// Monitors are named boolean flags that actions can set during the
+    /// simulation to report on the scenario. Multiple calls to this method
+    /// will accumulate monitors.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - Monitor name (referenced by `SetMonitorAction`)
+    /// * `value` - Initial value of the monitor
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use openscenario_rs::ScenarioBuilder;
+    ///
+    /// let builder = ScenarioBuilder::new()
+    ///     .with_header("Test", "Author")
+    ///     .add_monitor("no_collision", true)
+    ///     .add_monitor("lane_change_done", false);
+    /// ```
+    pub fn add_monitor(mut self, name: &str, value: bool) -> Self {
+        self.data
+            .monitor_declarations
+            .get_or_insert_with(MonitorDeclarations::new)
+            .add_monitor(name.to_string(), value);
+        self
+    }
+
     /// Add catalog locations (optional)
     pub fn with_catalog_locations(mut self, locations: CatalogLocations) -> Self {
         self.data.catalog_locations = Some(locations);
@@ -235,6 +341,8 @@ impl ScenarioBuilder<HasHeader> {
                 filepath: OSString::literal(file_path.to_string()),
             }),
             scene_graph_file: None,
+            traffic_signals: None,
+            used_area: None,
         });
         self
     }
@@ -349,12 +457,15 @@ impl ScenarioBuilder<HasEntities> {
             .data
             .storyboard
             .ok_or_else(|| BuilderError::missing_field("storyboard", ".with_storyboard()"))?;
+        validate_storyboard_not_empty(&storyboard)?;
 
         Ok(OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header,
             parameter_declarations: self.data.parameter_declarations,
             variable_declarations: None,
-            monitor_declarations: None,
+            monitor_declarations: self.data.monitor_declarations,
             catalog_locations: self.data.catalog_locations,
             road_network: self.data.road_network,
             entities: Some(entities),
@@ -391,12 +502,15 @@ impl ScenarioBuilder<Complete> {
             .data
             .storyboard
             .ok_or_else(|| BuilderError::missing_field("storyboard", ".with_storyboard()"))?;
+        validate_storyboard_not_empty(&storyboard)?;
 
         Ok(OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header,
             parameter_declarations: self.data.parameter_declarations,
             variable_declarations: None,
-            monitor_declarations: None,
+            monitor_declarations: self.data.monitor_declarations,
             catalog_locations: self.data.catalog_locations,
             road_network: self.data.road_network,
             entities: Some(entities),
@@ -407,6 +521,24 @@ impl ScenarioBuilder<Complete> {
     }
 }
 
+/// Reject storyboards that have neither stories nor init actions
+///
+/// Such a storyboard describes nothing to simulate, and most simulators refuse
+/// to load it.
+fn validate_storyboard_not_empty(storyboard: &Storyboard) -> BuilderResult<()> {
+    let actions = &storyboard.init.actions;
+    if storyboard.stories.is_empty()
+        && actions.global_actions.is_empty()
+        && actions.private_actions.is_empty()
+    {
+        return Err(BuilderError::validation_error_with_suggestion(
+            "Storyboard is empty: it has no stories and no init actions",
+            "Add a story with .add_story() or init actions with .with_init_actions()",
+        ));
+    }
+    Ok(())
+}
+
 impl Default for ScenarioBuilder<Empty> {
     fn default() -> Self {
         Self::new()
@@ -439,4 +571,76 @@ mod tests {
         assert!(scenario.entities.is_some());
         assert!(scenario.storyboard.is_some());
     }
+
+    #[test]
+    fn test_empty_storyboard_build_fails() {
+        let result = ScenarioBuilder::new()
+            .with_header("Test Scenario", "Test Author")
+            .with_entities()
+            .with_storyboard(|storyboard| {
+                storyboard.with_init_actions(crate::types::scenario::init::Init::default())
+            })
+            .build();
+
+        match result {
+            Err(BuilderError::ValidationError {
+                message,
+                suggestion,
+            }) => {
+                assert_eq!(
+                    message,
+                    "Storyboard is empty: it has no stories and no init actions"
+                );
+                assert!(suggestion.contains(".with_init_actions()"));
+            }
+            other => panic!("expected ValidationError, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_monitor_declarations() {
+        let scenario = ScenarioBuilder::new()
+            .with_header("Monitored", "Test Author")
+            .add_monitor("no_collision", true)
+            .add_monitor("lane_change_done", false)
+            .with_entities()
+            .with_storyboard(|storyboard| storyboard)
+            .build()
+            .unwrap();
+
+        let monitors = scenario.monitor_declarations.as_ref().unwrap();
+        assert_eq!(monitors.len(), 2);
+
+        let xml = crate::parser::xml::serialize_to_string(&scenario).unwrap();
+        assert!(xml.contains("<MonitorDeclarations><MonitorDeclaration"));
+        assert!(xml.contains(r#"name="no_collision""#));
+        assert!(xml.contains(r#"value="false""#));
+
+        let parsed = crate::parser::xml::parse_from_str(&xml).unwrap();
+        assert_eq!(parsed.monitor_declarations, scenario.monitor_declarations);
+    }
+
+    #[test]
+    fn test_minimal_runnable_scenario() {
+        let scenario = ScenarioBuilder::minimal("Smoke Test", "Test Author", "ego").unwrap();
+
+        let xml = crate::parser::xml::serialize_to_string(&scenario).unwrap();
+        let parsed = crate::parser::xml::parse_from_str(&xml).unwrap();
+
+        let entities = parsed.entities.unwrap();
+        assert_eq!(entities.scenario_objects.len(), 1);
+        assert_eq!(
+            entities.scenario_objects[0].name.as_literal().unwrap(),
+            "ego"
+        );
+
+        let storyboard = parsed.storyboard.unwrap();
+        assert_eq!(storyboard.stories.len(), 1);
+        let act = &storyboard.stories[0].acts[0];
+        assert!(act.start_trigger.is_some());
+        let event = &act.maneuver_groups[0].maneuvers[0].events[0];
+        assert!(event.start_trigger.is_some());
+        assert!(storyboard.stop_trigger.is_some());
+        assert_eq!(storyboard.init.actions.private_actions.len(), 1);
+    }
 }
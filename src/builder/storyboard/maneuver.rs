@@ -7,12 +7,12 @@ use crate::builder::{
     BuilderError, BuilderResult,
 };
 use crate::types::{
-    basic::OSString,
-    enums::Priority,
+    basic::{OSString, ParameterDeclaration, ParameterDeclarations, Value},
+    enums::{ParameterType, Priority},
     positions::Position,
     scenario::{
         story::{Event, Maneuver, StoryAction, StoryPrivateAction},
-        triggers::{ConditionGroup, Trigger},
+        triggers::{Condition, ConditionGroup, Trigger},
     },
 };
 
@@ -22,6 +22,7 @@ pub struct ManeuverBuilder<'parent> {
     maneuver_name: String,
     entity_ref: String,
     events: Vec<Event>,
+    parameter_declarations: Option<ParameterDeclarations>,
 }
 
 impl<'parent> ManeuverBuilder<'parent> {
@@ -35,9 +36,26 @@ impl<'parent> ManeuverBuilder<'parent> {
             maneuver_name: name.to_string(),
             entity_ref: entity_ref.to_string(),
             events: Vec::new(),
+            parameter_declarations: None,
         }
     }
 
+    /// Declare a parameter scoped to this maneuver
+    ///
+    /// Events of the maneuver can reference it as `${name}`, which lets a
+    /// catalog maneuver be parameterized per use.
+    pub fn add_parameter(mut self, name: &str, param_type: ParameterType, value: &str) -> Self {
+        self.parameter_declarations
+            .get_or_insert_with(ParameterDeclarations::default)
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                name.to_string(),
+                param_type,
+                value.to_string(),
+            ));
+        self
+    }
+
     /// Add a speed action event
     ///
     /// # Usage Note
@@ -100,17 +118,22 @@ impl<'parent> ManeuverBuilder<'parent> {
     }
 
     /// Finish this maneuver
-    pub fn finish(self) -> &'parent mut super::story::ActBuilder<'parent> {
-        let maneuver = Maneuver {
-            name: OSString::literal(self.maneuver_name),
-            events: self.events,
-            parameter_declarations: None,
-        };
+    pub fn finish(mut self) -> &'parent mut super::story::ActBuilder<'parent> {
+        let maneuver = self.take_maneuver();
 
         self.parent
             .add_maneuver_to_group(maneuver, &self.entity_ref);
         self.parent
     }
+
+    /// Move the collected events and parameters into a maneuver
+    fn take_maneuver(&mut self) -> Maneuver {
+        Maneuver {
+            name: OSString::literal(self.maneuver_name.clone()),
+            events: std::mem::take(&mut self.events),
+            parameter_declarations: self.parameter_declarations.take(),
+        }
+    }
 }
 
 /// Builder for speed action events within maneuvers
@@ -398,6 +421,23 @@ impl<P> EventTriggerBuilder<P> {
         self.trigger_builder = self.trigger_builder.add_condition(condition);
         self
     }
+
+    /// Add a custom condition as its own group
+    pub fn add_condition(mut self, condition: Condition) -> Self {
+        self.trigger_builder = self.trigger_builder.add_condition(condition);
+        self
+    }
+
+    /// Start a condition group (AND logic within the group, OR logic between groups)
+    pub fn add_condition_group(self) -> EventConditionGroupBuilder<P> {
+        EventConditionGroupBuilder::new(self)
+    }
+
+    /// Internal method to add a completed condition group
+    fn add_group(mut self, group: ConditionGroup) -> Self {
+        self.trigger_builder = self.trigger_builder.add_group(group);
+        self
+    }
 }
 
 impl<'a> EventTriggerBuilder<SpeedActionEventBuilder<'a>> {
@@ -408,11 +448,66 @@ impl<'a> EventTriggerBuilder<SpeedActionEventBuilder<'a>> {
     }
 }
 
+/// Builder for a condition group within an event trigger
+///
+/// All conditions in the group must be true for the group to fire. Groups
+/// added to the same [`EventTriggerBuilder`] are combined with OR logic.
+pub struct EventConditionGroupBuilder<P> {
+    parent: EventTriggerBuilder<P>,
+    conditions: Vec<Condition>,
+}
+
+impl<P> EventConditionGroupBuilder<P> {
+    pub fn new(parent: EventTriggerBuilder<P>) -> Self {
+        Self {
+            parent,
+            conditions: Vec::new(),
+        }
+    }
+
+    /// Add condition to this group
+    pub fn add_condition(mut self, condition: Condition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    /// Add time condition to this group
+    pub fn time_condition(self, time: f64) -> Self {
+        let condition = crate::builder::conditions::TimeConditionBuilder::new()
+            .at_time(time)
+            .build()
+            .unwrap();
+        self.add_condition(condition)
+    }
+
+    /// Add speed condition to this group
+    pub fn speed_condition(self, entity_ref: &str, speed: f64) -> Self {
+        let condition = crate::builder::conditions::ValueSpeedConditionBuilder::new()
+            .for_entity(entity_ref)
+            .speed_above(speed)
+            .build()
+            .unwrap();
+        self.add_condition(condition)
+    }
+
+    /// Finish this group and return to the event trigger builder
+    pub fn finish_group(self) -> EventTriggerBuilder<P> {
+        if self.conditions.is_empty() {
+            self.parent
+        } else {
+            self.parent.add_group(ConditionGroup {
+                conditions: self.conditions,
+            })
+        }
+    }
+}
+
 /// Detached builder for maneuvers (no lifetime constraints)
 pub struct DetachedManeuverBuilder {
     maneuver_name: String,
     entity_ref: String,
     events: Vec<Event>,
+    parameter_declarations: Option<ParameterDeclarations>,
 }
 
 impl DetachedManeuverBuilder {
@@ -422,9 +517,26 @@ impl DetachedManeuverBuilder {
             maneuver_name: name.to_string(),
             entity_ref: entity_ref.to_string(),
             events: Vec::new(),
+            parameter_declarations: None,
         }
     }
 
+    /// Declare a parameter scoped to this maneuver
+    ///
+    /// Events of the maneuver can reference it as `${name}`, which lets a
+    /// catalog maneuver be parameterized per use.
+    pub fn add_parameter(mut self, name: &str, param_type: ParameterType, value: &str) -> Self {
+        self.parameter_declarations
+            .get_or_insert_with(ParameterDeclarations::default)
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                name.to_string(),
+                param_type,
+                value.to_string(),
+            ));
+        self
+    }
+
     /// Add a speed action using closure-based configuration
     pub fn add_speed_action<F>(mut self, config: F) -> BuilderResult<Self>
     where
@@ -451,6 +563,14 @@ impl DetachedManeuverBuilder {
         DetachedFollowTrajectoryActionBuilder::new(&self.entity_ref)
     }
 
+    /// Start an event with its own start trigger and actions
+    ///
+    /// Call [`DetachedEventBuilder::finish`] to add the event and continue
+    /// with this maneuver, so several independent events can be composed.
+    pub fn create_event(self, name: &str) -> DetachedEventBuilder {
+        DetachedEventBuilder::new(self, name)
+    }
+
     /// Add a completed event to this maneuver
     pub fn add_event(&mut self, event: Event) {
         self.events.push(event);
@@ -458,22 +578,14 @@ impl DetachedManeuverBuilder {
 
     /// Attach this maneuver to an act builder
     pub fn attach_to(self, act: &mut super::story::ActBuilder<'_>) {
-        let maneuver = Maneuver {
-            name: OSString::literal(self.maneuver_name),
-            events: self.events,
-            parameter_declarations: None,
-        };
-        act.add_maneuver_to_group(maneuver, &self.entity_ref);
+        let entity_ref = self.entity_ref.clone();
+        act.add_maneuver_to_group(self.build(), &entity_ref);
     }
 
     /// Attach this maneuver to a detached act builder
     pub fn attach_to_detached(self, act: &mut super::story::DetachedActBuilder) {
-        let maneuver = Maneuver {
-            name: OSString::literal(self.maneuver_name),
-            events: self.events,
-            parameter_declarations: None,
-        };
-        act.add_completed_maneuver(maneuver, &self.entity_ref);
+        let entity_ref = self.entity_ref.clone();
+        act.add_completed_maneuver(self.build(), &entity_ref);
     }
 
     /// Build the final Maneuver object
@@ -481,8 +593,96 @@ impl DetachedManeuverBuilder {
         Maneuver {
             name: OSString::literal(self.maneuver_name),
             events: self.events,
-            parameter_declarations: None,
+            parameter_declarations: self.parameter_declarations,
+        }
+    }
+}
+
+/// Builder for one event of a [`DetachedManeuverBuilder`]
+///
+/// The event starts immediately with override priority unless configured
+/// otherwise, and needs at least one action.
+pub struct DetachedEventBuilder {
+    parent: DetachedManeuverBuilder,
+    event_name: String,
+    priority: Priority,
+    maximum_execution_count: Option<u32>,
+    start_trigger: Option<Trigger>,
+    actions: Vec<StoryAction>,
+}
+
+impl DetachedEventBuilder {
+    /// Create a new event builder for the given maneuver
+    pub fn new(parent: DetachedManeuverBuilder, name: &str) -> Self {
+        Self {
+            parent,
+            event_name: name.to_string(),
+            priority: Priority::Override,
+            maximum_execution_count: None,
+            start_trigger: None,
+            actions: Vec::new(),
+        }
+    }
+
+    /// Set how the event interacts with events already running in the maneuver
+    pub fn priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Set how many times the event may be executed
+    pub fn maximum_execution_count(mut self, count: u32) -> Self {
+        self.maximum_execution_count = Some(count);
+        self
+    }
+
+    /// Set custom start trigger for this event
+    pub fn with_trigger(mut self, trigger: Trigger) -> Self {
+        self.start_trigger = Some(trigger);
+        self
+    }
+
+    /// Add a time-based trigger (convenience method)
+    pub fn with_time_trigger(mut self, time: f64) -> BuilderResult<Self> {
+        let trigger = crate::builder::conditions::TriggerBuilder::new()
+            .add_condition(
+                crate::builder::conditions::TimeConditionBuilder::new()
+                    .at_time(time)
+                    .build()?,
+            )
+            .build()?;
+        self.start_trigger = Some(trigger);
+        Ok(self)
+    }
+
+    /// Add a private action built by any action builder
+    pub fn add_action<A: ActionBuilder>(mut self, name: &str, action: A) -> BuilderResult<Self> {
+        let private_action = action.build_action()?;
+        self.actions.push(StoryAction {
+            name: OSString::literal(name.to_string()),
+            private_action: Some(convert_private_action_to_story(private_action)),
+        });
+        Ok(self)
+    }
+
+    /// Add the event to the maneuver and return to the maneuver builder
+    pub fn finish(self) -> BuilderResult<DetachedManeuverBuilder> {
+        if self.actions.is_empty() {
+            return Err(BuilderError::validation_error(&format!(
+                "Event '{}' needs at least one action",
+                self.event_name
+            )));
         }
+
+        let mut maneuver = self.parent;
+        maneuver.add_event(Event {
+            name: OSString::literal(self.event_name),
+            maximum_execution_count: self.maximum_execution_count.map(Value::literal),
+            priority: Some(self.priority),
+            start_trigger: self.start_trigger.or_else(default_trigger),
+            actions: self.actions,
+        });
+        Ok(maneuver)
     }
 }
 
@@ -1275,6 +1475,14 @@ impl DetachedSpeedProfileActionBuilder {
         self
     }
 
+    pub fn with_dynamic_constraints(
+        mut self,
+        constraints: crate::types::actions::movement::DynamicConstraints,
+    ) -> Self {
+        self.action_builder = self.action_builder.with_dynamic_constraints(constraints);
+        self
+    }
+
     pub fn with_trigger(mut self, trigger: Trigger) -> Self {
         self.start_trigger = Some(trigger);
         self
@@ -1636,4 +1844,159 @@ mod tests {
         assert_eq!(maneuver_builder.entity_ref, "ego");
         assert_eq!(maneuver_builder.events.len(), 0);
     }
+
+    #[test]
+    fn test_event_trigger_with_or_combined_groups() {
+        let scenario_builder = ScenarioBuilder::new()
+            .with_header("Test", "Author")
+            .with_entities();
+
+        let mut storyboard_builder = StoryboardBuilder::new(scenario_builder);
+        let mut story_builder = storyboard_builder.add_story_simple("TestStory");
+        let mut act_builder = story_builder.add_act("TestAct");
+        let mut maneuver_builder = ManeuverBuilder::new(&mut act_builder, "TestManeuver", "ego");
+
+        // (time > 2 AND ego speed > 10) OR time > 20
+        let event_builder = SpeedActionEventBuilder::new(&mut maneuver_builder)
+            .to_speed(30.0)
+            .triggered_by()
+            .add_condition_group()
+            .time_condition(2.0)
+            .speed_condition("ego", 10.0)
+            .finish_group()
+            .add_condition_group()
+            .time_condition(20.0)
+            .finish_group()
+            .finish();
+
+        let trigger = event_builder.start_trigger.as_ref().unwrap();
+        assert_eq!(trigger.condition_groups.len(), 2);
+        assert_eq!(trigger.condition_groups[0].conditions.len(), 2);
+        assert_eq!(trigger.condition_groups[1].conditions.len(), 1);
+    }
+
+    #[test]
+    fn test_maneuver_parameter_declarations() {
+        let scenario_builder = ScenarioBuilder::new()
+            .with_header("Test", "Author")
+            .with_entities();
+
+        let mut storyboard_builder = StoryboardBuilder::new(scenario_builder);
+        let mut story_builder = storyboard_builder.add_story_simple("TestStory");
+        let mut act_builder = story_builder.add_act("TestAct");
+
+        let mut maneuver_builder = ManeuverBuilder::new(&mut act_builder, "CutIn", "ego")
+            .add_parameter("TargetSpeed", ParameterType::Double, "25.0");
+        let maneuver = maneuver_builder.take_maneuver();
+
+        let declarations = maneuver.parameter_declarations.as_ref().unwrap();
+        assert_eq!(declarations.parameter_declarations.len(), 1);
+        assert_eq!(
+            declarations.parameter_declarations[0]
+                .name
+                .as_literal()
+                .unwrap(),
+            "TargetSpeed"
+        );
+
+        let xml = quick_xml::se::to_string_with_root("Maneuver", &maneuver).unwrap();
+        assert!(xml.starts_with("<Maneuver name=\"CutIn\"><ParameterDeclarations>"));
+        assert!(xml.contains(
+            "<ParameterDeclaration name=\"TargetSpeed\" parameterType=\"double\" value=\"25.0\"/>"
+        ));
+
+        let detached = DetachedManeuverBuilder::new("CutIn", "ego")
+            .add_parameter("TargetSpeed", ParameterType::Double, "25.0")
+            .build();
+        assert_eq!(detached, maneuver);
+    }
+
+    #[test]
+    fn test_detached_maneuver_with_multiple_events() {
+        use crate::builder::actions::LaneChangeActionBuilder;
+
+        let maneuver = DetachedManeuverBuilder::new("Overtake", "ego")
+            .create_event("Accelerate")
+            .add_action(
+                "SpeedUp",
+                SpeedActionBuilder::new().for_entity("ego").to_speed(30.0),
+            )
+            .unwrap()
+            .finish()
+            .unwrap()
+            .create_event("ChangeLane")
+            .with_time_trigger(5.0)
+            .unwrap()
+            .priority(Priority::Parallel)
+            .maximum_execution_count(1)
+            .add_action(
+                "MoveLeft",
+                LaneChangeActionBuilder::new()
+                    .for_entity("ego")
+                    .to_relative_lane("ego", 1),
+            )
+            .unwrap()
+            .finish()
+            .unwrap()
+            .build();
+
+        assert_eq!(maneuver.events.len(), 2);
+        let accelerate = &maneuver.events[0];
+        assert_eq!(accelerate.name.as_literal().unwrap(), "Accelerate");
+        assert_eq!(accelerate.priority, Some(Priority::Override));
+        assert!(accelerate.start_trigger.is_some());
+        assert!(accelerate.actions[0]
+            .private_action
+            .as_ref()
+            .unwrap()
+            .longitudinal_action
+            .is_some());
+
+        let change_lane = &maneuver.events[1];
+        assert_eq!(change_lane.name.as_literal().unwrap(), "ChangeLane");
+        assert_eq!(change_lane.priority, Some(Priority::Parallel));
+        assert_eq!(change_lane.maximum_execution_count, Some(Value::literal(1)));
+        assert!(change_lane.actions[0]
+            .private_action
+            .as_ref()
+            .unwrap()
+            .lateral_action
+            .is_some());
+
+        // An event without actions is rejected
+        assert!(DetachedManeuverBuilder::new("Empty", "ego")
+            .create_event("Nothing")
+            .finish()
+            .is_err());
+    }
+
+    #[test]
+    fn test_event_priority_round_trip() {
+        let cases = [
+            (Priority::Overwrite, "overwrite"),
+            (Priority::Override, "override"),
+            (Priority::Parallel, "parallel"),
+            (Priority::Skip, "skip"),
+        ];
+
+        for (priority, token) in cases {
+            let maneuver = DetachedManeuverBuilder::new("Prioritized", "ego")
+                .create_event("Accelerate")
+                .priority(priority.clone())
+                .add_action(
+                    "SpeedUp",
+                    SpeedActionBuilder::new().for_entity("ego").to_speed(30.0),
+                )
+                .unwrap()
+                .finish()
+                .unwrap()
+                .build();
+
+            let xml = quick_xml::se::to_string_with_root("Maneuver", &maneuver).unwrap();
+            assert!(xml.contains(&format!("<Event name=\"Accelerate\" priority=\"{token}\">")));
+
+            let parsed: Maneuver = quick_xml::de::from_str(&xml).unwrap();
+            assert_eq!(parsed.events[0].priority, Some(priority));
+        }
+    }
 }
@@ -4,7 +4,7 @@ pub mod maneuver;
 pub mod story;
 
 pub use maneuver::{
-    DetachedAssignRouteActionBuilder, DetachedFollowTrajectoryActionBuilder,
+    DetachedAssignRouteActionBuilder, DetachedEventBuilder, DetachedFollowTrajectoryActionBuilder,
     DetachedLongitudinalDistanceActionBuilder, DetachedManeuverBuilder, DetachedSpeedActionBuilder,
     DetachedSpeedProfileActionBuilder, DetachedSynchronizeActionBuilder,
     DetachedTeleportActionBuilder, DetachedVisibilityActionBuilder, ManeuverBuilder,
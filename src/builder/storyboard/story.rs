@@ -4,7 +4,8 @@ use crate::builder::{
     init::InitActionBuilder, scenario::HasEntities, scenario::ScenarioBuilder, BuilderResult,
 };
 use crate::types::{
-    basic::{OSString, ParameterDeclarations, UnsignedInt},
+    basic::{OSString, ParameterDeclaration, ParameterDeclarations, UnsignedInt},
+    enums::ParameterType,
     scenario::{
         init::Init,
         story::{Act, Actors, ManeuverGroup, ScenarioStory},
@@ -169,6 +170,19 @@ impl<'parent> StoryBuilder<'parent> {
         self
     }
 
+    /// Declare a parameter scoped to this story
+    pub fn add_parameter(mut self, name: &str, param_type: ParameterType, value: &str) -> Self {
+        self.parameter_declarations
+            .get_or_insert_with(ParameterDeclarations::default)
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                name.to_string(),
+                param_type,
+                value.to_string(),
+            ));
+        self
+    }
+
     /// Add an act to this story
     ///
     /// # Usage Note
@@ -315,7 +329,7 @@ impl<'parent> ActBuilder<'parent> {
                         entity_ref: crate::types::basic::Value::literal(entity_ref.to_string()),
                     }],
                 },
-                catalog_reference: None,
+                catalog_references: Vec::new(),
                 maneuvers: Vec::new(),
             });
         } else {
@@ -410,7 +424,7 @@ impl DetachedActBuilder {
                         entity_ref: crate::types::basic::Value::literal(entity_ref.to_string()),
                     }],
                 },
-                catalog_reference: None,
+                catalog_references: Vec::new(),
                 maneuvers: Vec::new(),
             });
         } else {
@@ -461,7 +475,7 @@ impl DetachedActBuilder {
                         entity_ref: crate::types::basic::Value::literal(entity_ref.to_string()),
                     }],
                 },
-                catalog_reference: None,
+                catalog_references: Vec::new(),
                 maneuvers: Vec::new(),
             });
         } else {
@@ -578,6 +592,19 @@ impl DetachedStoryBuilder {
         self
     }
 
+    /// Declare a parameter scoped to this story
+    pub fn add_parameter(mut self, name: &str, param_type: ParameterType, value: &str) -> Self {
+        self.parameter_declarations
+            .get_or_insert_with(ParameterDeclarations::default)
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                name.to_string(),
+                param_type,
+                value.to_string(),
+            ));
+        self
+    }
+
     /// Add an act using closure-based configuration
     pub fn add_act<F>(mut self, name: &str, config: F) -> Self
     where
@@ -671,6 +698,47 @@ mod tests {
         assert_eq!(story_builder.acts.len(), 0);
     }
 
+    #[test]
+    fn test_storyboard_with_multiple_stories() {
+        let scenario_builder = ScenarioBuilder::new()
+            .with_header("Test", "Author")
+            .with_entities();
+
+        let mut storyboard_builder = StoryboardBuilder::new(scenario_builder)
+            .add_story("Approach", |story| story.add_act("Accelerate", |act| act))
+            .add_story("CutIn", |story| {
+                story
+                    .add_parameter("CutInGap", ParameterType::Double, "12.5")
+                    .add_act("LaneChange", |act| act)
+            });
+        storyboard_builder
+            .add_story_simple("Braking")
+            .add_parameter("Deceleration", ParameterType::Double, "-6.0")
+            .finish();
+
+        let stories = &storyboard_builder.stories;
+        let names: Vec<&str> = stories
+            .iter()
+            .map(|story| story.name.as_literal().unwrap().as_str())
+            .collect();
+        assert_eq!(names, ["Approach", "CutIn", "Braking"]);
+
+        assert!(stories[0].parameter_declarations.is_none());
+        let local = &stories[1]
+            .parameter_declarations
+            .as_ref()
+            .unwrap()
+            .parameter_declarations;
+        assert_eq!(local.len(), 1);
+        assert_eq!(local[0].name.as_literal().unwrap(), "CutInGap");
+        assert_eq!(local[0].parameter_type, ParameterType::Double);
+        assert_eq!(local[0].value.as_literal().unwrap(), "12.5");
+        assert!(stories[2].parameter_declarations.is_some());
+
+        let storyboard = storyboard_builder.finish().data.storyboard.unwrap();
+        assert_eq!(storyboard.stories.len(), 3);
+    }
+
     #[test]
     fn test_maneuver_group_has_actors() {
         let mut act = DetachedActBuilder::new("test_act");
@@ -0,0 +1,233 @@
+//! Builder for parameter variation documents
+//!
+//! A parameter variation document references a scenario file and describes
+//! how its parameters are swept, either deterministically (value sets and
+//! ranges) or stochastically (sampled distributions). The two kinds cannot be
+//! mixed in one document.
+//!
+//! # Usage Examples
+//!
+//! ```rust
+//! use openscenario_rs::builder::ParameterVariationBuilder;
+//!
+//! let variation = ParameterVariationBuilder::new()
+//!     .with_header("Ego speed sweep", "Test Author")
+//!     .scenario_file("cut_in.xosc")
+//!     .add_value_set("EgoSpeed", &["20.0", "25.0", "30.0"])
+//!     .add_range("Gap", 10.0, 30.0, 5.0)
+//!     .build()
+//!     .unwrap();
+//!
+//! assert!(variation.is_parameter_variation());
+//! ```
+
+use crate::builder::{BuilderError, BuilderResult};
+use crate::types::{
+    basic::{Double, OSString, Range, UnsignedInt, UnsignedShort},
+    distributions::{
+        Deterministic, DeterministicSingleParameterDistribution, DistributionRange,
+        DistributionSet, DistributionSetElement, ParameterValueDistribution, Stochastic,
+        StochasticDistribution,
+    },
+    entities::vehicle::File,
+    scenario::storyboard::{FileHeader, OpenScenario},
+};
+
+/// Builder for `OpenScenario` documents of the parameter variation category
+#[derive(Debug, Default)]
+pub struct ParameterVariationBuilder {
+    file_header: Option<FileHeader>,
+    scenario_file: Option<String>,
+    deterministic: Deterministic,
+    stochastic: Vec<StochasticDistribution>,
+    number_of_test_runs: Option<u32>,
+    random_seed: Option<f64>,
+}
+
+impl ParameterVariationBuilder {
+    /// Create new parameter variation builder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the file header description and author
+    pub fn with_header(mut self, description: &str, author: &str) -> Self {
+        let now = chrono::Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string();
+
+        self.file_header = Some(FileHeader {
+            rev_major: UnsignedShort::literal(1),
+            rev_minor: UnsignedShort::literal(0),
+            date: OSString::literal(now),
+            description: OSString::literal(description.to_string()),
+            author: OSString::literal(author.to_string()),
+        });
+        self
+    }
+
+    /// Set the path of the scenario file whose parameters are varied
+    pub fn scenario_file(mut self, filepath: &str) -> Self {
+        self.scenario_file = Some(filepath.to_string());
+        self
+    }
+
+    /// Vary a parameter over an explicit set of values
+    pub fn add_value_set(mut self, parameter: &str, values: &[&str]) -> Self {
+        let elements = values
+            .iter()
+            .map(|value| DistributionSetElement {
+                value: OSString::literal(value.to_string()),
+            })
+            .collect();
+
+        self.deterministic
+            .add_single(DeterministicSingleParameterDistribution {
+                parameter_name: OSString::literal(parameter.to_string()),
+                distribution_set: Some(DistributionSet { elements }),
+                distribution_range: None,
+                user_defined_distribution: None,
+            });
+        self
+    }
+
+    /// Vary a parameter from `lower` to `upper` in steps of `step_width`
+    pub fn add_range(mut self, parameter: &str, lower: f64, upper: f64, step_width: f64) -> Self {
+        self.deterministic
+            .add_single(DeterministicSingleParameterDistribution {
+                parameter_name: OSString::literal(parameter.to_string()),
+                distribution_set: None,
+                distribution_range: Some(DistributionRange {
+                    step_width: OSString::literal(step_width.to_string()),
+                    range: Range {
+                        lower_limit: Double::literal(lower),
+                        upper_limit: Double::literal(upper),
+                    },
+                }),
+                user_defined_distribution: None,
+            });
+        self
+    }
+
+    /// Add a stochastic distribution for one parameter
+    pub fn add_stochastic(mut self, distribution: StochasticDistribution) -> Self {
+        self.stochastic.push(distribution);
+        self
+    }
+
+    /// Set the number of test runs sampled from the stochastic distributions
+    pub fn number_of_test_runs(mut self, runs: u32) -> Self {
+        self.number_of_test_runs = Some(runs);
+        self
+    }
+
+    /// Set the random seed for the stochastic distributions
+    pub fn random_seed(mut self, seed: f64) -> Self {
+        self.random_seed = Some(seed);
+        self
+    }
+
+    /// Build the parameter variation document
+    pub fn build(self) -> BuilderResult<OpenScenario> {
+        let filepath = self.scenario_file.ok_or_else(|| {
+            BuilderError::missing_field(
+                "scenario_file",
+                "Call .scenario_file() with the path of the varied scenario",
+            )
+        })?;
+        let scenario_file = File { filepath };
+
+        let has_deterministic = !self.deterministic.is_empty();
+        let has_stochastic = !self.stochastic.is_empty();
+        let distribution = match (has_deterministic, has_stochastic) {
+            (true, false) => {
+                ParameterValueDistribution::new_deterministic(scenario_file, self.deterministic)
+            }
+            (false, true) => {
+                let number_of_test_runs = self.number_of_test_runs.ok_or_else(|| {
+                    BuilderError::missing_field(
+                        "number_of_test_runs",
+                        "Call .number_of_test_runs() for stochastic variations",
+                    )
+                })?;
+                ParameterValueDistribution::new_stochastic(
+                    scenario_file,
+                    Stochastic {
+                        distributions: self.stochastic,
+                        number_of_test_runs: UnsignedInt::literal(number_of_test_runs),
+                        random_seed: self.random_seed.map(Double::literal),
+                    },
+                )
+            }
+            (false, false) => {
+                return Err(BuilderError::validation_error(
+                    "Parameter variation needs at least one distribution",
+                ))
+            }
+            (true, true) => {
+                return Err(BuilderError::validation_error(
+                    "Deterministic and stochastic distributions cannot be mixed",
+                ))
+            }
+        };
+
+        Ok(OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
+            file_header: self
+                .file_header
+                .unwrap_or_else(|| OpenScenario::default().file_header),
+            parameter_declarations: None,
+            variable_declarations: None,
+            monitor_declarations: None,
+            catalog_locations: None,
+            road_network: None,
+            entities: None,
+            storyboard: None,
+            parameter_value_distribution: Some(distribution),
+            catalog: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::xml::{parse_from_str, serialize_to_string};
+
+    #[test]
+    fn test_deterministic_variation_round_trip() {
+        let variation = ParameterVariationBuilder::new()
+            .with_header("Speed sweep", "Test Author")
+            .scenario_file("scenarios/cut_in.xosc")
+            .add_value_set("EgoSpeed", &["20.0", "25.0", "30.0"])
+            .build()
+            .unwrap();
+        assert!(variation.is_parameter_variation());
+
+        let xml = serialize_to_string(&variation).unwrap();
+        let parsed = parse_from_str(&xml).unwrap();
+        assert!(parsed.is_parameter_variation());
+
+        let distribution = parsed.parameter_value_distribution.unwrap();
+        assert_eq!(distribution.scenario_file.filepath, "scenarios/cut_in.xosc");
+        assert!(distribution.stochastic.is_none());
+
+        let deterministic = distribution.deterministic.unwrap();
+        assert_eq!(deterministic.single_distributions.len(), 1);
+        let single = &deterministic.single_distributions[0];
+        assert_eq!(single.parameter_name.as_literal().unwrap(), "EgoSpeed");
+        let values: Vec<&str> = single
+            .distribution_set
+            .as_ref()
+            .unwrap()
+            .elements
+            .iter()
+            .map(|element| element.value.as_literal().unwrap().as_str())
+            .collect();
+        assert_eq!(values, ["20.0", "25.0", "30.0"]);
+
+        assert!(ParameterVariationBuilder::new()
+            .scenario_file("scenarios/cut_in.xosc")
+            .build()
+            .is_err());
+    }
+}
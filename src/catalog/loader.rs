@@ -7,29 +7,99 @@
 //! - File system operations for catalog discovery
 
 use crate::error::{Error, Result};
-use crate::parser::xml::{parse_catalog_from_file, parse_catalog_from_str};
+use crate::parser::xml::{parse_catalog_from_file, parse_catalog_from_str, read_xml_file};
 use crate::types::basic::Directory;
-use crate::types::catalogs::entities::{CatalogController, CatalogPedestrian, CatalogVehicle};
+use crate::types::catalogs::entities::{
+    CatalogController, CatalogEntity, CatalogPedestrian, CatalogVehicle,
+};
 use crate::types::catalogs::files::CatalogFile;
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fs;
-use std::path::{Path, PathBuf};
+use std::path::{Component, Path, PathBuf};
+
+/// Parsed catalog file with name-to-index maps for entry lookup
+#[derive(Debug, Clone)]
+pub struct IndexedCatalogFile {
+    catalog: CatalogFile,
+    vehicles: HashMap<String, usize>,
+    controllers: HashMap<String, usize>,
+    pedestrians: HashMap<String, usize>,
+}
+
+impl IndexedCatalogFile {
+    /// Index the vehicles, controllers and pedestrians of a catalog file
+    ///
+    /// When several entries share a name the first one wins, matching a
+    /// linear search from the start of the file.
+    pub fn new(catalog: CatalogFile) -> Self {
+        Self {
+            vehicles: index_entries(catalog.vehicles()),
+            controllers: index_entries(catalog.controllers()),
+            pedestrians: index_entries(catalog.pedestrians()),
+            catalog,
+        }
+    }
+
+    /// Get the underlying catalog file
+    pub fn catalog(&self) -> &CatalogFile {
+        &self.catalog
+    }
+
+    /// Look up a vehicle by name
+    pub fn vehicle(&self, name: &str) -> Option<&CatalogVehicle> {
+        self.vehicles
+            .get(name)
+            .map(|&index| &self.catalog.vehicles()[index])
+    }
+
+    /// Look up a controller by name
+    pub fn controller(&self, name: &str) -> Option<&CatalogController> {
+        self.controllers
+            .get(name)
+            .map(|&index| &self.catalog.controllers()[index])
+    }
+
+    /// Look up a pedestrian by name
+    pub fn pedestrian(&self, name: &str) -> Option<&CatalogPedestrian> {
+        self.pedestrians
+            .get(name)
+            .map(|&index| &self.catalog.pedestrians()[index])
+    }
+}
+
+fn index_entries<T: CatalogEntity>(entries: &[T]) -> HashMap<String, usize> {
+    let mut index = HashMap::with_capacity(entries.len());
+    for (i, entry) in entries.iter().enumerate() {
+        index.entry(entry.entity_name().to_string()).or_insert(i);
+    }
+    index
+}
 
 /// Catalog file loader that handles file system operations
 pub struct CatalogLoader {
     /// Base path for resolving relative catalog paths
     base_path: Option<PathBuf>,
+    /// Parsed and indexed catalog files keyed by path
+    ///
+    /// Behind a `RefCell` so the `&self` entity loaders can fill the cache.
+    indexed: RefCell<HashMap<PathBuf, IndexedCatalogFile>>,
 }
 
 impl CatalogLoader {
     /// Create a new catalog loader
     pub fn new() -> Self {
-        Self { base_path: None }
+        Self {
+            base_path: None,
+            indexed: RefCell::new(HashMap::new()),
+        }
     }
 
     /// Create a catalog loader with a specific base path
     pub fn with_base_path<P: AsRef<Path>>(base_path: P) -> Self {
         Self {
             base_path: Some(base_path.as_ref().to_path_buf()),
+            indexed: RefCell::new(HashMap::new()),
         }
     }
 
@@ -93,8 +163,7 @@ impl CatalogLoader {
             return Err(Error::file_not_found(&path.to_string_lossy()));
         }
 
-        fs::read_to_string(path)
-            .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))
+        read_xml_file(path)
     }
 
     /// Load and parse a catalog file into a CatalogFile structure
@@ -113,55 +182,99 @@ impl CatalogLoader {
         })
     }
 
+    /// Load, parse and index a catalog file, reusing the cached result
+    ///
+    /// The first call for a path parses the file and builds name-to-index
+    /// maps for its entries; later calls return the cached index without
+    /// touching the file system.
+    pub fn load_indexed_catalog_file<P: AsRef<Path>>(
+        &mut self,
+        file_path: P,
+    ) -> Result<&IndexedCatalogFile> {
+        let path = file_path.as_ref();
+        if !self.indexed.get_mut().contains_key(path) {
+            let catalog = self.load_and_parse_catalog_file(path)?;
+            self.indexed
+                .get_mut()
+                .insert(path.to_path_buf(), IndexedCatalogFile::new(catalog));
+        }
+        Ok(&self.indexed.get_mut()[path])
+    }
+
+    /// Drop all cached catalog indexes so files are re-read on next use
+    ///
+    /// Cached files are never re-read on their own, so call this after
+    /// catalog files change on disk.
+    pub fn clear_cache(&mut self) {
+        self.indexed.get_mut().clear();
+    }
+
     /// Load and parse a catalog from XML string
     pub fn parse_catalog_from_string(&self, xml: &str) -> Result<CatalogFile> {
         parse_catalog_from_str(xml)
             .map_err(|e| e.with_context("Failed to parse catalog from string"))
     }
 
-    /// Load all vehicle catalogs from a directory
-    pub fn load_vehicle_catalogs(&self, directory: &Directory) -> Result<Vec<CatalogVehicle>> {
+    /// Load, parse and index every catalog file in a directory
+    ///
+    /// Files are cached as in [`load_indexed_catalog_file`](Self::load_indexed_catalog_file),
+    /// so a catalog file holding several entity types is parsed once for all
+    /// of the entity loaders below.
+    pub fn load_indexed_catalog_files(
+        &mut self,
+        directory: &Directory,
+    ) -> Result<Vec<&IndexedCatalogFile>> {
         let catalog_files = self.discover_catalog_files(directory)?;
-        let mut vehicles = Vec::new();
-
-        for file_path in catalog_files {
-            let catalog = self.load_and_parse_catalog_file(&file_path)?;
-            vehicles.extend(catalog.vehicles().iter().cloned());
+        for file_path in &catalog_files {
+            self.load_indexed_catalog_file(file_path)?;
         }
+        let indexed = self.indexed.get_mut();
+        Ok(catalog_files
+            .iter()
+            .map(|file_path| &indexed[file_path])
+            .collect())
+    }
 
-        Ok(vehicles)
+    /// Load all vehicles from the catalog files in a directory
+    pub fn load_vehicle_catalogs(&self, directory: &Directory) -> Result<Vec<CatalogVehicle>> {
+        self.collect_catalog_entries(directory, CatalogFile::vehicles)
     }
 
-    /// Load all controller catalogs from a directory
+    /// Load all controllers from the catalog files in a directory
     pub fn load_controller_catalogs(
         &self,
         directory: &Directory,
     ) -> Result<Vec<CatalogController>> {
-        let catalog_files = self.discover_catalog_files(directory)?;
-        let mut controllers = Vec::new();
-
-        for file_path in catalog_files {
-            let catalog = self.load_and_parse_catalog_file(&file_path)?;
-            controllers.extend(catalog.controllers().iter().cloned());
-        }
-
-        Ok(controllers)
+        self.collect_catalog_entries(directory, CatalogFile::controllers)
     }
 
-    /// Load all pedestrian catalogs from a directory
+    /// Load all pedestrians from the catalog files in a directory
     pub fn load_pedestrian_catalogs(
         &self,
         directory: &Directory,
     ) -> Result<Vec<CatalogPedestrian>> {
-        let catalog_files = self.discover_catalog_files(directory)?;
-        let mut pedestrians = Vec::new();
+        self.collect_catalog_entries(directory, CatalogFile::pedestrians)
+    }
 
-        for file_path in catalog_files {
-            let catalog = self.load_and_parse_catalog_file(&file_path)?;
-            pedestrians.extend(catalog.pedestrians().iter().cloned());
+    /// Clone one kind of entry out of every catalog file in a directory
+    ///
+    /// Each file is parsed once and cached, as in
+    /// [`load_indexed_catalog_files`](Self::load_indexed_catalog_files).
+    fn collect_catalog_entries<T: Clone>(
+        &self,
+        directory: &Directory,
+        entries: impl Fn(&CatalogFile) -> &[T],
+    ) -> Result<Vec<T>> {
+        let mut collected = Vec::new();
+        for file_path in self.discover_catalog_files(directory)? {
+            if !self.indexed.borrow().contains_key(&file_path) {
+                let catalog =
+                    IndexedCatalogFile::new(self.load_and_parse_catalog_file(&file_path)?);
+                self.indexed.borrow_mut().insert(file_path.clone(), catalog);
+            }
+            collected.extend_from_slice(entries(self.indexed.borrow()[&file_path].catalog()));
         }
-
-        Ok(pedestrians)
+        Ok(collected)
     }
 
     /// Find a specific entity in a catalog file
@@ -191,8 +304,7 @@ impl CatalogLoader {
             return Err(Error::file_not_found(&path.to_string_lossy()));
         }
 
-        let xml_content = fs::read_to_string(path)
-            .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))?;
+        let xml_content = read_xml_file(path)?;
 
         quick_xml::de::from_str(&xml_content)
             .map_err(|e| Error::parse_error(&path.to_string_lossy(), &e.to_string()))
@@ -208,8 +320,7 @@ impl CatalogLoader {
             return Err(Error::file_not_found(&path.to_string_lossy()));
         }
 
-        let xml_content = fs::read_to_string(path)
-            .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))?;
+        let xml_content = read_xml_file(path)?;
 
         quick_xml::de::from_str(&xml_content)
             .map_err(|e| Error::parse_error(&path.to_string_lossy(), &e.to_string()))
@@ -225,8 +336,7 @@ impl CatalogLoader {
             return Err(Error::file_not_found(&path.to_string_lossy()));
         }
 
-        let xml_content = fs::read_to_string(path)
-            .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))?;
+        let xml_content = read_xml_file(path)?;
 
         quick_xml::de::from_str(&xml_content)
             .map_err(|e| Error::parse_error(&path.to_string_lossy(), &e.to_string()))
@@ -242,8 +352,7 @@ impl CatalogLoader {
             return Err(Error::file_not_found(&path.to_string_lossy()));
         }
 
-        let xml_content = fs::read_to_string(path)
-            .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))?;
+        let xml_content = read_xml_file(path)?;
 
         quick_xml::de::from_str(&xml_content)
             .map_err(|e| Error::parse_error(&path.to_string_lossy(), &e.to_string()))
@@ -345,20 +454,52 @@ impl CatalogLoader {
     }
 
     /// Resolve a path relative to the base path if needed
+    ///
+    /// Absolute paths are used as is. Relative paths such as `./catalogs` or
+    /// `../Catalogs/Vehicles` are joined against the base path, or against the
+    /// scenario file's directory if the base path names a file. `.` and `..`
+    /// components are then removed without touching the file system.
     fn resolve_path(&self, path: &str) -> Result<PathBuf> {
         let path = Path::new(path);
 
         if path.is_absolute() {
-            Ok(path.to_path_buf())
+            Ok(normalize_path(path))
         } else if let Some(base) = &self.base_path {
-            Ok(base.join(path))
+            let base = if base.is_file() {
+                base.parent().unwrap_or(Path::new(""))
+            } else {
+                base.as_path()
+            };
+            Ok(normalize_path(&base.join(path)))
         } else {
             // Use current directory as base
-            Ok(std::env::current_dir()?.join(path))
+            Ok(normalize_path(&std::env::current_dir()?.join(path)))
         }
     }
 }
 
+/// Remove `.` components and fold `..` into the preceding component
+///
+/// A `..` that cannot be folded (at the start of a relative path) is kept, and
+/// one directly after the root is dropped, matching how the OS resolves it.
+fn normalize_path(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => match normalized.components().next_back() {
+                Some(Component::Normal(_)) => {
+                    normalized.pop();
+                }
+                Some(Component::RootDir) | Some(Component::Prefix(_)) => {}
+                _ => normalized.push(".."),
+            },
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
 impl Default for CatalogLoader {
     fn default() -> Self {
         Self::new()
@@ -448,4 +589,136 @@ mod tests {
         assert_eq!(catalog.file_header.author.as_literal().unwrap(), "Test");
         assert_eq!(catalog.catalog.entity_count(), 0);
     }
+
+    #[test]
+    fn test_load_indexed_catalog_file_caches_index() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let file_path = temp_dir.path().join("ControllerCatalog.xosc");
+        let catalog_xml = r#"<?xml version="1.0"?>
+        <OpenSCENARIO>
+            <FileHeader author="Test" date="2024-01-01T00:00:00" description="Test" revMajor="1" revMinor="3"/>
+            <Catalog name="ControllerCatalog">
+                <Controller name="Calm"/>
+                <Controller name="Aggressive"/>
+                <Controller name="Calm" controllerType="lateral"/>
+            </Catalog>
+        </OpenSCENARIO>"#;
+        fs::write(&file_path, catalog_xml)?;
+
+        let mut loader = CatalogLoader::new();
+        let indexed = loader.load_indexed_catalog_file(&file_path)?;
+        assert_eq!(indexed.controller("Aggressive").unwrap().name, "Aggressive");
+        // Duplicate names resolve to the first entry
+        assert!(std::ptr::eq(
+            indexed.controller("Calm").unwrap(),
+            &indexed.catalog().controllers()[0]
+        ));
+        assert!(indexed.controller("Missing").is_none());
+        assert!(indexed.vehicle("Calm").is_none());
+
+        // Later loads are served from the cache, even if the file is gone
+        fs::remove_file(&file_path)?;
+        let indexed = loader.load_indexed_catalog_file(&file_path)?;
+        assert!(indexed.controller("Aggressive").is_some());
+
+        loader.clear_cache();
+        assert!(loader.load_indexed_catalog_file(&file_path).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_mixed_entity_catalog_parsed_once() -> Result<()> {
+        let temp_dir = TempDir::new().unwrap();
+        let catalog_xml = r#"<?xml version="1.0"?>
+        <OpenSCENARIO>
+            <FileHeader author="Test" date="2024-01-01T00:00:00" description="Test" revMajor="1" revMinor="3"/>
+            <Catalog name="MixedCatalog">
+                <Vehicle name="Car" vehicleCategory="car">
+                    <BoundingBox>
+                        <Center x="1.4" y="0.0" z="0.9"/>
+                        <Dimensions width="2.0" length="5.0" height="1.8"/>
+                    </BoundingBox>
+                    <Performance maxSpeed="69" maxAcceleration="10" maxDeceleration="10"/>
+                    <Axles>
+                        <FrontAxle maxSteering="0.5" wheelDiameter="0.6" trackWidth="1.8" positionX="3.1" positionZ="0.3"/>
+                        <RearAxle maxSteering="0.0" wheelDiameter="0.6" trackWidth="1.8" positionX="0.0" positionZ="0.3"/>
+                    </Axles>
+                </Vehicle>
+                <Pedestrian name="Walker" pedestrianCategory="pedestrian" mass="75.0">
+                    <BoundingBox>
+                        <Center x="0.0" y="0.0" z="0.9"/>
+                        <Dimensions width="0.6" length="0.6" height="1.8"/>
+                    </BoundingBox>
+                </Pedestrian>
+            </Catalog>
+        </OpenSCENARIO>"#;
+        fs::write(temp_dir.path().join("MixedCatalog.xosc"), catalog_xml)?;
+        let directory = Directory::new(temp_dir.path().to_string_lossy().to_string());
+
+        let mut loader = CatalogLoader::new();
+        let vehicles = loader.load_vehicle_catalogs(&directory)?;
+        let pedestrians = loader.load_pedestrian_catalogs(&directory)?;
+        assert_eq!(vehicles.len(), 1);
+        assert_eq!(vehicles[0].name, "Car");
+        assert_eq!(pedestrians.len(), 1);
+        assert_eq!(pedestrians[0].name, "Walker");
+        assert!(loader.load_controller_catalogs(&directory)?.is_empty());
+
+        // Both loaders read the same cached parse of the file
+        assert_eq!(loader.indexed.borrow().len(), 1);
+        let indexed = loader.load_indexed_catalog_files(&directory)?;
+        assert!(indexed[0].vehicle("Car").is_some());
+        assert!(indexed[0].pedestrian("Walker").is_some());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_relative_directory_resolved_against_base_path() -> Result<()> {
+        // scenarios/highway.xosc referencing ../catalogs/vehicles
+        let temp_dir = TempDir::new().unwrap();
+        let scenario_dir = temp_dir.path().join("scenarios");
+        let catalog_dir = temp_dir.path().join("catalogs").join("vehicles");
+        fs::create_dir_all(&scenario_dir)?;
+        fs::create_dir_all(&catalog_dir)?;
+        fs::write(catalog_dir.join("VehicleCatalog.xosc"), "catalog")?;
+        fs::write(scenario_dir.join("highway.xosc"), "scenario")?;
+
+        let expected = vec![catalog_dir.join("VehicleCatalog.xosc")];
+        let loader = CatalogLoader::with_base_path(&scenario_dir);
+        let files =
+            loader.discover_catalog_files(&Directory::new("../catalogs/vehicles".to_string()))?;
+        assert_eq!(files, expected);
+        let files = loader
+            .discover_catalog_files(&Directory::new("./../catalogs/./vehicles".to_string()))?;
+        assert_eq!(files, expected);
+
+        // A scenario file as base path resolves against its directory
+        let loader = CatalogLoader::with_base_path(scenario_dir.join("highway.xosc"));
+        let files =
+            loader.discover_catalog_files(&Directory::new("../catalogs/vehicles".to_string()))?;
+        assert_eq!(files, expected);
+
+        // Absolute paths ignore the base path
+        let loader = CatalogLoader::with_base_path("/nonexistent");
+        let files = loader
+            .discover_catalog_files(&Directory::new(catalog_dir.to_string_lossy().to_string()))?;
+        assert_eq!(files, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_path() {
+        assert_eq!(
+            normalize_path(Path::new("/base/./scenarios/../catalogs")),
+            PathBuf::from("/base/catalogs")
+        );
+        assert_eq!(normalize_path(Path::new("/../a")), PathBuf::from("/a"));
+        assert_eq!(
+            normalize_path(Path::new("../../a/./b/..")),
+            PathBuf::from("../../a")
+        );
+    }
 }
@@ -19,13 +19,15 @@ use crate::types::catalogs::references::{
 };
 use crate::types::controllers::Controller;
 use crate::types::entities::vehicle::Vehicle;
+use crate::types::entities::{ScenarioEntityReference, ScenarioObject};
+use crate::types::scenario::storyboard::OpenScenario;
 
 pub mod loader;
 pub mod parameters;
 pub mod resolver;
 
 // Re-export key types for convenience
-pub use loader::CatalogLoader;
+pub use loader::{CatalogLoader, IndexedCatalogFile};
 pub use parameters::ParameterSubstitutionEngine;
 pub use resolver::{CatalogResolver, ResolvedCatalog};
 
@@ -62,6 +64,30 @@ pub trait ScenarioResolver: Sized {
     fn resolve_all_catalogs(self) -> Result<Self, crate::error::Error>;
 }
 
+/// A catalog reference that failed to resolve during
+/// [`CatalogManager::validate_references`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnresolvedReference {
+    /// Name of the scenario object holding the reference
+    pub object_name: String,
+    /// Catalog name of the reference
+    pub catalog_name: String,
+    /// Entry name of the reference
+    pub entry_name: String,
+    /// Why the reference could not be resolved
+    pub reason: String,
+}
+
+/// A scenario object with its catalog references resolved, yielded by
+/// [`CatalogManager::resolved_entities`]
+#[derive(Debug, Clone)]
+pub struct ResolvedEntity {
+    /// The object with catalog references replaced by the referenced entries
+    pub object: ScenarioObject,
+    /// Catalog files the referenced entries were read from
+    pub catalog_paths: Vec<String>,
+}
+
 /// Main catalog manager that coordinates loading and resolution
 pub struct CatalogManager {
     loader: CatalogLoader,
@@ -117,6 +143,9 @@ impl CatalogManager {
                 .as_literal()
                 .unwrap_or(&"unknown".to_string())
         );
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("resolve_catalog_reference", reference = %reference_key).entered();
         self.resolver.begin_resolution(&reference_key)?;
 
         // Load catalog files from the location and track file paths
@@ -130,15 +159,10 @@ impl CatalogManager {
         })?;
 
         for file_path in catalog_files {
-            let catalog = self.loader.load_and_parse_catalog_file(&file_path)?;
-            for vehicle in catalog.vehicles() {
-                if vehicle.entity_name() == entry_name {
-                    catalog_vehicle = Some(vehicle.clone());
-                    catalog_file_path = file_path.to_string_lossy().to_string();
-                    break;
-                }
-            }
-            if catalog_vehicle.is_some() {
+            let catalog = self.loader.load_indexed_catalog_file(&file_path)?;
+            if let Some(vehicle) = catalog.vehicle(entry_name) {
+                catalog_vehicle = Some(vehicle.clone());
+                catalog_file_path = file_path.to_string_lossy().to_string();
                 break;
             }
         }
@@ -151,22 +175,7 @@ impl CatalogManager {
         })?;
 
         // Resolve parameters if any
-        let mut parameters = std::collections::HashMap::new();
-        if let Some(assignments) = &reference.parameter_assignments {
-            for assignment in assignments.iter() {
-                let resolved_name = assignment.parameter_ref.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter names",
-                    )
-                })?;
-                let resolved_value = assignment.value.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter values",
-                    )
-                })?;
-                parameters.insert(resolved_name.clone(), resolved_value.clone());
-            }
-        }
+        let parameters = reference.to_parameter_map()?;
 
         // Convert catalog vehicle to scenario vehicle
         let resolved_vehicle = catalog_vehicle
@@ -176,6 +185,14 @@ impl CatalogManager {
         // End resolution tracking
         self.resolver.end_resolution(&reference_key);
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            entry = %entry_name,
+            file = %catalog_file_path,
+            parameters = parameters.len(),
+            "resolved catalog reference"
+        );
+
         Ok(ResolvedCatalog::with_parameters(
             resolved_vehicle,
             catalog_file_path,
@@ -204,6 +221,9 @@ impl CatalogManager {
                 .as_literal()
                 .unwrap_or(&"unknown".to_string())
         );
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("resolve_catalog_reference", reference = %reference_key).entered();
         self.resolver.begin_resolution(&reference_key)?;
 
         // Load catalog files from the location and track file paths
@@ -217,15 +237,10 @@ impl CatalogManager {
         })?;
 
         for file_path in catalog_files {
-            let catalog = self.loader.load_and_parse_catalog_file(&file_path)?;
-            for controller in catalog.controllers() {
-                if controller.entity_name() == entry_name {
-                    catalog_controller = Some(controller.clone());
-                    catalog_file_path = file_path.to_string_lossy().to_string();
-                    break;
-                }
-            }
-            if catalog_controller.is_some() {
+            let catalog = self.loader.load_indexed_catalog_file(&file_path)?;
+            if let Some(controller) = catalog.controller(entry_name) {
+                catalog_controller = Some(controller.clone());
+                catalog_file_path = file_path.to_string_lossy().to_string();
                 break;
             }
         }
@@ -238,22 +253,7 @@ impl CatalogManager {
         })?;
 
         // Resolve parameters
-        let mut parameters = std::collections::HashMap::new();
-        if let Some(assignments) = &reference.parameter_assignments {
-            for assignment in assignments.iter() {
-                let resolved_name = assignment.parameter_ref.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter names",
-                    )
-                })?;
-                let resolved_value = assignment.value.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter values",
-                    )
-                })?;
-                parameters.insert(resolved_name.clone(), resolved_value.clone());
-            }
-        }
+        let parameters = reference.to_parameter_map()?;
 
         // Convert catalog controller to scenario controller
         let resolved_controller = catalog_controller
@@ -263,6 +263,14 @@ impl CatalogManager {
         // End resolution tracking
         self.resolver.end_resolution(&reference_key);
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            entry = %entry_name,
+            file = %catalog_file_path,
+            parameters = parameters.len(),
+            "resolved catalog reference"
+        );
+
         Ok(ResolvedCatalog::with_parameters(
             resolved_controller,
             catalog_file_path,
@@ -292,6 +300,9 @@ impl CatalogManager {
                 .as_literal()
                 .unwrap_or(&"unknown".to_string())
         );
+        #[cfg(feature = "tracing")]
+        let _span =
+            tracing::debug_span!("resolve_catalog_reference", reference = %reference_key).entered();
         self.resolver.begin_resolution(&reference_key)?;
 
         // Load catalog files from the location and track file paths
@@ -305,15 +316,10 @@ impl CatalogManager {
         })?;
 
         for file_path in catalog_files {
-            let catalog = self.loader.load_and_parse_catalog_file(&file_path)?;
-            for pedestrian in catalog.pedestrians() {
-                if pedestrian.entity_name() == entry_name {
-                    catalog_pedestrian = Some(pedestrian.clone());
-                    catalog_file_path = file_path.to_string_lossy().to_string();
-                    break;
-                }
-            }
-            if catalog_pedestrian.is_some() {
+            let catalog = self.loader.load_indexed_catalog_file(&file_path)?;
+            if let Some(pedestrian) = catalog.pedestrian(entry_name) {
+                catalog_pedestrian = Some(pedestrian.clone());
+                catalog_file_path = file_path.to_string_lossy().to_string();
                 break;
             }
         }
@@ -326,22 +332,7 @@ impl CatalogManager {
         })?;
 
         // Resolve parameters
-        let mut parameters = std::collections::HashMap::new();
-        if let Some(assignments) = &reference.parameter_assignments {
-            for assignment in assignments.iter() {
-                let resolved_name = assignment.parameter_ref.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter names",
-                    )
-                })?;
-                let resolved_value = assignment.value.as_literal().ok_or_else(|| {
-                    crate::error::Error::catalog_error(
-                        "Cannot resolve parameterized parameter values",
-                    )
-                })?;
-                parameters.insert(resolved_name.clone(), resolved_value.clone());
-            }
-        }
+        let parameters = reference.to_parameter_map()?;
 
         // Convert catalog pedestrian to scenario pedestrian
         let resolved_pedestrian = catalog_pedestrian
@@ -351,6 +342,14 @@ impl CatalogManager {
         // End resolution tracking
         self.resolver.end_resolution(&reference_key);
 
+        #[cfg(feature = "tracing")]
+        tracing::debug!(
+            entry = %entry_name,
+            file = %catalog_file_path,
+            parameters = parameters.len(),
+            "resolved catalog reference"
+        );
+
         Ok(ResolvedCatalog::with_parameters(
             resolved_pedestrian,
             catalog_file_path,
@@ -359,6 +358,211 @@ impl CatalogManager {
         ))
     }
 
+    /// Try to resolve every catalog reference in the scenario entities
+    ///
+    /// Covers entity references (vehicles, falling back to the pedestrian
+    /// catalog) and object controller references, resolved against the
+    /// scenario's `CatalogLocations`. Returns the references that failed; an
+    /// empty list means the scenario is ready to run.
+    pub fn validate_references(
+        &mut self,
+        scenario: &OpenScenario,
+    ) -> Result<Vec<UnresolvedReference>, crate::error::Error> {
+        let mut unresolved = Vec::new();
+        let Some(entities) = &scenario.entities else {
+            return Ok(unresolved);
+        };
+        let locations = scenario.catalog_locations.clone().unwrap_or_default();
+
+        for object in &entities.scenario_objects {
+            if let Some(reference) = &object.entity_catalog_reference {
+                let (catalog_name, entry_name) = match reference {
+                    ScenarioEntityReference::Vehicle(r) => (&r.catalog_name, &r.entry_name),
+                    ScenarioEntityReference::Pedestrian(r) => (&r.catalog_name, &r.entry_name),
+                };
+                if let Err(reason) = self.check_entity_reference(reference, &locations) {
+                    unresolved.push(UnresolvedReference {
+                        object_name: object.name.to_string(),
+                        catalog_name: catalog_name.to_string(),
+                        entry_name: entry_name.to_string(),
+                        reason,
+                    });
+                }
+            }
+
+            let controller_references = object
+                .object_controllers
+                .iter()
+                .filter_map(|controller| controller.catalog_reference.as_ref());
+            for reference in controller_references {
+                let result = match &locations.controller_catalog {
+                    Some(location) => self
+                        .resolve_controller_reference(reference, location)
+                        .map(|_| ())
+                        .map_err(|err| err.to_string()),
+                    None => Err("No ControllerCatalog location declared".to_string()),
+                };
+                if let Err(reason) = result {
+                    unresolved.push(UnresolvedReference {
+                        object_name: object.name.to_string(),
+                        catalog_name: reference.catalog_name.to_string(),
+                        entry_name: reference.entry_name.to_string(),
+                        reason,
+                    });
+                }
+            }
+
+            // A failed resolution leaves its key on the resolution stack, which
+            // would be reported as a circular dependency on the next attempt
+            self.resolver.clear();
+        }
+
+        Ok(unresolved)
+    }
+
+    /// Resolve the catalog references of each scenario object on demand
+    ///
+    /// Objects are resolved one at a time as the iterator advances, so callers
+    /// can process and drop each before the next is loaded. Entity references
+    /// are tried against the vehicle catalog first and then the pedestrian
+    /// catalog; object controller references are inlined as controllers.
+    pub fn resolved_entities<'a>(
+        &'a mut self,
+        scenario: &'a OpenScenario,
+        locations: &'a CatalogLocations,
+    ) -> impl Iterator<Item = Result<ResolvedEntity, crate::error::Error>> + 'a {
+        scenario
+            .entities
+            .iter()
+            .flat_map(|entities| entities.scenario_objects.iter())
+            .map(move |object| {
+                let resolved = self.resolve_object(object, locations);
+                // Keep a failed resolution from poisoning the next object
+                self.resolver.clear();
+                resolved
+            })
+    }
+
+    fn resolve_object(
+        &mut self,
+        object: &ScenarioObject,
+        locations: &CatalogLocations,
+    ) -> Result<ResolvedEntity, crate::error::Error> {
+        let mut resolved = object.clone();
+        let mut catalog_paths = Vec::new();
+
+        if let Some(reference) = resolved.entity_catalog_reference.take() {
+            match reference {
+                ScenarioEntityReference::Vehicle(vehicle) => {
+                    match self.resolve_vehicle_entity(&vehicle, locations) {
+                        Ok(entry) => {
+                            catalog_paths.push(entry.metadata.catalog_path);
+                            resolved.vehicle = Some(entry.entity);
+                        }
+                        Err(vehicle_error) => {
+                            // Parsed entity references are read as vehicle
+                            // references, so the entry may be a pedestrian
+                            let Some(location) = &locations.pedestrian_catalog else {
+                                return Err(vehicle_error);
+                            };
+                            self.resolver.clear();
+                            let pedestrian = as_pedestrian_reference(&vehicle);
+                            let entry = self
+                                .resolve_pedestrian_reference(&pedestrian, location)
+                                .map_err(|_| vehicle_error)?;
+                            catalog_paths.push(entry.metadata.catalog_path);
+                            resolved.pedestrian = Some(entry.entity);
+                        }
+                    }
+                }
+                ScenarioEntityReference::Pedestrian(pedestrian) => {
+                    let location = locations.pedestrian_catalog.as_ref().ok_or_else(|| {
+                        crate::error::Error::catalog_error("No PedestrianCatalog location declared")
+                    })?;
+                    let entry = self.resolve_pedestrian_reference(&pedestrian, location)?;
+                    catalog_paths.push(entry.metadata.catalog_path);
+                    resolved.pedestrian = Some(entry.entity);
+                }
+            }
+        }
+
+        for controller in &mut resolved.object_controllers {
+            if let Some(reference) = controller.catalog_reference.take() {
+                let location = locations.controller_catalog.as_ref().ok_or_else(|| {
+                    crate::error::Error::catalog_error("No ControllerCatalog location declared")
+                })?;
+                let entry = self.resolve_controller_reference(&reference, location)?;
+                catalog_paths.push(entry.metadata.catalog_path);
+                controller.controller = Some(entry.entity);
+            }
+        }
+
+        Ok(ResolvedEntity {
+            object: resolved,
+            catalog_paths,
+        })
+    }
+
+    fn resolve_vehicle_entity(
+        &mut self,
+        reference: &VehicleCatalogReference,
+        locations: &CatalogLocations,
+    ) -> Result<ResolvedCatalog<Vehicle>, crate::error::Error> {
+        let location = locations.vehicle_catalog.as_ref().ok_or_else(|| {
+            crate::error::Error::catalog_error("No VehicleCatalog location declared")
+        })?;
+        self.resolve_vehicle_reference(reference, location)
+    }
+
+    /// Resolve an entity reference, returning why it failed if it does not resolve
+    fn check_entity_reference(
+        &mut self,
+        reference: &ScenarioEntityReference,
+        locations: &CatalogLocations,
+    ) -> Result<(), String> {
+        let vehicle = match reference {
+            ScenarioEntityReference::Pedestrian(r) => {
+                return self.check_pedestrian_reference(r, locations)
+            }
+            ScenarioEntityReference::Vehicle(r) => r,
+        };
+        let vehicle_error = match &locations.vehicle_catalog {
+            Some(location) => match self.resolve_vehicle_reference(vehicle, location) {
+                Ok(_) => return Ok(()),
+                Err(err) => err.to_string(),
+            },
+            None => "No VehicleCatalog location declared".to_string(),
+        };
+
+        // Parsed entity references are read as vehicle references, so an
+        // entry missing from the vehicle catalog may be a pedestrian
+        if locations.pedestrian_catalog.is_some() {
+            self.resolver.clear();
+            let pedestrian = as_pedestrian_reference(vehicle);
+            if self
+                .check_pedestrian_reference(&pedestrian, locations)
+                .is_ok()
+            {
+                return Ok(());
+            }
+        }
+        Err(vehicle_error)
+    }
+
+    fn check_pedestrian_reference(
+        &mut self,
+        reference: &PedestrianCatalogReference,
+        locations: &CatalogLocations,
+    ) -> Result<(), String> {
+        match &locations.pedestrian_catalog {
+            Some(location) => self
+                .resolve_pedestrian_reference(reference, location)
+                .map(|_| ())
+                .map_err(|err| err.to_string()),
+            None => Err("No PedestrianCatalog location declared".to_string()),
+        }
+    }
+
     /// Discover and load all catalogs from catalog locations
     pub fn discover_and_load_catalogs(
         &mut self,
@@ -428,6 +632,16 @@ impl Default for CatalogManager {
     }
 }
 
+/// Read a parsed entity reference, which is always typed as a vehicle, as a
+/// pedestrian reference to the same catalog entry
+fn as_pedestrian_reference(vehicle: &VehicleCatalogReference) -> PedestrianCatalogReference {
+    let mut pedestrian = PedestrianCatalogReference::default();
+    pedestrian.catalog_name = vehicle.catalog_name.clone();
+    pedestrian.entry_name = vehicle.entry_name.clone();
+    pedestrian.parameter_assignments = vehicle.parameter_assignments.clone();
+    pedestrian
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
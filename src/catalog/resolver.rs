@@ -8,10 +8,19 @@
 
 use crate::catalog::parameters::ParameterSubstitutionEngine;
 use crate::error::{Error, Result};
+use crate::types::actions::movement::{FollowTrajectoryAction, Trajectory, TrajectoryRef};
+use crate::types::basic::{Boolean, Double, OSString, Value};
 use crate::types::catalogs::controllers::{CatalogController, ControllerCatalog};
+use crate::types::catalogs::entities::CatalogTrajectory as TrajectoryEntry;
 use crate::types::catalogs::environments::{CatalogEnvironment, EnvironmentCatalog};
+use crate::types::catalogs::maneuvers::ManeuverCatalog;
+use crate::types::catalogs::references::{CatalogReference, ManeuverCatalogReference};
 use crate::types::catalogs::routes::{CatalogRoute, RouteCatalog};
-use crate::types::catalogs::trajectories::{CatalogTrajectory, TrajectoryCatalog};
+use crate::types::catalogs::trajectories::{
+    CatalogTrajectory, CatalogTrajectoryShape, TrajectoryCatalog,
+};
+use crate::types::geometry::shapes::{Polyline, Shape, Vertex};
+use crate::types::scenario::story::{Maneuver, ManeuverGroup};
 use std::collections::{HashMap, HashSet};
 
 /// Represents a resolved catalog entity
@@ -49,6 +58,8 @@ pub struct CatalogManager {
     pub route_catalogs: HashMap<String, RouteCatalog>,
     /// Environment catalogs indexed by catalog name
     pub environment_catalogs: HashMap<String, EnvironmentCatalog>,
+    /// Maneuver catalogs indexed by catalog name
+    pub maneuver_catalogs: HashMap<String, ManeuverCatalog>,
     /// Parameter resolver for handling parameter substitution
     pub parameter_resolver: ParameterSubstitutionEngine,
 }
@@ -75,6 +86,7 @@ impl CatalogManager {
             trajectory_catalogs: HashMap::new(),
             route_catalogs: HashMap::new(),
             environment_catalogs: HashMap::new(),
+            maneuver_catalogs: HashMap::new(),
             parameter_resolver: ParameterSubstitutionEngine::new(),
         }
     }
@@ -99,6 +111,11 @@ impl CatalogManager {
         self.environment_catalogs.insert(name, catalog);
     }
 
+    /// Add a maneuver catalog
+    pub fn add_maneuver_catalog(&mut self, name: String, catalog: ManeuverCatalog) {
+        self.maneuver_catalogs.insert(name, catalog);
+    }
+
     /// Set global parameter values that apply to all catalog resolutions
     pub fn set_parameters(&mut self, parameters: HashMap<String, String>) -> Result<()> {
         self.parameter_resolver.set_parameters(parameters)
@@ -199,6 +216,102 @@ impl CatalogManager {
         Err(Error::catalog_not_found(catalog_name, &available))
     }
 
+    /// Resolve a trajectory catalog reference into an inline trajectory
+    ///
+    /// The entry's declared parameter defaults are overridden only by the
+    /// reference's parameter assignments; the global parameter context is
+    /// used to resolve the reference itself. Vertex times and the closed flag
+    /// are resolved to literals. Vertex positions are copied unchanged, so a
+    /// `${param}` inside a position is not resolved and no longer refers to
+    /// the entry's declaration once inlined. Only polyline trajectories can
+    /// be inlined, and every vertex needs a time.
+    pub fn inline_trajectory(
+        &self,
+        reference: &CatalogReference<TrajectoryEntry>,
+    ) -> Result<Trajectory> {
+        let context = self.parameter_context();
+        let catalog_name = reference.get_catalog_name(context)?;
+        let entry_name = reference.get_entry_name(context)?;
+        let entry = self
+            .resolve_trajectory_reference(&catalog_name, &entry_name)?
+            .into_entity();
+
+        let mut params: HashMap<String, String> = entry
+            .parameter_declarations
+            .iter()
+            .flat_map(|declarations| &declarations.parameter_declarations)
+            .filter_map(|declaration| {
+                Some((
+                    declaration.name.as_literal()?.clone(),
+                    declaration.value.as_literal()?.clone(),
+                ))
+            })
+            .collect();
+        params.extend(reference.build_parameter_map(context)?);
+
+        let location = format!("trajectory '{}' in catalog '{}'", entry_name, catalog_name);
+        let CatalogTrajectoryShape::Polyline(polyline) = &entry.shape else {
+            return Err(Error::validation_error(
+                "Shape",
+                "only polyline trajectories can be inlined into a FollowTrajectoryAction",
+            )
+            .with_context(&location));
+        };
+        let vertices = polyline
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let time = vertex.time.as_ref().ok_or_else(|| {
+                    Error::validation_error("time", "every inlined vertex needs a time")
+                })?;
+                Ok(Vertex {
+                    time: Double::literal(time.resolve(&params)?),
+                    position: vertex.position.clone(),
+                })
+            })
+            .collect::<Result<Vec<_>>>()
+            .map_err(|e| e.with_context(&location))?;
+        let closed = match &entry.closed {
+            Some(closed) => closed
+                .resolve(&params)
+                .map_err(|e| e.with_context(&location))?,
+            None => false,
+        };
+
+        Ok(Trajectory {
+            name: OSString::literal(entry.name),
+            closed: Boolean::literal(closed),
+            shape: Shape {
+                polyline: Some(Polyline { vertices }),
+            },
+        })
+    }
+
+    /// Replace a catalog-referenced trajectory of an action with its inline definition
+    ///
+    /// Both the `TrajectoryRef` catalog reference and the deprecated direct
+    /// `CatalogReference` are resolved with [`inline_trajectory`](Self::inline_trajectory).
+    /// Returns whether a reference was replaced; actions with inline
+    /// trajectories are left unchanged.
+    pub fn inline_follow_trajectory(&self, action: &mut FollowTrajectoryAction) -> Result<bool> {
+        let mut replaced = false;
+        if let Some(trajectory_ref) = &mut action.trajectory_ref {
+            if let Some(reference) = &trajectory_ref.catalog_reference {
+                *trajectory_ref = TrajectoryRef {
+                    trajectory: Some(self.inline_trajectory(reference)?),
+                    catalog_reference: None,
+                };
+                replaced = true;
+            }
+        }
+        if let Some(reference) = &action.catalog_reference {
+            action.trajectory = Some(self.inline_trajectory(reference)?);
+            action.catalog_reference = None;
+            replaced = true;
+        }
+        Ok(replaced)
+    }
+
     /// Resolve route reference from route catalogs
     pub fn resolve_route_reference(
         &self,
@@ -272,6 +385,93 @@ impl CatalogManager {
         let available: Vec<String> = self.environment_catalogs.keys().cloned().collect();
         Err(Error::catalog_not_found(catalog_name, &available))
     }
+
+    /// Resolve maneuver reference from maneuver catalogs
+    pub fn resolve_maneuver_reference(
+        &self,
+        catalog_name: &str,
+        entry_name: &str,
+    ) -> Result<ResolvedCatalog<Maneuver>> {
+        self.resolve_maneuver_reference_with_params(catalog_name, entry_name, &HashMap::new())
+    }
+
+    /// Resolve maneuver reference with parameter overrides
+    pub fn resolve_maneuver_reference_with_params(
+        &self,
+        catalog_name: &str,
+        entry_name: &str,
+        params: &HashMap<String, String>,
+    ) -> Result<ResolvedCatalog<Maneuver>> {
+        if let Some(catalog) = self.maneuver_catalogs.get(catalog_name) {
+            if let Some(maneuver) = catalog.find_maneuver(entry_name) {
+                return Ok(ResolvedCatalog::with_parameters(
+                    maneuver.clone(),
+                    format!("maneuver_catalog:{}", catalog_name),
+                    entry_name.to_string(),
+                    params.clone(),
+                ));
+            }
+            let available = catalog.maneuver_names();
+            return Err(Error::catalog_entry_not_found(catalog_name, entry_name)
+                .with_context(&format!("Available maneuvers: {}", available.join(", "))));
+        }
+        let available: Vec<String> = self.maneuver_catalogs.keys().cloned().collect();
+        Err(Error::catalog_not_found(catalog_name, &available))
+    }
+
+    /// Resolve a maneuver catalog reference into an inline maneuver
+    ///
+    /// The reference's parameter assignments replace the default values of
+    /// the entry's parameter declarations. Events are copied unchanged, so
+    /// `$param` references inside them keep resolving against the maneuver's
+    /// own parameter scope. Assigning an undeclared parameter is an error.
+    pub fn inline_maneuver(&self, reference: &ManeuverCatalogReference) -> Result<Maneuver> {
+        let context = self.parameter_context();
+        let catalog_name = reference.get_catalog_name(context)?;
+        let entry_name = reference.get_entry_name(context)?;
+        let params = reference.build_parameter_map(context)?;
+        let mut maneuver = self
+            .resolve_maneuver_reference_with_params(&catalog_name, &entry_name, &params)?
+            .into_entity();
+
+        let location = format!("maneuver '{}' in catalog '{}'", entry_name, catalog_name);
+        for (name, value) in params {
+            let declaration = maneuver
+                .parameter_declarations
+                .iter_mut()
+                .flat_map(|declarations| &mut declarations.parameter_declarations)
+                .find(|declaration| declaration.name.as_literal() == Some(&name))
+                .ok_or_else(|| {
+                    Error::parameter_error(&name, "assigned parameter is not declared")
+                        .with_context(&location)
+                })?;
+            declaration.value = Value::Literal(value);
+        }
+
+        Ok(maneuver)
+    }
+
+    /// Replace the catalog references of a maneuver group with inline maneuvers
+    ///
+    /// Each maneuver is resolved with [`inline_maneuver`](Self::inline_maneuver)
+    /// and, in reference order, placed before any maneuvers the group already
+    /// declares, matching the schema's element order. The group is left
+    /// untouched if any reference fails. Returns whether a reference was
+    /// replaced.
+    pub fn inline_maneuver_group(&self, group: &mut ManeuverGroup) -> Result<bool> {
+        if group.catalog_references.is_empty() {
+            return Ok(false);
+        }
+        let mut maneuvers = group
+            .catalog_references
+            .iter()
+            .map(|reference| self.inline_maneuver(reference))
+            .collect::<Result<Vec<_>>>()?;
+        maneuvers.append(&mut group.maneuvers);
+        group.maneuvers = maneuvers;
+        group.catalog_references.clear();
+        Ok(true)
+    }
 }
 
 impl CatalogResolver {
@@ -408,6 +608,206 @@ mod tests {
         resolver.end_resolution("vehicle1");
     }
 
+    #[test]
+    fn test_inline_follow_trajectory_catalog_reference() {
+        use crate::types::basic::{ParameterDeclaration, ParameterDeclarations};
+        use crate::types::catalogs::references::ParameterAssignment;
+        use crate::types::catalogs::trajectories::{CatalogPolyline, CatalogVertex};
+        use crate::types::enums::ParameterType;
+        use crate::types::positions::Position;
+
+        let shape = CatalogTrajectoryShape::Polyline(CatalogPolyline {
+            vertices: vec![
+                CatalogVertex {
+                    time: Some(Double::parameter("StartTime".to_string())),
+                    position: Position::default(),
+                },
+                CatalogVertex {
+                    time: Some(Double::parameter("EndTime".to_string())),
+                    position: Position::default(),
+                },
+            ],
+        });
+        let declarations = ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration::new(
+                "StartTime".to_string(),
+                ParameterType::Double,
+                "0.5".to_string(),
+            )],
+        };
+        let mut catalog = TrajectoryCatalog::default();
+        catalog.add_trajectory(CatalogTrajectory::with_parameters(
+            "LaneChange".to_string(),
+            shape,
+            declarations,
+        ));
+        let mut manager = CatalogManager::new();
+        manager.add_trajectory_catalog("TrajectoryCatalog".to_string(), catalog);
+        // A global parameter of the same name does not override the entry default
+        manager
+            .set_parameter("StartTime".to_string(), "9.0".to_string())
+            .unwrap();
+
+        let mut action = FollowTrajectoryAction {
+            trajectory_ref: Some(TrajectoryRef {
+                trajectory: None,
+                catalog_reference: Some(CatalogReference::with_parameters(
+                    "TrajectoryCatalog".to_string(),
+                    "LaneChange".to_string(),
+                    vec![ParameterAssignment::new(
+                        "EndTime".to_string(),
+                        "4.5".to_string(),
+                    )],
+                )),
+            }),
+            ..FollowTrajectoryAction::default()
+        };
+
+        assert!(manager.inline_follow_trajectory(&mut action).unwrap());
+        let trajectory_ref = action.trajectory_ref.as_ref().unwrap();
+        assert!(trajectory_ref.catalog_reference.is_none());
+        let trajectory = trajectory_ref.trajectory.as_ref().unwrap();
+        assert_eq!(trajectory.name.as_literal().unwrap(), "LaneChange");
+        assert_eq!(trajectory.closed.as_literal(), Some(&false));
+        let times: Vec<f64> = trajectory
+            .shape
+            .polyline
+            .as_ref()
+            .unwrap()
+            .vertices
+            .iter()
+            .map(|vertex| *vertex.time.as_literal().unwrap())
+            .collect();
+        assert_eq!(times, [0.5, 4.5]);
+
+        assert!(!manager.inline_follow_trajectory(&mut action).unwrap());
+
+        let mut missing = FollowTrajectoryAction {
+            catalog_reference: Some(CatalogReference::new(
+                "TrajectoryCatalog".to_string(),
+                "Unknown".to_string(),
+            )),
+            ..FollowTrajectoryAction::default()
+        };
+        assert!(manager.inline_follow_trajectory(&mut missing).is_err());
+    }
+
+    #[test]
+    fn test_inline_maneuver_group_catalog_reference() {
+        use crate::types::basic::{ParameterDeclaration, ParameterDeclarations};
+        use crate::types::catalogs::references::ParameterAssignment;
+        use crate::types::enums::ParameterType;
+        use crate::types::scenario::story::Event;
+
+        let mut catalog = ManeuverCatalog::default();
+        catalog.add_maneuver(Maneuver {
+            name: OSString::literal("CutIn".to_string()),
+            parameter_declarations: Some(ParameterDeclarations {
+                parameter_declarations: vec![ParameterDeclaration {
+                    name: OSString::literal("Gap".to_string()),
+                    parameter_type: ParameterType::Double,
+                    value: OSString::literal("10.0".to_string()),
+                    constraint_groups: Vec::new(),
+                }],
+            }),
+            events: vec![
+                Event {
+                    name: OSString::literal("ChangeLane".to_string()),
+                    ..Event::default()
+                },
+                Event {
+                    name: OSString::literal("Slow".to_string()),
+                    ..Event::default()
+                },
+            ],
+        });
+        let mut manager = CatalogManager::new();
+        manager.add_maneuver_catalog("ManeuverCatalog".to_string(), catalog);
+
+        let xml = r#"<ManeuverGroup name="Group" maximumExecutionCount="1">
+            <Actors selectTriggeringEntities="false"><EntityRef entityRef="Target"/></Actors>
+            <CatalogReference catalogName="ManeuverCatalog" entryName="CutIn">
+                <ParameterAssignments>
+                    <ParameterAssignment parameterRef="Gap" value="5.0"/>
+                </ParameterAssignments>
+            </CatalogReference>
+        </ManeuverGroup>"#;
+        let mut group: ManeuverGroup = quick_xml::de::from_str(xml).unwrap();
+        assert!(group.maneuvers.is_empty());
+
+        assert!(manager.inline_maneuver_group(&mut group).unwrap());
+        assert!(group.catalog_references.is_empty());
+        assert_eq!(group.maneuvers.len(), 1);
+        let maneuver = &group.maneuvers[0];
+        assert_eq!(maneuver.name.as_literal().unwrap(), "CutIn");
+        let events: Vec<&str> = maneuver
+            .events
+            .iter()
+            .map(|event| event.name.as_literal().unwrap().as_str())
+            .collect();
+        assert_eq!(events, ["ChangeLane", "Slow"]);
+        let declarations = maneuver.parameter_declarations.as_ref().unwrap();
+        let gap = &declarations.parameter_declarations[0];
+        assert_eq!(gap.value.as_literal().unwrap(), "5.0");
+
+        assert!(!manager.inline_maneuver_group(&mut group).unwrap());
+
+        let mut undeclared = ManeuverGroup {
+            catalog_references: vec![CatalogReference::with_parameters(
+                "ManeuverCatalog".to_string(),
+                "CutIn".to_string(),
+                vec![ParameterAssignment::new(
+                    "Speed".to_string(),
+                    "20.0".to_string(),
+                )],
+            )],
+            ..ManeuverGroup::default()
+        };
+        assert!(manager.inline_maneuver_group(&mut undeclared).is_err());
+
+        let mut missing = ManeuverGroup {
+            catalog_references: vec![CatalogReference::new(
+                "ManeuverCatalog".to_string(),
+                "Unknown".to_string(),
+            )],
+            ..ManeuverGroup::default()
+        };
+        assert!(manager.inline_maneuver_group(&mut missing).is_err());
+        assert_eq!(missing.catalog_references.len(), 1);
+
+        let xml = r#"<ManeuverGroup name="Group" maximumExecutionCount="1">
+            <Actors selectTriggeringEntities="false"><EntityRef entityRef="Target"/></Actors>
+            <CatalogReference catalogName="ManeuverCatalog" entryName="CutIn">
+                <ParameterAssignments>
+                    <ParameterAssignment parameterRef="Gap" value="5.0"/>
+                </ParameterAssignments>
+            </CatalogReference>
+            <CatalogReference catalogName="ManeuverCatalog" entryName="CutIn"/>
+        </ManeuverGroup>"#;
+        let mut group: ManeuverGroup = quick_xml::de::from_str(xml).unwrap();
+        assert_eq!(group.catalog_references.len(), 2);
+        group.maneuvers.push(Maneuver {
+            name: OSString::literal("Direct".to_string()),
+            ..Maneuver::default()
+        });
+
+        assert!(manager.inline_maneuver_group(&mut group).unwrap());
+        assert!(group.catalog_references.is_empty());
+        let gaps: Vec<&str> = group.maneuvers[..2]
+            .iter()
+            .map(|maneuver| {
+                let declarations = maneuver.parameter_declarations.as_ref().unwrap();
+                declarations.parameter_declarations[0]
+                    .value
+                    .as_literal()
+                    .unwrap()
+                    .as_str()
+            })
+            .collect();
+        assert_eq!(gaps, ["5.0", "10.0"]);
+        assert_eq!(group.maneuvers[2].name.as_literal().unwrap(), "Direct");
+    }
+
     #[test]
     fn test_resolved_catalog() {
         let entity = "test_vehicle".to_string();
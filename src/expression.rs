@@ -10,6 +10,7 @@
 //! Supported types: numeric literals, parameters, function calls, constants
 //! Supported functions: sin, cos, tan, sqrt, abs, floor, ceil, min, max
 //! Supported constants: PI, E
+//! Supported trailing units (with `evaluate_expression_with_units`): km/h, m/s, deg, rad
 //!
 //! XSD Pattern: `[$][{][ A-Za-z0-9_\+\-\*/%$\(\)\.,]*[\}]`
 
@@ -762,6 +763,115 @@ where
     })
 }
 
+/// Physical units recognised as a trailing token by `evaluate_expression_with_units`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Unit {
+    /// Meters per second (`m/s`)
+    MetersPerSecond,
+    /// Kilometers per hour (`km/h`)
+    KilometersPerHour,
+    /// Radians (`rad`)
+    Radians,
+    /// Degrees (`deg`)
+    Degrees,
+}
+
+impl Unit {
+    const ALL: [Unit; 4] = [
+        Unit::MetersPerSecond,
+        Unit::KilometersPerHour,
+        Unit::Radians,
+        Unit::Degrees,
+    ];
+
+    /// Symbol of the unit as written after an expression
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Unit::MetersPerSecond => "m/s",
+            Unit::KilometersPerHour => "km/h",
+            Unit::Radians => "rad",
+            Unit::Degrees => "deg",
+        }
+    }
+
+    /// Factor converting a value in this unit to the SI unit of its quantity
+    fn si_factor(&self) -> f64 {
+        match self {
+            Unit::MetersPerSecond | Unit::Radians => 1.0,
+            Unit::KilometersPerHour => 1.0 / 3.6,
+            Unit::Degrees => std::f64::consts::PI / 180.0,
+        }
+    }
+
+    fn is_speed(&self) -> bool {
+        matches!(self, Unit::MetersPerSecond | Unit::KilometersPerHour)
+    }
+
+    /// Convert `value` from this unit to `target`
+    ///
+    /// Fails if the units measure different quantities, e.g. speed and angle.
+    pub fn convert(&self, value: f64, target: Unit) -> Result<f64> {
+        if self.is_speed() != target.is_speed() {
+            return Err(Error::invalid_value(
+                "unit",
+                self.symbol(),
+                &format!("cannot convert to {}", target.symbol()),
+            ));
+        }
+        Ok(value * self.si_factor() / target.si_factor())
+    }
+}
+
+impl FromStr for Unit {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Unit::ALL
+            .into_iter()
+            .find(|unit| unit.symbol() == s)
+            .ok_or_else(|| Error::invalid_value("unit", s, "expected km/h, m/s, deg or rad"))
+    }
+}
+
+/// Split an optional trailing unit token from an expression
+///
+/// The unit must be separated from the expression by whitespace or follow a
+/// number or closing parenthesis directly, so `${speed} km/h` and `(1+2)deg`
+/// carry units while a parameter named `$grad` does not.
+fn split_unit(expr: &str) -> (&str, Option<Unit>) {
+    let trimmed = expr.trim_end();
+    for unit in Unit::ALL {
+        if let Some(rest) = trimmed.strip_suffix(unit.symbol()) {
+            let separated = rest
+                .chars()
+                .next_back()
+                .is_some_and(|c| c.is_whitespace() || c.is_ascii_digit() || c == ')' || c == '}');
+            if separated {
+                return (rest, Some(unit));
+            }
+        }
+    }
+    (trimmed, None)
+}
+
+/// Evaluate an expression with an optional trailing unit and convert to `unit`
+///
+/// `10 * 36 km/h` evaluated in [`Unit::MetersPerSecond`] yields 100. Without a
+/// unit token the result is assumed to already be in `unit`. Converting
+/// between speeds and angles is an error.
+pub fn evaluate_expression_with_units(
+    expr: &str,
+    params: &HashMap<String, String>,
+    unit: Unit,
+) -> Result<f64> {
+    let (expr, source) = split_unit(expr);
+    let value = evaluate_expression::<f64>(expr, params)?;
+    match source {
+        Some(source) => source.convert(value, unit),
+        None => Ok(value),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1186,4 +1296,30 @@ mod tests {
         .unwrap();
         assert_eq!(result, 2.0); // max(1.5, min(5.0, 20.0 / 10.0)) = max(1.5, 2.0) = 2.0
     }
+
+    #[test]
+    fn test_evaluate_expression_with_units() {
+        let mut params = HashMap::new();
+        params.insert("speed".to_string(), "72".to_string());
+
+        let result = evaluate_expression_with_units("10 * 36 km/h", &params, Unit::MetersPerSecond);
+        assert!((result.unwrap() - 100.0).abs() < 1e-9);
+        let result =
+            evaluate_expression_with_units("${speed} km/h", &params, Unit::MetersPerSecond);
+        assert!((result.unwrap() - 20.0).abs() < 1e-9);
+        let result = evaluate_expression_with_units("5 m/s", &params, Unit::KilometersPerHour);
+        assert!((result.unwrap() - 18.0).abs() < 1e-9);
+
+        // Without a unit the value is taken as is
+        let result =
+            evaluate_expression_with_units("10 * 1000 / 3600", &params, Unit::MetersPerSecond);
+        assert!((result.unwrap() - 10.0 / 3.6).abs() < 1e-9);
+
+        let result = evaluate_expression_with_units("(45 + 45)deg", &params, Unit::Radians);
+        assert!((result.unwrap() - std::f64::consts::FRAC_PI_2).abs() < 1e-9);
+
+        assert!(evaluate_expression_with_units("5 m/s", &params, Unit::Degrees).is_err());
+        assert_eq!("km/h".parse::<Unit>().unwrap(), Unit::KilometersPerHour);
+        assert!("mph".parse::<Unit>().is_err());
+    }
 }
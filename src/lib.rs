@@ -45,6 +45,7 @@ pub mod error;
 pub mod expression;
 pub mod parser;
 pub mod types;
+pub mod utils;
 
 #[cfg(feature = "builder")]
 pub mod builder;
@@ -57,7 +58,10 @@ pub use types::scenario::storyboard::{
 // Re-export parser functions
 pub use parser::xml::{
     parse_catalog_from_file, parse_catalog_from_str, parse_from_file, parse_from_str,
-    serialize_catalog_to_file, serialize_catalog_to_string, serialize_to_file, serialize_to_string,
+    parse_from_str_lenient, parse_from_str_with_warnings, serialize_catalog_to_file,
+    serialize_catalog_to_string, serialize_parameter_variation_to_string, serialize_to_file,
+    serialize_to_string, write_controller_catalog, write_pedestrian_catalog, write_vehicle_catalog,
+    FromXml, ToXml,
 };
 
 // Re-export choice group infrastructure
@@ -71,8 +75,12 @@ pub use expression::evaluate_expression;
 // Re-export catalog system
 pub use catalog::{
     CatalogLoader, CatalogManager, CatalogResolver, ParameterSubstitutionEngine, ResolvedCatalog,
+    ResolvedEntity, UnresolvedReference,
 };
 
+// Re-export document utilities
+pub use utils::{scenario_diff, DiffEntry};
+
 // Feature-gated re-exports
 #[cfg(feature = "builder")]
 pub use builder::ScenarioBuilder;
@@ -71,7 +71,19 @@
 //!
 //! - Parsing is optimized for speed with zero-copy deserialization
 //! - Enable validation caching for repeated validation operations
+//! - With the `tracing` feature, each parsed file emits a `parse_file` span
+//!   with its byte length and elapsed time
+//!
+//! ## Packages
+//!
+//! With the `zip` feature, [`package::write_zip_package`] bundles a scenario
+//! with its catalogs and extra files into one ZIP archive, and
+//! [`package::read_zip_package`] reads it back.
 
 pub mod choice_groups;
+#[cfg(feature = "zip")]
+pub mod package;
+#[cfg(feature = "tracing")]
+mod trace;
 pub mod validation;
 pub mod xml;
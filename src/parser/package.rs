@@ -0,0 +1,183 @@
+//! ZIP packaging of scenarios with their catalogs and extra files
+//!
+//! A package is a single ZIP archive holding the main scenario under
+//! [`MAIN_SCENARIO_ENTRY`], catalog files under the names they were given,
+//! and arbitrary extra files such as road networks. Entries ending in `.xosc`
+//! other than the main scenario are read back as catalogs; everything else is
+//! returned as raw bytes.
+
+use crate::error::{Error, Result};
+use crate::parser::xml::{
+    parse_catalog_from_str, parse_from_str, serialize_catalog_to_string, serialize_to_string,
+};
+use crate::types::catalogs::files::CatalogFile;
+use crate::types::scenario::storyboard::OpenScenario;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::Path;
+use zip::write::SimpleFileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+/// Archive entry name of the main scenario in a package
+pub const MAIN_SCENARIO_ENTRY: &str = "scenario.xosc";
+
+/// Contents of a scenario package read by [`read_zip_package`]
+#[derive(Debug, Clone)]
+pub struct ScenarioPackage {
+    /// The main scenario
+    pub scenario: OpenScenario,
+    /// Catalog files keyed by their archive entry name
+    pub catalogs: Vec<(String, CatalogFile)>,
+    /// All other entries keyed by their archive entry name
+    pub extra_files: Vec<(String, Vec<u8>)>,
+}
+
+/// Write a scenario, its catalogs and extra files into a single ZIP package
+///
+/// Catalogs and extra files are stored under the given entry names, which
+/// may contain `/` to place them in subdirectories of the archive.
+pub fn write_zip_package<P: AsRef<Path>>(
+    path: P,
+    scenario: &OpenScenario,
+    catalogs: &[(String, CatalogFile)],
+    extra_files: &[(String, Vec<u8>)],
+) -> Result<()> {
+    let path = path.as_ref();
+    let file = fs::File::create(path)
+        .map_err(Error::from)
+        .map_err(|e| e.with_context(&format!("Failed to create package: {}", path.display())))?;
+
+    let mut writer = ZipWriter::new(file);
+    let options = SimpleFileOptions::default();
+
+    let mut write_entry = |name: &str, content: &[u8]| -> Result<()> {
+        writer
+            .start_file(name, options)
+            .map_err(|e| Error::file_write_error(&path.display().to_string(), &e.to_string()))?;
+        writer.write_all(content)?;
+        Ok(())
+    };
+
+    write_entry(
+        MAIN_SCENARIO_ENTRY,
+        serialize_to_string(scenario)?.as_bytes(),
+    )?;
+    for (name, catalog) in catalogs {
+        if name == MAIN_SCENARIO_ENTRY {
+            return Err(Error::invalid_value(
+                "catalogs",
+                name,
+                "the entry name is reserved for the main scenario",
+            ));
+        }
+        write_entry(name, serialize_catalog_to_string(catalog)?.as_bytes())?;
+    }
+    for (name, content) in extra_files {
+        if name == MAIN_SCENARIO_ENTRY {
+            return Err(Error::invalid_value(
+                "extra_files",
+                name,
+                "the entry name is reserved for the main scenario",
+            ));
+        }
+        write_entry(name, content)?;
+    }
+
+    writer
+        .finish()
+        .map_err(|e| Error::file_write_error(&path.display().to_string(), &e.to_string()))?;
+    Ok(())
+}
+
+/// Read a ZIP package written by [`write_zip_package`]
+pub fn read_zip_package<P: AsRef<Path>>(path: P) -> Result<ScenarioPackage> {
+    let path = path.as_ref();
+    let file = fs::File::open(path)
+        .map_err(Error::from)
+        .map_err(|e| e.with_context(&format!("Failed to open package: {}", path.display())))?;
+    let mut archive = ZipArchive::new(file).map_err(|e| zip_error(path, &e))?;
+
+    let mut scenario = None;
+    let mut catalogs = Vec::new();
+    let mut extra_files = Vec::new();
+
+    for index in 0..archive.len() {
+        let mut entry = archive.by_index(index).map_err(|e| zip_error(path, &e))?;
+        if entry.is_dir() {
+            continue;
+        }
+        let name = entry.name().to_string();
+        let mut content = Vec::new();
+        entry.read_to_end(&mut content)?;
+
+        if name == MAIN_SCENARIO_ENTRY {
+            scenario = Some(parse_from_str(&entry_text(path, &name, content)?)?);
+        } else if name.ends_with(".xosc") {
+            let catalog = parse_catalog_from_str(&entry_text(path, &name, content)?)?;
+            catalogs.push((name, catalog));
+        } else {
+            extra_files.push((name, content));
+        }
+    }
+
+    let scenario = scenario.ok_or_else(|| {
+        Error::file_read_error(
+            &path.display().to_string(),
+            &format!("package has no '{}' entry", MAIN_SCENARIO_ENTRY),
+        )
+    })?;
+
+    Ok(ScenarioPackage {
+        scenario,
+        catalogs,
+        extra_files,
+    })
+}
+
+fn entry_text(path: &Path, name: &str, content: Vec<u8>) -> Result<String> {
+    String::from_utf8(content).map_err(|e| {
+        Error::file_read_error(
+            &format!("{}:{}", path.display(), name),
+            &format!("invalid UTF-8: {}", e),
+        )
+    })
+}
+
+fn zip_error(path: &Path, error: &zip::result::ZipError) -> Error {
+    Error::file_read_error(&path.display().to_string(), &error.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_zip_package_round_trip() {
+        let scenario =
+            crate::parse_file("tests/data/simple_scenario.xosc").expect("fixture should parse");
+        let catalog = CatalogFile::from_controllers("DriverCatalog".to_string(), Vec::new());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("package.xosc");
+        write_zip_package(
+            &path,
+            &scenario,
+            &[("catalogs/controllers.xosc".to_string(), catalog.clone())],
+            &[("maps/road.xodr".to_string(), b"<OpenDRIVE/>".to_vec())],
+        )
+        .unwrap();
+
+        let package = read_zip_package(&path).unwrap();
+        assert_eq!(
+            serialize_to_string(&package.scenario).unwrap(),
+            serialize_to_string(&scenario).unwrap()
+        );
+        assert_eq!(package.catalogs.len(), 1);
+        assert_eq!(package.catalogs[0].0, "catalogs/controllers.xosc");
+        assert_eq!(package.catalogs[0].1.catalog, catalog.catalog);
+        assert_eq!(
+            package.extra_files,
+            vec![("maps/road.xodr".to_string(), b"<OpenDRIVE/>".to_vec())]
+        );
+    }
+}
@@ -0,0 +1,111 @@
+//! Tracing spans for file parsing (`tracing` feature)
+//!
+//! Every scenario or catalog file read from disk gets a `parse_file` span
+//! carrying the file kind, path, byte length and elapsed time. The span is
+//! entered for the whole read, validate and deserialize sequence, so events
+//! emitted while parsing are attributed to the file.
+
+use std::path::Path;
+use std::time::Instant;
+
+/// Entered `parse_file` span that records its elapsed time when dropped
+pub(crate) struct FileSpan {
+    span: tracing::span::EnteredSpan,
+    start: Instant,
+}
+
+impl FileSpan {
+    /// Create and enter a span for parsing the file at `path`
+    pub(crate) fn enter(kind: &'static str, path: &Path) -> Self {
+        let span = tracing::info_span!(
+            "parse_file",
+            kind,
+            path = %path.display(),
+            bytes = tracing::field::Empty,
+            elapsed_us = tracing::field::Empty,
+        );
+        Self {
+            span: span.entered(),
+            start: Instant::now(),
+        }
+    }
+
+    /// Record the size of the file in bytes
+    pub(crate) fn record_bytes(&self, bytes: u64) {
+        self.span.record("bytes", bytes);
+    }
+}
+
+impl Drop for FileSpan {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed().as_micros() as u64;
+        self.span.record("elapsed_us", elapsed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Collects span names and the names of fields recorded on them
+    #[derive(Default)]
+    struct Recorder {
+        next_id: AtomicU64,
+        spans: Arc<Mutex<Vec<String>>>,
+        fields: Arc<Mutex<Vec<String>>>,
+    }
+
+    struct FieldNames<'a>(&'a Mutex<Vec<String>>);
+
+    impl Visit for FieldNames<'_> {
+        fn record_debug(&mut self, field: &Field, _value: &dyn std::fmt::Debug) {
+            self.0.lock().unwrap().push(field.name().to_string());
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            self.spans
+                .lock()
+                .unwrap()
+                .push(span.metadata().name().to_string());
+            Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+        }
+
+        fn record(&self, _span: &Id, values: &Record<'_>) {
+            values.record(&mut FieldNames(&self.fields));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, _span: &Id) {}
+
+        fn exit(&self, _span: &Id) {}
+    }
+
+    #[test]
+    fn test_parse_from_file_emits_span() {
+        let recorder = Recorder::default();
+        let spans = recorder.spans.clone();
+        let fields = recorder.fields.clone();
+
+        tracing::subscriber::with_default(recorder, || {
+            crate::parser::xml::parse_from_file("tests/data/simple_scenario.xosc").unwrap();
+        });
+
+        assert_eq!(*spans.lock().unwrap(), vec!["parse_file".to_string()]);
+        let fields = fields.lock().unwrap();
+        assert!(fields.contains(&"bytes".to_string()));
+        assert!(fields.contains(&"elapsed_us".to_string()));
+    }
+}
@@ -861,7 +861,7 @@ mod tests {
                 vehicle: Some(vehicle),
                 pedestrian: None,
                 entity_catalog_reference: None,
-                object_controller: Default::default(),
+                object_controllers: Default::default(),
             }],
         };
 
@@ -876,6 +876,8 @@ mod tests {
         };
 
         let scenario = OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header: valid_header,
             parameter_declarations: None,
             variable_declarations: None,
@@ -925,7 +927,7 @@ mod tests {
                 vehicle: Some(vehicle),
                 pedestrian: None,
                 entity_catalog_reference: None,
-                object_controller: Default::default(),
+                object_controllers: Default::default(),
             }],
         };
 
@@ -940,6 +942,8 @@ mod tests {
         };
 
         let scenario = OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header: invalid_header,
             parameter_declarations: None,
             variable_declarations: None,
@@ -990,14 +994,14 @@ mod tests {
                     vehicle: Some(vehicle1),
                     pedestrian: None,
                     entity_catalog_reference: None,
-                    object_controller: Default::default(),
+                    object_controllers: Default::default(),
                 },
                 ScenarioObject {
                     name: Value::literal("Car1".to_string()),
                     vehicle: Some(vehicle2),
                     pedestrian: None,
                     entity_catalog_reference: None,
-                    object_controller: Default::default(),
+                    object_controllers: Default::default(),
                 },
             ],
         };
@@ -1013,6 +1017,8 @@ mod tests {
         };
 
         let scenario = OpenScenario {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header: FileHeader {
                 author: Value::literal("Test Author".to_string()),
                 date: Value::literal("2024-01-01T00:00:00".to_string()),
@@ -1079,7 +1085,7 @@ mod tests {
             vehicle: Some(vehicle),
             pedestrian: None,
             entity_catalog_reference: None,
-            object_controller: Default::default(),
+            object_controllers: Default::default(),
         };
 
         let entities = crate::types::entities::Entities {
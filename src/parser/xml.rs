@@ -103,9 +103,15 @@
 //! - Validation adds ~10-15% overhead but catches malformed XML early
 
 use crate::error::{Error, Result};
+use crate::types::catalogs::entities::{CatalogController, CatalogPedestrian, CatalogVehicle};
 use crate::types::catalogs::files::CatalogFile;
-use crate::types::scenario::storyboard::OpenScenario;
+use crate::types::controllers::Controller;
+use crate::types::distributions::ParameterValueDistribution;
+use crate::types::routing::Route;
+use crate::types::scenario::storyboard::{FileHeader, OpenScenario};
 use markup_fmt::{config::FormatOptions, format_text, Language};
+use serde::Serialize;
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
@@ -123,8 +129,193 @@ fn remove_bom(content: &str) -> &str {
     }
 }
 
+/// Read an XML file and decode it to a string
+///
+/// A leading UTF-8 BOM is stripped. Otherwise the `encoding` attribute of the
+/// XML declaration selects the decoding: UTF-8 (or its ASCII subset) and
+/// ISO-8859-1 (Latin-1) are supported. Files without a declared encoding are
+/// read as UTF-8.
+pub(crate) fn read_xml_file(path: &Path) -> Result<String> {
+    let bytes = fs::read(path)
+        .map_err(|e| Error::file_read_error(&path.to_string_lossy(), &e.to_string()))?;
+    decode_xml(bytes).map_err(|reason| Error::file_read_error(&path.to_string_lossy(), &reason))
+}
+
+fn decode_xml(bytes: Vec<u8>) -> std::result::Result<String, String> {
+    const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+    if let Some(content) = bytes.strip_prefix(UTF8_BOM) {
+        return String::from_utf8(content.to_vec()).map_err(|e| format!("invalid UTF-8: {}", e));
+    }
+
+    let encoding = declared_encoding(&bytes).map(|encoding| encoding.to_ascii_lowercase());
+    match encoding.as_deref() {
+        None | Some("utf-8" | "utf8" | "us-ascii" | "ascii") => {
+            String::from_utf8(bytes).map_err(|e| format!("invalid UTF-8: {}", e))
+        }
+        // Latin-1 maps each byte to the code point of the same value
+        Some("iso-8859-1" | "iso_8859-1" | "latin1" | "latin-1") => {
+            Ok(bytes.into_iter().map(char::from).collect())
+        }
+        Some(other) => Err(format!("unsupported encoding '{}'", other)),
+    }
+}
+
+/// Value of the `encoding` attribute in the XML declaration, if any
+fn declared_encoding(bytes: &[u8]) -> Option<&str> {
+    let declaration = bytes.strip_prefix(b"<?xml")?;
+    let end = declaration.windows(2).position(|w| w == b"?>")?;
+    let declaration = std::str::from_utf8(&declaration[..end]).ok()?;
+    let value = declaration.split_once("encoding")?.1.trim_start();
+    let value = value.strip_prefix('=')?.trim_start();
+    let quote = value.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+    let value = &value[1..];
+    Some(&value[..value.find(quote)?])
+}
+
+/// Serialize a type to an XML string
+///
+/// Documents ([`OpenScenario`], [`CatalogFile`]) are written with an XML
+/// declaration; element types such as [`Route`] and [`Controller`] are written
+/// as bare fragments suitable for embedding or catalog entries.
+pub trait ToXml {
+    /// Serialize `self` to XML
+    fn to_xml(&self) -> Result<String>;
+}
+
+/// Parse a type from an XML string
+pub trait FromXml: Sized {
+    /// Parse an instance from XML
+    fn from_xml(xml: &str) -> Result<Self>;
+}
+
+/// XML declaration prepended to serialized documents
+const XML_DECLARATION: &str = r#"<?xml version="1.0" encoding="UTF-8"?>"#;
+
+/// Rewrite elements without any content as self-closing tags
+///
+/// `<Properties></Properties>` becomes `<Properties/>`; attributes are kept.
+/// Elements containing text, including whitespace, are left unchanged.
+/// Documents and catalogs are passed through this when serialized.
+pub fn self_close_empty_elements(xml: &str) -> Result<String> {
+    use quick_xml::events::{BytesStart, Event};
+    use quick_xml::{Reader, Writer};
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    let mut open: Option<BytesStart<'static>> = None;
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+        match (event, open.take()) {
+            (Event::End(_), Some(start)) => writer.write_event(Event::Empty(start))?,
+            (event, pending) => {
+                if let Some(start) = pending {
+                    writer.write_event(Event::Start(start))?;
+                }
+                match event {
+                    Event::Eof => break,
+                    Event::Start(tag) => open = Some(tag.into_owned()),
+                    event => writer.write_event(event)?,
+                }
+            }
+        }
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| Error::invalid_xml(&e.to_string()))
+}
+
+impl ToXml for OpenScenario {
+    fn to_xml(&self) -> Result<String> {
+        // A parameter variation document holds only the FileHeader and the
+        // ParameterValueDistribution (XSD group ParameterValueDistributionDefinition)
+        let serialized = match &self.parameter_value_distribution {
+            Some(distribution) if self.is_parameter_variation() => {
+                quick_xml::se::to_string(&ParameterVariationDocument {
+                    xmlns_xsi: &self.xmlns_xsi,
+                    schema_location: &self.schema_location,
+                    file_header: &self.file_header,
+                    parameter_value_distribution: distribution,
+                })
+            }
+            _ => quick_xml::se::to_string(self),
+        }
+        .map_err(Error::XmlSerializeError)
+        .map_err(|e| e.with_context("Failed to serialize OpenSCENARIO to XML"))?;
+        let serialized = self_close_empty_elements(&serialized)?;
+        let s = format_text(
+            &serialized,
+            Language::Xml,
+            &FormatOptions::default(),
+            |serialized, _| Ok::<_, std::convert::Infallible>(serialized.into()),
+        )
+        .unwrap();
+        Ok(format!("{}\n{}", XML_DECLARATION, s))
+    }
+}
+
+impl FromXml for OpenScenario {
+    fn from_xml(xml: &str) -> Result<Self> {
+        quick_xml::de::from_str(xml)
+            .map_err(Error::from)
+            .map_err(|e| e.with_context("Failed to parse OpenSCENARIO XML"))
+    }
+}
+
+impl ToXml for CatalogFile {
+    fn to_xml(&self) -> Result<String> {
+        let serialized = quick_xml::se::to_string(self)
+            .map_err(Error::XmlSerializeError)
+            .map_err(|e| e.with_context("Failed to serialize catalog to XML"))?;
+        let serialized = self_close_empty_elements(&serialized)?;
+        Ok(format!("{}\n{}", XML_DECLARATION, serialized))
+    }
+}
+
+impl FromXml for CatalogFile {
+    fn from_xml(xml: &str) -> Result<Self> {
+        quick_xml::de::from_str(xml)
+            .map_err(Error::from)
+            .map_err(|e| e.with_context("Failed to parse catalog XML"))
+    }
+}
+
+impl ToXml for Route {
+    fn to_xml(&self) -> Result<String> {
+        quick_xml::se::to_string(self)
+            .map_err(Error::XmlSerializeError)
+            .map_err(|e| e.with_context("Failed to serialize Route to XML"))
+    }
+}
+
+impl FromXml for Route {
+    fn from_xml(xml: &str) -> Result<Self> {
+        quick_xml::de::from_str(xml)
+            .map_err(Error::from)
+            .map_err(|e| e.with_context("Failed to parse Route XML"))
+    }
+}
+
+impl ToXml for Controller {
+    fn to_xml(&self) -> Result<String> {
+        quick_xml::se::to_string(self)
+            .map_err(Error::XmlSerializeError)
+            .map_err(|e| e.with_context("Failed to serialize Controller to XML"))
+    }
+}
+
+impl FromXml for Controller {
+    fn from_xml(xml: &str) -> Result<Self> {
+        quick_xml::de::from_str(xml)
+            .map_err(Error::from)
+            .map_err(|e| e.with_context("Failed to parse Controller XML"))
+    }
+}
+
 /// Internal helper to parse OpenSCENARIO from file
 fn parse_from_file_internal<P: AsRef<Path>>(path: P, validate_xml: bool) -> Result<OpenScenario> {
+    #[cfg(feature = "tracing")]
+    let span = super::trace::FileSpan::enter("scenario", path.as_ref());
+
     let metadata = fs::metadata(&path).map_err(Error::from).map_err(|e| {
         e.with_context(&format!(
             "Failed to read file metadata: {}",
@@ -132,6 +323,9 @@ fn parse_from_file_internal<P: AsRef<Path>>(path: P, validate_xml: bool) -> Resu
         ))
     })?;
 
+    #[cfg(feature = "tracing")]
+    span.record_bytes(metadata.len());
+
     if metadata.len() > MAX_FILE_SIZE {
         return Err(Error::out_of_range(
             "file_size",
@@ -141,13 +335,10 @@ fn parse_from_file_internal<P: AsRef<Path>>(path: P, validate_xml: bool) -> Resu
         ));
     }
 
-    let xml_content = fs::read_to_string(&path)
-        .map_err(Error::from)
-        .map_err(|e| {
-            e.with_context(&format!("Failed to read file: {}", path.as_ref().display()))
-        })?;
-
-    let cleaned_content = remove_bom(&xml_content);
+    let xml_content = read_xml_file(path.as_ref()).map_err(|e| {
+        e.with_context(&format!("Failed to read file: {}", path.as_ref().display()))
+    })?;
+    let cleaned_content = xml_content.as_str();
 
     if validate_xml {
         validate_xml_structure(cleaned_content).map_err(|e| {
@@ -171,6 +362,9 @@ fn parse_catalog_from_file_internal<P: AsRef<Path>>(
     path: P,
     validate_xml: bool,
 ) -> Result<CatalogFile> {
+    #[cfg(feature = "tracing")]
+    let span = super::trace::FileSpan::enter("catalog", path.as_ref());
+
     let metadata = fs::metadata(&path).map_err(Error::from).map_err(|e| {
         e.with_context(&format!(
             "Failed to read catalog file metadata: {}",
@@ -178,6 +372,9 @@ fn parse_catalog_from_file_internal<P: AsRef<Path>>(
         ))
     })?;
 
+    #[cfg(feature = "tracing")]
+    span.record_bytes(metadata.len());
+
     if metadata.len() > MAX_FILE_SIZE {
         return Err(Error::out_of_range(
             "file_size",
@@ -187,16 +384,13 @@ fn parse_catalog_from_file_internal<P: AsRef<Path>>(
         ));
     }
 
-    let xml_content = fs::read_to_string(&path)
-        .map_err(Error::from)
-        .map_err(|e| {
-            e.with_context(&format!(
-                "Failed to read catalog file: {}",
-                path.as_ref().display()
-            ))
-        })?;
-
-    let cleaned_content = remove_bom(&xml_content);
+    let xml_content = read_xml_file(path.as_ref()).map_err(|e| {
+        e.with_context(&format!(
+            "Failed to read catalog file: {}",
+            path.as_ref().display()
+        ))
+    })?;
+    let cleaned_content = xml_content.as_str();
 
     if validate_xml {
         validate_catalog_xml_structure(cleaned_content).map_err(|e| {
@@ -221,9 +415,7 @@ fn parse_catalog_from_file_internal<P: AsRef<Path>>(
 /// XML into our Rust type system.
 #[must_use = "parsing result should be handled"]
 pub fn parse_from_str(xml: &str) -> Result<OpenScenario> {
-    quick_xml::de::from_str(xml)
-        .map_err(Error::from)
-        .map_err(|e| e.with_context("Failed to parse OpenSCENARIO XML"))
+    OpenScenario::from_xml(xml)
 }
 
 /// Parse an OpenSCENARIO document from a file
@@ -234,27 +426,421 @@ pub fn parse_from_file<P: AsRef<Path>>(path: P) -> Result<OpenScenario> {
     parse_from_file_internal(path, false)
 }
 
+/// Raw XML of an element that was skipped during lenient parsing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawXml(pub String);
+
+impl RawXml {
+    /// The element's XML exactly as it appeared in the source document
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+/// Result of [`parse_from_str_lenient`]
+#[derive(Debug, Clone)]
+pub struct LenientParseResult {
+    /// The document with all unknown elements removed
+    pub scenario: OpenScenario,
+    /// Unknown elements keyed by path relative to the root, e.g. `Entities/EntitySelection`
+    pub extra: HashMap<String, Vec<RawXml>>,
+}
+
+/// Child elements modelled by the crate for each container scanned in lenient mode
+///
+/// Paths are relative to the `OpenSCENARIO` root element.
+const LENIENT_KNOWN_CHILDREN: &[(&str, &[&str])] = &[
+    (
+        "",
+        &[
+            "FileHeader",
+            "ParameterDeclarations",
+            "VariableDeclarations",
+            "MonitorDeclarations",
+            "CatalogLocations",
+            "RoadNetwork",
+            "Entities",
+            "Storyboard",
+            "ParameterValueDistribution",
+            "Catalog",
+        ],
+    ),
+    ("Entities", &["ScenarioObject"]),
+    ("Storyboard", &["Init", "Story", "StopTrigger"]),
+];
+
+/// Parse an OpenSCENARIO document, setting aside elements the crate does not model
+///
+/// Unknown children of the root, `Entities` and `Storyboard` elements are cut out
+/// of the document before deserialization and returned in [`LenientParseResult::extra`].
+/// This lets documents from newer OpenSCENARIO versions load even when the new
+/// elements are interleaved with lists (which strict parsing rejects as duplicate
+/// fields). Unknown elements deeper in the tree are still handled as in
+/// [`parse_from_str`].
+#[must_use = "parsing result should be handled"]
+pub fn parse_from_str_lenient(xml: &str) -> Result<LenientParseResult> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let xml = remove_bom(xml);
+    let mut reader = Reader::from_str(xml);
+    let mut path: Vec<String> = Vec::new();
+    let mut skipped: Vec<std::ops::Range<usize>> = Vec::new();
+    let mut extra: HashMap<String, Vec<RawXml>> = HashMap::new();
+
+    loop {
+        let start = reader.buffer_position() as usize;
+        let event = reader.read_event().map_err(|e| {
+            Error::invalid_xml(&format!("{} at byte {}", e, reader.error_position()))
+        })?;
+        let (tag, is_empty) = match &event {
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(_) => {
+                path.pop();
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        let name = String::from_utf8_lossy(tag.local_name().as_ref()).into_owned();
+        let qname = tag.name().as_ref().to_vec();
+
+        // The root element itself has no container to check against
+        let container = path.get(1..).map(|p| p.join("/"));
+        let known = container.as_deref().and_then(|c| {
+            LENIENT_KNOWN_CHILDREN
+                .iter()
+                .find(|(path, _)| *path == c)
+                .map(|(_, children)| *children)
+        });
+
+        match known {
+            Some(children) if !children.contains(&name.as_str()) => {
+                if !is_empty {
+                    reader
+                        .read_to_end(quick_xml::name::QName(&qname))
+                        .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+                }
+                let end = reader.buffer_position() as usize;
+                let key = match container.as_deref() {
+                    Some("") | None => name,
+                    Some(c) => format!("{}/{}", c, name),
+                };
+                extra
+                    .entry(key)
+                    .or_default()
+                    .push(RawXml(xml[start..end].to_string()));
+                skipped.push(start..end);
+            }
+            _ if !is_empty => path.push(name),
+            _ => {}
+        }
+    }
+
+    let scenario = if skipped.is_empty() {
+        parse_from_str(xml)?
+    } else {
+        let mut filtered = String::with_capacity(xml.len());
+        let mut cursor = 0;
+        for range in &skipped {
+            filtered.push_str(&xml[cursor..range.start]);
+            cursor = range.end;
+        }
+        filtered.push_str(&xml[cursor..]);
+        parse_from_str(&filtered)?
+    };
+
+    Ok(LenientParseResult { scenario, extra })
+}
+
+/// Non-fatal issue found while parsing a document
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Warning {
+    /// What was found and how it was handled
+    pub message: String,
+    /// Path of the element relative to the root, e.g. `Storyboard/Init/Actions`
+    pub path: Option<String>,
+}
+
+impl std::fmt::Display for Warning {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.path {
+            Some(path) => write!(f, "{}: {}", path, self.message),
+            None => f.write_str(&self.message),
+        }
+    }
+}
+
+/// Elements deprecated by the XSD as (parent, element, replacement)
+const DEPRECATED_ELEMENTS: &[(&str, &str, &str)] = &[
+    ("GlobalAction", "ParameterAction", "VariableAction"),
+    (
+        "PrivateAction",
+        "ActivateControllerAction",
+        "ControllerAction",
+    ),
+    (
+        "EntityCondition",
+        "ReachPositionCondition",
+        "DistanceCondition",
+    ),
+    ("FollowTrajectoryAction", "Trajectory", "TrajectoryRef"),
+    (
+        "FollowTrajectoryAction",
+        "CatalogReference",
+        "TrajectoryRef",
+    ),
+];
+
+/// Attributes deprecated by the XSD as (element, attribute, replacement)
+///
+/// Deprecated attributes that the crate still models as required (such as
+/// `Weather@cloudState`) are left out.
+const DEPRECATED_ATTRIBUTES: &[(&str, &str, &str)] = &[
+    ("GeoPosition", "height", "altitude"),
+    ("Pedestrian", "model", "model3d"),
+    ("TrafficSourceAction", "velocity", "speed"),
+    ("TrafficSwarmAction", "velocity", "InitialSpeedRange"),
+    ("DistanceCondition", "alongRoute", "relativeDistanceType"),
+    ("TimeHeadwayCondition", "alongRoute", "relativeDistanceType"),
+    (
+        "TimeToCollisionCondition",
+        "alongRoute",
+        "relativeDistanceType",
+    ),
+];
+
+/// Optional declaration lists that carry no meaning when empty
+const OPTIONAL_COLLECTIONS: &[&str] = &[
+    "ParameterDeclarations",
+    "VariableDeclarations",
+    "MonitorDeclarations",
+];
+
+/// Defaults for required `FileHeader` attributes that lenient parsing fills in
+///
+/// The date is the Unix epoch, matching [`OpenScenario::default`].
+const FILE_HEADER_DEFAULTS: &[(&str, &str)] = &[
+    ("revMajor", "1"),
+    ("revMinor", "0"),
+    ("date", "1970-01-01T00:00:00"),
+];
+
+/// Parse an OpenSCENARIO document and report non-fatal issues alongside it
+///
+/// The document is parsed as in [`parse_from_str`], except that missing
+/// `revMajor`, `revMinor` and `date` attributes of the `FileHeader` are
+/// filled with the defaults in [`FILE_HEADER_DEFAULTS`] instead of failing.
+/// Warnings are raised for each recovered header attribute, for elements and
+/// attributes the XSD marks as deprecated, for empty optional declaration
+/// lists and for unknown children of the root, `Entities` and `Storyboard`
+/// elements, which strict parsing ignores.
+#[must_use = "parsing result should be handled"]
+pub fn parse_from_str_with_warnings(xml: &str) -> Result<(OpenScenario, Vec<Warning>)> {
+    let xml = remove_bom(xml);
+    let (recovered, mut warnings) = recover_file_header(xml)?;
+    let scenario = parse_from_str(&recovered)?;
+    warnings.extend(collect_warnings(xml)?);
+    Ok((scenario, warnings))
+}
+
+/// Add the defaults of missing required attributes to the `FileHeader` of `xml`
+fn recover_file_header(xml: &str) -> Result<(String, Vec<Warning>)> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(xml);
+    let mut depth = 0;
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            Error::invalid_xml(&format!("{} at byte {}", e, reader.error_position()))
+        })?;
+        let (tag, is_empty) = match &event {
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(_) => {
+                depth -= 1;
+                continue;
+            }
+            Event::Eof => return Ok((xml.to_string(), Vec::new())),
+            _ => continue,
+        };
+        if depth != 1 || tag.local_name().as_ref() != b"FileHeader" {
+            if !is_empty {
+                depth += 1;
+            }
+            continue;
+        }
+
+        let present: Vec<Vec<u8>> = tag
+            .attributes()
+            .flatten()
+            .map(|attribute| attribute.key.local_name().as_ref().to_vec())
+            .collect();
+        let mut added = String::new();
+        let mut warnings = Vec::new();
+        for (name, default) in FILE_HEADER_DEFAULTS {
+            if !present.iter().any(|key| key == name.as_bytes()) {
+                added.push_str(&format!(" {}=\"{}\"", name, default));
+                warnings.push(Warning {
+                    message: format!(
+                        "Missing required attribute '{}', using default '{}'",
+                        name, default
+                    ),
+                    path: Some("FileHeader".to_string()),
+                });
+            }
+        }
+
+        // Insert before the closing `>` or `/>` of the start tag
+        let end = reader.buffer_position() as usize;
+        let insert_at = if is_empty { end - 2 } else { end - 1 };
+        let mut recovered = String::with_capacity(xml.len() + added.len());
+        recovered.push_str(&xml[..insert_at]);
+        recovered.push_str(&added);
+        recovered.push_str(&xml[insert_at..]);
+        return Ok((recovered, warnings));
+    }
+}
+
+/// Scan the document for the issues reported by [`parse_from_str_with_warnings`]
+fn collect_warnings(xml: &str) -> Result<Vec<Warning>> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(remove_bom(xml));
+    // Open elements with the number of child elements seen so far
+    let mut path: Vec<(String, usize)> = Vec::new();
+    let mut warnings = Vec::new();
+
+    loop {
+        let event = reader.read_event().map_err(|e| {
+            Error::invalid_xml(&format!("{} at byte {}", e, reader.error_position()))
+        })?;
+        let (tag, is_empty) = match &event {
+            Event::Start(e) => (e, false),
+            Event::Empty(e) => (e, true),
+            Event::End(_) => {
+                if let Some((name, 0)) = path.pop() {
+                    if OPTIONAL_COLLECTIONS.contains(&name.as_str()) {
+                        warnings.push(empty_collection_warning(element_path(&path, &name)));
+                    }
+                }
+                continue;
+            }
+            Event::Eof => break,
+            _ => continue,
+        };
+        let name = String::from_utf8_lossy(tag.local_name().as_ref()).into_owned();
+        if let Some((_, children)) = path.last_mut() {
+            *children += 1;
+        }
+        let parent = path.last().map(|(parent, _)| parent.as_str());
+        let container = path.get(1..).map(|p| {
+            p.iter()
+                .map(|(name, _)| name.as_str())
+                .collect::<Vec<_>>()
+                .join("/")
+        });
+        let known = container.as_deref().and_then(|c| {
+            LENIENT_KNOWN_CHILDREN
+                .iter()
+                .find(|(path, _)| *path == c)
+                .map(|(_, children)| *children)
+        });
+        if known.is_some_and(|children| !children.contains(&name.as_str())) {
+            warnings.push(Warning {
+                message: format!("Unknown element <{}> is ignored", name),
+                path: Some(element_path(&path, &name)),
+            });
+        }
+
+        if let Some((_, _, replacement)) = DEPRECATED_ELEMENTS
+            .iter()
+            .find(|(p, element, _)| Some(*p) == parent && *element == name)
+        {
+            warnings.push(Warning {
+                message: format!(
+                    "Element <{}> is deprecated, use {} instead",
+                    name, replacement
+                ),
+                path: Some(element_path(&path, &name)),
+            });
+        }
+
+        for attribute in tag.attributes().flatten() {
+            let key = attribute.key.local_name();
+            let key = String::from_utf8_lossy(key.as_ref());
+            if let Some((_, _, replacement)) = DEPRECATED_ATTRIBUTES
+                .iter()
+                .find(|(element, attr, _)| *element == name && *attr == key)
+            {
+                warnings.push(Warning {
+                    message: format!(
+                        "Attribute '{}' is deprecated, use {} instead",
+                        key, replacement
+                    ),
+                    path: Some(element_path(&path, &name)),
+                });
+            }
+        }
+
+        if is_empty {
+            if OPTIONAL_COLLECTIONS.contains(&name.as_str()) {
+                warnings.push(empty_collection_warning(element_path(&path, &name)));
+            }
+        } else {
+            path.push((name, 0));
+        }
+    }
+
+    Ok(warnings)
+}
+
+/// Path of element `name` below the open `parents`, relative to the root
+fn element_path(parents: &[(String, usize)], name: &str) -> String {
+    parents
+        .iter()
+        .skip(1)
+        .map(|(parent, _)| parent.as_str())
+        .chain(std::iter::once(name))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn empty_collection_warning(path: String) -> Warning {
+    let name = path.rsplit('/').next().unwrap_or_default();
+    Warning {
+        message: format!("Empty <{}> can be omitted", name),
+        path: Some(path),
+    }
+}
+
+/// Serialization view of a parameter variation document
+#[derive(Serialize)]
+#[serde(rename = "OpenSCENARIO")]
+struct ParameterVariationDocument<'a> {
+    #[serde(rename = "@xmlns:xsi", skip_serializing_if = "Option::is_none")]
+    xmlns_xsi: &'a Option<String>,
+    #[serde(
+        rename = "@xsi:noNamespaceSchemaLocation",
+        skip_serializing_if = "Option::is_none"
+    )]
+    schema_location: &'a Option<String>,
+    #[serde(rename = "FileHeader")]
+    file_header: &'a FileHeader,
+    #[serde(rename = "ParameterValueDistribution")]
+    parameter_value_distribution: &'a ParameterValueDistribution,
+}
+
 /// Serialize an OpenSCENARIO document to XML string
 ///
 /// This function uses quick-xml's serde integration to serialize
 /// our Rust types back to XML format.
 #[must_use = "serialization result should be handled"]
 pub fn serialize_to_string(scenario: &OpenScenario) -> Result<String> {
-    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
-    xml.push('\n');
-
-    let serialized = quick_xml::se::to_string(scenario)
-        .map_err(Error::XmlSerializeError)
-        .map_err(|e| e.with_context("Failed to serialize OpenSCENARIO to XML"))?;
-    let s = format_text(
-        &serialized,
-        Language::Xml,
-        &FormatOptions::default(),
-        |serialized, _| Ok::<_, std::convert::Infallible>(serialized.into()),
-    )
-    .unwrap();
-    xml.push_str(&s);
-    Ok(xml)
+    scenario.to_xml()
 }
 
 /// Serialize an OpenSCENARIO document to a file
@@ -272,6 +858,22 @@ pub fn serialize_to_file<P: AsRef<Path>>(scenario: &OpenScenario, path: P) -> Re
     })
 }
 
+/// Serialize a parameter variation document to XML string
+///
+/// Like [`serialize_to_string`], but fails unless the document is a
+/// `ParameterValueDistribution` document, so a scenario is never written
+/// where a variation file is expected.
+#[must_use = "serialization result should be handled"]
+pub fn serialize_parameter_variation_to_string(scenario: &OpenScenario) -> Result<String> {
+    if !scenario.is_parameter_variation() {
+        return Err(Error::validation_error(
+            "ParameterValueDistribution",
+            "document is not a parameter variation document",
+        ));
+    }
+    serialize_to_string(scenario)
+}
+
 /// Validate XML structure before parsing
 ///
 /// This function performs basic XML structure validation to provide
@@ -324,9 +926,7 @@ pub fn parse_from_file_validated<P: AsRef<Path>>(path: P) -> Result<OpenScenario
 /// catalog XML into our catalog file structure.
 #[must_use = "parsing result should be handled"]
 pub fn parse_catalog_from_str(xml: &str) -> Result<CatalogFile> {
-    quick_xml::de::from_str(xml)
-        .map_err(Error::from)
-        .map_err(|e| e.with_context("Failed to parse catalog XML"))
+    CatalogFile::from_xml(xml)
 }
 
 /// Parse a catalog file from a file path
@@ -390,15 +990,7 @@ pub fn parse_catalog_from_file_validated<P: AsRef<Path>>(path: P) -> Result<Cata
 /// This function uses quick-xml's serde integration to serialize
 /// our catalog types back to XML format.
 pub fn serialize_catalog_to_string(catalog: &CatalogFile) -> Result<String> {
-    let mut xml = String::from(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
-    xml.push('\n');
-
-    let serialized = quick_xml::se::to_string(catalog)
-        .map_err(Error::XmlSerializeError)
-        .map_err(|e| e.with_context("Failed to serialize catalog to XML"))?;
-
-    xml.push_str(&serialized);
-    Ok(xml)
+    catalog.to_xml()
 }
 
 /// Serialize a catalog file to a file path
@@ -415,10 +1007,115 @@ pub fn serialize_catalog_to_file<P: AsRef<Path>>(catalog: &CatalogFile, path: P)
     })
 }
 
+/// Write a vehicle catalog file containing the given vehicles
+///
+/// Convenience wrapper around [`CatalogFile::from_vehicles`] and
+/// [`serialize_catalog_to_file`].
+pub fn write_vehicle_catalog<P: AsRef<Path>>(
+    path: P,
+    name: &str,
+    vehicles: Vec<CatalogVehicle>,
+) -> Result<()> {
+    serialize_catalog_to_file(
+        &CatalogFile::from_vehicles(name.to_string(), vehicles),
+        path,
+    )
+}
+
+/// Write a controller catalog file containing the given controllers
+pub fn write_controller_catalog<P: AsRef<Path>>(
+    path: P,
+    name: &str,
+    controllers: Vec<CatalogController>,
+) -> Result<()> {
+    serialize_catalog_to_file(
+        &CatalogFile::from_controllers(name.to_string(), controllers),
+        path,
+    )
+}
+
+/// Write a pedestrian catalog file containing the given pedestrians
+pub fn write_pedestrian_catalog<P: AsRef<Path>>(
+    path: P,
+    name: &str,
+    pedestrians: Vec<CatalogPedestrian>,
+) -> Result<()> {
+    serialize_catalog_to_file(
+        &CatalogFile::from_pedestrians(name.to_string(), pedestrians),
+        path,
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_self_close_empty_elements() {
+        let xml = r#"<Vehicle name="Car"><Properties></Properties><Performance maxSpeed="70"></Performance><Text> </Text></Vehicle>"#;
+        assert_eq!(
+            self_close_empty_elements(xml).unwrap(),
+            r#"<Vehicle name="Car"><Properties/><Performance maxSpeed="70"/><Text> </Text></Vehicle>"#
+        );
+
+        let mut vehicle = crate::types::entities::vehicle::Vehicle::default();
+        vehicle.properties = Some(crate::types::entities::vehicle::Properties::default());
+        let scenario = OpenScenario {
+            entities: Some(crate::types::entities::Entities::from_vehicles([(
+                "Ego".to_string(),
+                vehicle,
+            )])),
+            ..OpenScenario::default()
+        };
+        let serialized = serialize_to_string(&scenario).unwrap();
+        assert!(serialized.contains("<Properties />"));
+        assert!(!serialized.contains("</Properties>"));
+    }
+
+    #[test]
+    fn test_parse_from_file_with_bom() {
+        let xml = fs::read("tests/data/simple_scenario.xosc").unwrap();
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("bom.xosc");
+        fs::write(&path, [b"\xEF\xBB\xBF".as_slice(), &xml].concat()).unwrap();
+
+        let scenario = parse_from_file(&path).unwrap();
+        assert_eq!(
+            scenario.file_header.author.as_literal().unwrap(),
+            "OpenSCENARIO-rs"
+        );
+    }
+
+    #[test]
+    fn test_parse_from_file_latin1() {
+        let xml = fs::read_to_string("tests/data/simple_scenario.xosc")
+            .unwrap()
+            .replace(r#"encoding="UTF-8""#, r#"encoding="ISO-8859-1""#)
+            .replace(r#"author="OpenSCENARIO-rs""#, r#"author="José Müller""#);
+        // Encode as Latin-1: every character in the document fits in one byte
+        let bytes: Vec<u8> = xml.chars().map(|c| u8::try_from(c).unwrap()).collect();
+        assert!(std::str::from_utf8(&bytes).is_err());
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let path = dir.path().join("latin1.xosc");
+        fs::write(&path, &bytes).unwrap();
+
+        let scenario = parse_from_file(&path).unwrap();
+        assert_eq!(
+            scenario.file_header.author.as_literal().unwrap(),
+            "José Müller"
+        );
+    }
+
+    #[test]
+    fn test_decode_xml_rejects_unsupported_encoding() {
+        let xml = br#"<?xml version="1.0" encoding='Shift_JIS'?><OpenSCENARIO/>"#;
+        assert_eq!(declared_encoding(xml), Some("Shift_JIS"));
+        let err = decode_xml(xml.to_vec()).unwrap_err();
+        assert!(err.contains("shift_jis"));
+        assert_eq!(declared_encoding(b"<OpenSCENARIO/>"), None);
+    }
+
     #[test]
     fn test_validate_xml_structure() {
         // Valid XML
@@ -469,4 +1166,284 @@ mod tests {
         assert!(xml.contains("OpenSCENARIO"));
         assert!(xml.contains("Catalog"));
     }
+
+    #[test]
+    fn test_parse_with_warnings() {
+        let xml = r#"<?xml version="1.0"?>
+<OpenSCENARIO>
+  <FileHeader revMajor="1" revMinor="3" date="2024-01-01T00:00:00" author="Test" description="Test"/>
+  <ParameterDeclarations/>
+  <FutureDeclarations/>
+  <Entities/>
+  <Storyboard>
+    <Init><Actions/></Init>
+    <StopTrigger>
+      <ConditionGroup>
+        <Condition name="Near" delay="0" conditionEdge="rising">
+          <ByEntityCondition>
+            <TriggeringEntities triggeringEntitiesRule="any">
+              <EntityRef entityRef="ego"/>
+            </TriggeringEntities>
+            <EntityCondition>
+              <DistanceCondition value="5" freespace="false" alongRoute="false" rule="lessThan">
+                <Position><WorldPosition x="0" y="0"/></Position>
+              </DistanceCondition>
+            </EntityCondition>
+          </ByEntityCondition>
+        </Condition>
+      </ConditionGroup>
+    </StopTrigger>
+  </Storyboard>
+</OpenSCENARIO>"#;
+
+        let (scenario, warnings) = parse_from_str_with_warnings(xml).unwrap();
+        assert!(scenario.storyboard.is_some());
+
+        let distance_path = "Storyboard/StopTrigger/ConditionGroup/Condition/\
+                             ByEntityCondition/EntityCondition/DistanceCondition";
+        let paths: Vec<_> = warnings.iter().map(|w| w.path.as_deref()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                Some("ParameterDeclarations"),
+                Some("FutureDeclarations"),
+                Some(distance_path),
+            ]
+        );
+        assert_eq!(
+            warnings[2].to_string(),
+            format!(
+                "{}: Attribute 'alongRoute' is deprecated, use relativeDistanceType instead",
+                distance_path
+            )
+        );
+        assert!(warnings[1]
+            .message
+            .contains("Unknown element <FutureDeclarations>"));
+
+        // Clean documents produce no warnings
+        let (_, warnings) = parse_from_str_with_warnings(
+            &std::fs::read_to_string("tests/data/simple_scenario.xosc").unwrap(),
+        )
+        .unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn test_parse_with_warnings_recovers_file_header() {
+        let without_date = fs::read_to_string("tests/data/simple_scenario.xosc")
+            .unwrap()
+            .replacen(r#" date="2024-01-01T00:00:00""#, "", 1);
+
+        // Strict parsing rejects the header
+        assert!(parse_from_str(&without_date).is_err());
+
+        let (scenario, warnings) = parse_from_str_with_warnings(&without_date).unwrap();
+        assert_eq!(
+            scenario.file_header.date.as_literal().unwrap(),
+            "1970-01-01T00:00:00"
+        );
+        assert_eq!(
+            warnings,
+            vec![Warning {
+                message: "Missing required attribute 'date', using default '1970-01-01T00:00:00'"
+                    .to_string(),
+                path: Some("FileHeader".to_string()),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_parse_lenient_keeps_unknown_elements() {
+        let xml = r#"<?xml version="1.0"?>
+<OpenSCENARIO>
+  <FileHeader revMajor="1" revMinor="3" date="2024-01-01T00:00:00" author="Test" description="Test"/>
+  <FutureDeclarations><FutureItem value="1"/></FutureDeclarations>
+  <Entities>
+    <ScenarioObject name="ego"/>
+    <FutureEntity name="drone"/>
+    <ScenarioObject name="lead"/>
+  </Entities>
+  <Storyboard>
+    <Init><Actions/></Init>
+  </Storyboard>
+</OpenSCENARIO>"#;
+
+        // The unknown element splits the ScenarioObject list, which strict parsing rejects
+        assert!(parse_from_str(xml).is_err());
+
+        let parsed = parse_from_str_lenient(xml).unwrap();
+        let entities = parsed.scenario.entities.unwrap();
+        assert_eq!(entities.scenario_objects.len(), 2);
+        assert_eq!(entities.scenario_objects[1].get_name(), Some("lead"));
+
+        assert_eq!(parsed.extra.len(), 2);
+        assert_eq!(
+            parsed.extra["Entities/FutureEntity"],
+            vec![RawXml(r#"<FutureEntity name="drone"/>"#.to_string())]
+        );
+        assert_eq!(
+            parsed.extra["FutureDeclarations"][0].as_str(),
+            r#"<FutureDeclarations><FutureItem value="1"/></FutureDeclarations>"#
+        );
+    }
+
+    #[test]
+    fn test_root_schema_attributes_roundtrip() {
+        let xml = fs::read_to_string("tests/data/simple_scenario.xosc")
+            .unwrap()
+            .replacen(
+                "<OpenSCENARIO>",
+                r#"<OpenSCENARIO xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:noNamespaceSchemaLocation="OpenSCENARIO.xsd">"#,
+                1,
+            );
+
+        let scenario = parse_from_str(&xml).unwrap();
+        assert_eq!(
+            scenario.xmlns_xsi.as_deref(),
+            Some("http://www.w3.org/2001/XMLSchema-instance")
+        );
+        assert_eq!(
+            scenario.schema_location.as_deref(),
+            Some("OpenSCENARIO.xsd")
+        );
+
+        let serialized = serialize_to_string(&scenario).unwrap();
+        assert!(serialized.contains(r#"xsi:noNamespaceSchemaLocation="OpenSCENARIO.xsd""#));
+        assert!(serialized.contains(r#"xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance""#));
+
+        let reparsed = parse_from_str(&serialized).unwrap();
+        assert_eq!(reparsed.schema_location, scenario.schema_location);
+        assert_eq!(reparsed.xmlns_xsi, scenario.xmlns_xsi);
+
+        // Documents without the attributes do not gain them
+        let plain = parse_from_file("tests/data/simple_scenario.xosc").unwrap();
+        assert!(!serialize_to_string(&plain).unwrap().contains("xsi:"));
+    }
+
+    #[test]
+    fn test_parameter_variation_roundtrip() {
+        use crate::types::basic::Value;
+        use crate::types::distributions::{
+            Deterministic, DeterministicMultiParameterDistribution,
+            DeterministicSingleParameterDistribution, DistributionSet, DistributionSetElement,
+            ParameterAssignment, ParameterValueDistribution, ParameterValueSet,
+            ValueSetDistribution,
+        };
+        use crate::types::entities::vehicle::File;
+        use crate::types::scenario::storyboard::OpenScenarioDocumentType;
+
+        let mut doc = OpenScenario {
+            entities: None,
+            storyboard: None,
+            ..OpenScenario::default()
+        };
+        doc.parameter_value_distribution = Some(ParameterValueDistribution {
+            scenario_file: File {
+                filepath: "highway.xosc".to_string(),
+            },
+            deterministic: Some(Deterministic {
+                single_distributions: vec![DeterministicSingleParameterDistribution {
+                    parameter_name: Value::literal("EgoSpeed".to_string()),
+                    distribution_set: Some(DistributionSet {
+                        elements: vec![
+                            DistributionSetElement {
+                                value: Value::literal("20".to_string()),
+                            },
+                            DistributionSetElement {
+                                value: Value::literal("30".to_string()),
+                            },
+                        ],
+                    }),
+                    distribution_range: None,
+                    user_defined_distribution: None,
+                }],
+                multi_distributions: vec![DeterministicMultiParameterDistribution {
+                    distribution_type: ValueSetDistribution {
+                        parameter_value_sets: vec![ParameterValueSet {
+                            parameter_assignments: vec![ParameterAssignment {
+                                parameter_ref: "Gap".to_string(),
+                                value: Value::literal("15".to_string()),
+                            }],
+                        }],
+                        assignment_author: None,
+                    },
+                }],
+            }),
+            stochastic: None,
+        });
+
+        let xml = serialize_parameter_variation_to_string(&doc).unwrap();
+        assert!(xml.contains("<ParameterValueDistribution"));
+        assert!(xml.contains("<ScenarioFile"));
+        assert!(xml.contains(r#"filepath="highway.xosc""#));
+        assert!(xml.contains("<Deterministic"));
+        for element in ["<ParameterDeclarations", "<RoadNetwork", "<Entities"] {
+            assert!(!xml.contains(element), "unexpected {}", element);
+        }
+
+        let parsed = parse_from_str(&xml).unwrap();
+        assert_eq!(
+            parsed.document_type(),
+            OpenScenarioDocumentType::ParameterVariation
+        );
+        assert_eq!(
+            parsed.parameter_value_distribution,
+            doc.parameter_value_distribution
+        );
+
+        // Scenario documents are rejected by the dedicated serializer
+        assert!(serialize_parameter_variation_to_string(&OpenScenario::default()).is_err());
+    }
+
+    #[test]
+    fn test_xml_traits_roundtrip_route_and_controller() {
+        use crate::types::enums::{ControllerType, RouteStrategy};
+        use crate::types::routing::Waypoint;
+
+        let route = Route::new("Loop", true)
+            .add_waypoint(Waypoint::world_position(
+                0.0,
+                0.0,
+                0.0,
+                RouteStrategy::Shortest,
+            ))
+            .add_waypoint(Waypoint::world_position(
+                50.0,
+                0.0,
+                0.0,
+                RouteStrategy::Fastest,
+            ));
+        let xml = route.to_xml().unwrap();
+        assert!(xml.starts_with("<Route"));
+        assert!(xml.contains(r#"name="Loop""#));
+        assert_eq!(Route::from_xml(&xml).unwrap(), route);
+
+        let controller = Controller::new("Driver".to_string(), ControllerType::Movement);
+        let xml = controller.to_xml().unwrap();
+        assert!(xml.starts_with("<Controller"));
+        assert_eq!(Controller::from_xml(&xml).unwrap(), controller);
+
+        assert!(Route::from_xml("<Route").is_err());
+    }
+
+    #[test]
+    fn test_xml_traits_match_free_functions() {
+        let catalog = CatalogFile::default();
+        assert_eq!(
+            catalog.to_xml().unwrap(),
+            serialize_catalog_to_string(&catalog).unwrap()
+        );
+        let parsed = CatalogFile::from_xml(&catalog.to_xml().unwrap()).unwrap();
+        assert_eq!(parsed.catalog_name(), catalog.catalog_name());
+
+        let scenario = OpenScenario::default();
+        let xml = scenario.to_xml().unwrap();
+        assert!(xml.starts_with(XML_DECLARATION));
+        assert_eq!(xml, serialize_to_string(&scenario).unwrap());
+        assert_eq!(
+            OpenScenario::from_xml(&xml).unwrap().file_header.author,
+            scenario.file_header.author
+        );
+    }
 }
@@ -2,7 +2,7 @@
 //!
 //! This file contains:
 //! - Controller assignment and activation actions following OpenSCENARIO specification  
-//! - Override actions for manual control (throttle, brake, steering, gear)
+//! - OverrideControllerValueAction with per-input overrides (throttle, brake, steering, gear)
 //! - Controller configuration and parameter setting per OpenSCENARIO XSD schema
 //! - Gear control types (manual/automatic) and supporting enumerations
 //!
@@ -10,6 +10,7 @@ use crate::types::basic::{Boolean, Double, Int};
 use crate::types::catalogs::entities::CatalogController;
 use crate::types::catalogs::references::CatalogReference;
 use crate::types::controllers::Controller;
+pub use crate::types::enums::AutomaticGearType;
 use serde::{Deserialize, Serialize};
 
 
@@ -24,44 +25,12 @@ pub struct ControllerAction {
     )]
     pub assign_controller_action: Option<AssignControllerAction>,
 
-    /// Override throttle action
+    /// Override controller value action
     #[serde(
-        rename = "OverrideThrottleAction",
+        rename = "OverrideControllerValueAction",
         skip_serializing_if = "Option::is_none"
     )]
-    pub override_throttle_action: Option<OverrideThrottleAction>,
-
-    /// Override brake action
-    #[serde(
-        rename = "OverrideBrakeAction",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub override_brake_action: Option<OverrideBrakeAction>,
-
-    /// Override clutch action
-    #[serde(
-        rename = "OverrideClutchAction",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub override_clutch_action: Option<OverrideClutchAction>,
-
-    /// Override parking brake action
-    #[serde(
-        rename = "OverrideParkingBrakeAction",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub override_parking_brake_action: Option<OverrideParkingBrakeAction>,
-
-    /// Override steering wheel action
-    #[serde(
-        rename = "OverrideSteeringWheelAction",
-        skip_serializing_if = "Option::is_none"
-    )]
-    pub override_steering_wheel_action: Option<OverrideSteeringWheelAction>,
-
-    /// Override gear action
-    #[serde(rename = "OverrideGearAction", skip_serializing_if = "Option::is_none")]
-    pub override_gear_action: Option<OverrideGearAction>,
+    pub override_controller_value_action: Option<OverrideControllerValueAction>,
 
     /// Activate controller action (deprecated in OpenSCENARIO 1.2)
     #[serde(
@@ -93,6 +62,27 @@ pub struct ActivateControllerAction {
     pub animation: Option<Boolean>,
 }
 
+/// Override controller value action grouping per-input overrides
+///
+/// Every input carries its own `active` flag, so one action can take over the
+/// throttle while releasing an earlier brake override. Inputs that are not
+/// given are left as they are.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct OverrideControllerValueAction {
+    #[serde(rename = "Throttle", skip_serializing_if = "Option::is_none")]
+    pub throttle: Option<OverrideThrottleAction>,
+    #[serde(rename = "Brake", skip_serializing_if = "Option::is_none")]
+    pub brake: Option<OverrideBrakeAction>,
+    #[serde(rename = "Clutch", skip_serializing_if = "Option::is_none")]
+    pub clutch: Option<OverrideClutchAction>,
+    #[serde(rename = "ParkingBrake", skip_serializing_if = "Option::is_none")]
+    pub parking_brake: Option<OverrideParkingBrakeAction>,
+    #[serde(rename = "SteeringWheel", skip_serializing_if = "Option::is_none")]
+    pub steering_wheel: Option<OverrideSteeringWheelAction>,
+    #[serde(rename = "Gear", skip_serializing_if = "Option::is_none")]
+    pub gear: Option<OverrideGearAction>,
+}
+
 // Individual Override Actions matching XSD schema names
 
 /// Override brake action (XSD compliant name)
@@ -178,21 +168,6 @@ pub struct AutomaticGear {
     pub gear: AutomaticGearType,
 }
 
-/// Automatic gear type enumeration
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(Default)]
-pub enum AutomaticGearType {
-    #[serde(rename = "park")]
-    Park,
-    #[serde(rename = "reverse")]
-    Reverse,
-    #[serde(rename = "neutral")]
-    Neutral,
-    #[serde(rename = "drive")]
-    #[default]
-    Drive,
-}
-
 /// Base brake type for brake input groups
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Brake {
@@ -541,6 +516,29 @@ mod tests {
         assert_eq!(neutral.gear, AutomaticGearType::Neutral);
     }
 
+    #[test]
+    fn test_override_gear_action_round_trip() {
+        for gear in [Gear::manual(3), Gear::automatic(AutomaticGearType::Reverse)] {
+            let action = OverrideControllerValueAction {
+                gear: Some(OverrideGearAction {
+                    active: Boolean::literal(true),
+                    number: None,
+                    gear: Some(gear),
+                }),
+                ..OverrideControllerValueAction::default()
+            };
+            let xml = quick_xml::se::to_string(&action).unwrap();
+            let parsed: OverrideControllerValueAction = quick_xml::de::from_str(&xml).unwrap();
+            assert_eq!(parsed, action);
+        }
+
+        let xml = quick_xml::se::to_string(&AutomaticGear::park()).unwrap();
+        assert!(xml.contains("gear=\"p\""));
+        let legacy: AutomaticGear =
+            quick_xml::de::from_str(r#"<AutomaticGear gear="drive"/>"#).unwrap();
+        assert_eq!(legacy.gear, AutomaticGearType::Drive);
+    }
+
     #[test]
     fn test_controller_action_defaults() {
         let assign = AssignControllerAction::default();
@@ -552,8 +550,7 @@ mod tests {
 
         let controller_action = ControllerAction::default();
         assert!(controller_action.assign_controller_action.is_none());
-        assert!(controller_action.override_brake_action.is_none());
-        assert!(controller_action.override_throttle_action.is_none());
+        assert!(controller_action.override_controller_value_action.is_none());
     }
 
     // Tests for new group types
@@ -653,4 +650,39 @@ mod tests {
             panic!("Expected AutomaticGear variant as default");
         }
     }
+
+    #[test]
+    fn test_override_controller_value_action_independent_active_flags() {
+        let action = ControllerAction {
+            override_controller_value_action: Some(OverrideControllerValueAction {
+                throttle: Some(OverrideThrottleAction {
+                    active: Boolean::literal(true),
+                    value: Double::literal(0.4),
+                    max_rate: None,
+                }),
+                brake: Some(OverrideBrakeAction {
+                    active: Boolean::literal(false),
+                    value: None,
+                    brake_input: Some(BrakeInput::percent(0.0)),
+                }),
+                ..OverrideControllerValueAction::default()
+            }),
+            ..ControllerAction::default()
+        };
+
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert_eq!(
+            xml,
+            "<ControllerAction><OverrideControllerValueAction>\
+             <Throttle active=\"true\" value=\"0.4\"/>\
+             <Brake active=\"false\"><BrakePercent value=\"0\"/></Brake>\
+             </OverrideControllerValueAction></ControllerAction>"
+        );
+
+        let parsed: ControllerAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, action);
+        let overrides = parsed.override_controller_value_action.unwrap();
+        assert_eq!(overrides.throttle.unwrap().active, Boolean::literal(true));
+        assert_eq!(overrides.brake.unwrap().active, Boolean::literal(false));
+    }
 }
@@ -41,6 +41,8 @@ pub use movement::{
     SpeedAction,
     SpeedProfileAction,
     SynchronizeAction,
+    TargetDistanceSteadyState,
+    TargetTimeSteadyState,
     TeleportAction,
     Trajectory,
     TrajectoryFollowingMode,
@@ -91,6 +93,7 @@ pub use control::{
     ManualGear,
     OverrideBrakeAction,
     OverrideClutchAction,
+    OverrideControllerValueAction,
     OverrideGearAction,
     OverrideParkingBrakeAction,
     OverrideSteeringWheelAction,
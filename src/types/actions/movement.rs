@@ -11,8 +11,8 @@ use crate::types::basic::{Boolean, Double, Int, OSString};
 use crate::types::catalogs::entities::CatalogTrajectory;
 use crate::types::catalogs::references::{CatalogReference, ParameterAssignment};
 use crate::types::enums::{DynamicsDimension, DynamicsShape, FollowingMode, SpeedTargetValueType};
-use crate::types::geometry::shapes::Shape;
-use crate::types::positions::Position;
+use crate::types::geometry::shapes::{Polyline, Shape, Vertex};
+use crate::types::positions::{Position, WorldPosition};
 use crate::types::routing::{Route, RouteRef};
 use serde::{Deserialize, Deserializer, Serialize};
 
@@ -104,6 +104,13 @@ pub struct TransitionDynamics {
     pub dynamics_dimension: DynamicsDimension,
     #[serde(rename = "@dynamicsShape")]
     pub dynamics_shape: DynamicsShape,
+    /// Whether the target is followed exactly (position) or with vehicle dynamics (follow)
+    #[serde(
+        rename = "@followingMode",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub following_mode: Option<FollowingMode>,
     #[serde(rename = "@value")]
     pub value: Double,
 }
@@ -170,14 +177,37 @@ pub struct TrajectoryFollowingMode {
     pub following_mode: FollowingMode,
 }
 
+/// Steady state reached at a distance before the target position
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TargetDistanceSteadyState {
+    /// Distance to the target position in meters
+    #[serde(rename = "@distance")]
+    pub distance: Double,
+}
+
+/// Steady state reached at a time before arriving at the target position
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct TargetTimeSteadyState {
+    /// Time to the target position in seconds
+    #[serde(rename = "@time")]
+    pub time: Double,
+}
+
 /// Time reference for trajectory following
+///
+/// XSD choice between `None`, where vertex times are ignored, and `Timing`.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[derive(Default)]
 pub struct TimeReference {
-    #[serde(rename = "Timing")]
-    pub timing: Timing,
+    #[serde(rename = "None", skip_serializing_if = "Option::is_none")]
+    pub none: Option<NoTimeReference>,
+    #[serde(rename = "Timing", skip_serializing_if = "Option::is_none")]
+    pub timing: Option<Timing>,
 }
 
+/// Marker for trajectories followed without timing (empty per XSD)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
+pub struct NoTimeReference {}
+
 /// Timing specification for trajectory following
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct Timing {
@@ -458,19 +488,33 @@ pub struct LongitudinalDistanceAction {
 /// Speed profile action for time-based speed control
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SpeedProfileAction {
-    #[serde(rename = "@entityRef")]
+    /// Entity the entry speeds are relative to; speeds are absolute if omitted
+    #[serde(
+        rename = "@entityRef",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
     pub entity_ref: Option<OSString>,
-    #[serde(rename = "DynamicConstraints")]
+    /// Whether the speeds are followed exactly (position) or with vehicle dynamics (follow)
+    #[serde(rename = "@followingMode")]
+    pub following_mode: FollowingMode,
+    #[serde(
+        rename = "DynamicConstraints",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
     pub dynamic_constraints: Option<DynamicConstraints>,
-    #[serde(rename = "Entry", default)]
+    /// Speeds to reach in order; `Entry` is accepted for documents written by older versions
+    #[serde(rename = "SpeedProfileEntry", alias = "Entry", default)]
     pub entries: Vec<SpeedProfileEntry>,
 }
 
 /// Speed profile entry with time and speed
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SpeedProfileEntry {
-    #[serde(rename = "@time")]
-    pub time: Double,
+    /// Time in seconds to reach the speed; left to the simulator if omitted
+    #[serde(rename = "@time", skip_serializing_if = "Option::is_none", default)]
+    pub time: Option<Double>,
     #[serde(rename = "@speed")]
     pub speed: Double,
 }
@@ -479,13 +523,44 @@ pub struct SpeedProfileEntry {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[derive(Default)]
 pub struct DynamicConstraints {
-    #[serde(rename = "@maxLateralAcc")]
+    #[serde(rename = "@maxLateralAcc", skip_serializing_if = "Option::is_none")]
     pub max_lateral_acc: Option<Double>,
-    #[serde(rename = "@maxSpeed")]
+    #[serde(rename = "@maxSpeed", skip_serializing_if = "Option::is_none")]
     pub max_speed: Option<Double>,
+    /// Maximum acceleration in m/s²
+    #[serde(
+        rename = "@maxAcceleration",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub max_acceleration: Option<Double>,
+    /// Maximum acceleration rate (jerk) in m/s³, since OpenSCENARIO 1.2
+    #[serde(
+        rename = "@maxAccelerationRate",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub max_acceleration_rate: Option<Double>,
+    /// Maximum deceleration in m/s²
+    #[serde(
+        rename = "@maxDeceleration",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub max_deceleration: Option<Double>,
+    /// Maximum deceleration rate in m/s³, since OpenSCENARIO 1.2
+    #[serde(
+        rename = "@maxDecelerationRate",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub max_deceleration_rate: Option<Double>,
 }
 
 /// Synchronize action for coordinated entity movement
+///
+/// Unlike [`SpeedProfileAction`], the XSD gives SynchronizeAction no
+/// `DynamicConstraints` child, so none is modelled here.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct SynchronizeAction {
     /// Reference to the master entity to synchronize with
@@ -536,6 +611,22 @@ pub enum FinalSpeedChoice {
 pub struct AbsoluteSpeed {
     #[serde(rename = "@value")]
     pub value: Double,
+
+    /// Steady state reached a distance before the target position (optional)
+    #[serde(
+        rename = "TargetDistanceSteadyState",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub target_distance_steady_state: Option<TargetDistanceSteadyState>,
+
+    /// Steady state reached a time before the target position (optional)
+    #[serde(
+        rename = "TargetTimeSteadyState",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub target_time_steady_state: Option<TargetTimeSteadyState>,
 }
 
 /// Relative speed to master specification
@@ -543,6 +634,22 @@ pub struct AbsoluteSpeed {
 pub struct RelativeSpeedToMaster {
     #[serde(rename = "@value")]
     pub value: Double,
+
+    /// Steady state reached a distance before the target position (optional)
+    #[serde(
+        rename = "TargetDistanceSteadyState",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub target_distance_steady_state: Option<TargetDistanceSteadyState>,
+
+    /// Steady state reached a time before the target position (optional)
+    #[serde(
+        rename = "TargetTimeSteadyState",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub target_time_steady_state: Option<TargetTimeSteadyState>,
 }
 
 /// Acquire position action for moving to a specific position
@@ -560,6 +667,7 @@ impl Default for TransitionDynamics {
         Self {
             dynamics_dimension: DynamicsDimension::Time,
             dynamics_shape: DynamicsShape::Linear,
+            following_mode: None,
             value: Double::literal(1.0),
         }
     }
@@ -593,6 +701,34 @@ impl Default for RelativeTargetSpeed {
     }
 }
 
+impl Trajectory {
+    /// Create an open polyline trajectory from `(time, x, y, heading)` samples
+    ///
+    /// Each sample becomes a vertex with a world position, in the given order.
+    pub fn from_world_points(name: &str, points: &[(f64, f64, f64, f64)]) -> Self {
+        let vertices = points
+            .iter()
+            .map(|&(time, x, y, h)| Vertex {
+                time: Double::literal(time),
+                position: Position {
+                    world_position: Some(WorldPosition {
+                        h: Some(Double::literal(h)),
+                        ..WorldPosition::new(x, y)
+                    }),
+                    ..Position::empty()
+                },
+            })
+            .collect();
+        Self {
+            name: OSString::literal(name.to_string()),
+            closed: Boolean::literal(false),
+            shape: Shape {
+                polyline: Some(Polyline { vertices }),
+            },
+        }
+    }
+}
+
 impl Default for Trajectory {
     fn default() -> Self {
         Self {
@@ -611,6 +747,30 @@ impl Default for TrajectoryFollowingMode {
     }
 }
 
+impl TimeReference {
+    /// Follow the trajectory ignoring vertex times
+    pub fn none() -> Self {
+        Self {
+            none: Some(NoTimeReference {}),
+            timing: None,
+        }
+    }
+
+    /// Follow the trajectory with vertex times interpreted by `timing`
+    pub fn with_timing(timing: Timing) -> Self {
+        Self {
+            none: None,
+            timing: Some(timing),
+        }
+    }
+}
+
+impl Default for TimeReference {
+    /// Absolute timing with unit scale and no offset
+    fn default() -> Self {
+        Self::with_timing(Timing::default())
+    }
+}
 
 impl Default for Timing {
     fn default() -> Self {
@@ -685,7 +845,7 @@ impl FollowTrajectoryAction {
     }
 
     /// Validates the FollowTrajectoryAction according to XSD requirements
-    /// TimeReference is required, trajectory sources are optional but at most one should be present
+    /// TimeReference must hold exactly one choice, trajectory sources are optional but at most one should be present
     pub fn validate(&self) -> Result<(), String> {
         let trajectory_count = [
             self.trajectory.is_some(),
@@ -700,7 +860,9 @@ impl FollowTrajectoryAction {
             return Err("FollowTrajectoryAction can contain at most one trajectory source (Trajectory, CatalogReference, or TrajectoryRef), found multiple".to_string());
         }
 
-        // TimeReference is required and always present due to struct definition
+        if self.time_reference.none.is_some() == self.time_reference.timing.is_some() {
+            return Err("TimeReference must contain exactly one of None or Timing".to_string());
+        }
         Ok(())
     }
 
@@ -815,6 +977,33 @@ impl RoutingAction {
     }
 }
 
+impl SpeedAction {
+    /// Replace the target with a speed relative to `master_entity`
+    ///
+    /// `value` is added to the master entity's speed (a `delta` target). The
+    /// target is evaluated once at the start of the action, not continuously.
+    pub fn to_relative(&mut self, master_entity: &str, value: f64) {
+        self.speed_action_target = SpeedActionTarget {
+            absolute: None,
+            relative: Some(RelativeTargetSpeed {
+                value: Double::literal(value),
+                entity_ref: master_entity.to_string(),
+                value_type: SpeedTargetValueType::Delta,
+                continuous: false,
+            }),
+        };
+    }
+
+    /// Replace the target with an absolute speed in m/s
+    pub fn to_absolute(&mut self, value: f64) {
+        self.speed_action_target = SpeedActionTarget {
+            absolute: Some(AbsoluteTargetSpeed {
+                value: Double::literal(value),
+            }),
+            relative: None,
+        };
+    }
+}
 
 impl LaneChangeAction {
     /// Create a new LaneChangeAction with the specified dynamics and target
@@ -1101,6 +1290,7 @@ impl Default for SpeedProfileAction {
     fn default() -> Self {
         Self {
             entity_ref: None,
+            following_mode: FollowingMode::Follow,
             dynamic_constraints: None,
             entries: vec![SpeedProfileEntry::default()],
         }
@@ -1110,7 +1300,7 @@ impl Default for SpeedProfileAction {
 impl Default for SpeedProfileEntry {
     fn default() -> Self {
         Self {
-            time: Double::literal(0.0),
+            time: Some(Double::literal(0.0)),
             speed: Double::literal(10.0),
         }
     }
@@ -1142,6 +1332,8 @@ impl Default for AbsoluteSpeed {
     fn default() -> Self {
         Self {
             value: Double::literal(10.0),
+            target_distance_steady_state: None,
+            target_time_steady_state: None,
         }
     }
 }
@@ -1150,6 +1342,8 @@ impl Default for RelativeSpeedToMaster {
     fn default() -> Self {
         Self {
             value: Double::literal(0.0),
+            target_distance_steady_state: None,
+            target_time_steady_state: None,
         }
     }
 }
@@ -1303,6 +1497,7 @@ mod tests {
             TransitionDynamics {
                 dynamics_dimension: DynamicsDimension::Time,
                 dynamics_shape: DynamicsShape::Linear,
+                following_mode: None,
                 value: Double::literal(2.0),
             },
             LaneChangeTarget::relative("TestEntity", 2),
@@ -1434,6 +1629,7 @@ mod tests {
             dynamic_constraints: Some(DynamicConstraints {
                 max_lateral_acc: Some(Double::literal(2.0)),
                 max_speed: Some(Double::literal(50.0)),
+                ..DynamicConstraints::default()
             }),
         };
 
@@ -1487,19 +1683,21 @@ mod tests {
     #[test]
     fn test_speed_profile_action_creation() {
         let entry1 = SpeedProfileEntry {
-            time: Double::literal(0.0),
+            time: Some(Double::literal(0.0)),
             speed: Double::literal(10.0),
         };
         let entry2 = SpeedProfileEntry {
-            time: Double::literal(5.0),
+            time: Some(Double::literal(5.0)),
             speed: Double::literal(20.0),
         };
 
         let action = SpeedProfileAction {
             entity_ref: Some(OSString::literal("RefEntity".to_string())),
+            following_mode: FollowingMode::Position,
             dynamic_constraints: Some(DynamicConstraints {
                 max_lateral_acc: Some(Double::literal(1.5)),
                 max_speed: Some(Double::literal(30.0)),
+                ..DynamicConstraints::default()
             }),
             entries: vec![entry1, entry2],
         };
@@ -1509,9 +1707,9 @@ mod tests {
             Some(&"RefEntity".to_string())
         );
         assert_eq!(action.entries.len(), 2);
-        assert_eq!(action.entries[0].time.as_literal(), Some(&0.0));
+        assert_eq!(action.entries[0].time, Some(Double::literal(0.0)));
         assert_eq!(action.entries[0].speed.as_literal(), Some(&10.0));
-        assert_eq!(action.entries[1].time.as_literal(), Some(&5.0));
+        assert_eq!(action.entries[1].time, Some(Double::literal(5.0)));
         assert_eq!(action.entries[1].speed.as_literal(), Some(&20.0));
     }
 
@@ -1524,6 +1722,7 @@ mod tests {
             final_speed: Some(FinalSpeed {
                 speed_choice: FinalSpeedChoice::AbsoluteSpeed(AbsoluteSpeed {
                     value: Double::literal(15.0),
+                    ..AbsoluteSpeed::default()
                 }),
             }),
             target_tolerance_master: Some(Double::literal(1.0)),
@@ -1544,6 +1743,30 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_speed_profile_dynamic_constraints_round_trip() {
+        let original = SpeedProfileAction {
+            entity_ref: None,
+            following_mode: FollowingMode::Follow,
+            dynamic_constraints: Some(DynamicConstraints {
+                max_acceleration: Some(Double::literal(2.0)),
+                max_deceleration: Some(Double::literal(4.0)),
+                max_speed: Some(Double::literal(30.0)),
+                ..DynamicConstraints::default()
+            }),
+            entries: vec![SpeedProfileEntry {
+                time: Some(Double::literal(5.0)),
+                speed: Double::literal(20.0),
+            }],
+        };
+
+        let xml = quick_xml::se::to_string(&original).unwrap();
+        assert!(xml.contains("<DynamicConstraints"));
+        assert!(xml.contains("maxAcceleration=\"2\""));
+        let deserialized: SpeedProfileAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, original);
+    }
+
     #[test]
     fn test_acquire_position_action_creation() {
         let action = AcquirePositionAction {
@@ -1562,6 +1785,7 @@ mod tests {
         let constraints = DynamicConstraints {
             max_lateral_acc: Some(Double::literal(3.0)),
             max_speed: Some(Double::literal(80.0)),
+            ..DynamicConstraints::default()
         };
 
         assert_eq!(
@@ -1581,6 +1805,7 @@ mod tests {
         let abs_final = FinalSpeed {
             speed_choice: FinalSpeedChoice::AbsoluteSpeed(AbsoluteSpeed {
                 value: Double::literal(25.0),
+                ..AbsoluteSpeed::default()
             }),
         };
 
@@ -1592,6 +1817,7 @@ mod tests {
         let rel_final = FinalSpeed {
             speed_choice: FinalSpeedChoice::RelativeSpeedToMaster(RelativeSpeedToMaster {
                 value: Double::literal(-5.0),
+                ..RelativeSpeedToMaster::default()
             }),
         };
 
@@ -1600,6 +1826,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_final_speed_steady_state_round_trip() {
+        let final_speed = FinalSpeed {
+            speed_choice: FinalSpeedChoice::AbsoluteSpeed(AbsoluteSpeed {
+                value: Double::literal(15.0),
+                target_time_steady_state: Some(TargetTimeSteadyState {
+                    time: Double::literal(2.0),
+                }),
+                ..AbsoluteSpeed::default()
+            }),
+        };
+        let xml = quick_xml::se::to_string_with_root("FinalSpeed", &final_speed).unwrap();
+        assert!(xml.contains(
+            r#"<AbsoluteSpeed value="15"><TargetTimeSteadyState time="2"/></AbsoluteSpeed>"#
+        ));
+        assert!(!xml.contains("SteadyState>"));
+        assert_eq!(quick_xml::de::from_str::<FinalSpeed>(&xml).unwrap(), final_speed);
+
+        let xml = r#"<FinalSpeed>
+            <RelativeSpeedToMaster value="-2">
+                <TargetDistanceSteadyState distance="30"/>
+            </RelativeSpeedToMaster>
+        </FinalSpeed>"#;
+        let parsed: FinalSpeed = quick_xml::de::from_str(xml).unwrap();
+        let FinalSpeedChoice::RelativeSpeedToMaster(relative) = parsed.speed_choice else {
+            panic!("Expected RelativeSpeedToMaster");
+        };
+        let steady_state = relative.target_distance_steady_state.unwrap();
+        assert_eq!(steady_state.distance.as_literal(), Some(&30.0));
+        assert!(relative.target_time_steady_state.is_none());
+    }
+
     #[test]
     fn test_action_defaults() {
         // Test that all new action types have working defaults
@@ -1683,6 +1941,105 @@ mod tests {
         };
         assert!(invalid_multiple.validate().is_err());
     }
+
+    #[test]
+    fn test_trajectory_from_world_points() {
+        let trajectory = Trajectory::from_world_points(
+            "Recorded",
+            &[
+                (0.0, 0.0, 0.0, 0.0),
+                (1.0, 10.0, 0.5, 0.05),
+                (2.0, 20.0, 1.5, 0.1),
+                (3.0, 30.0, 3.0, 0.15),
+            ],
+        );
+        assert_eq!(trajectory.name.as_literal().unwrap(), "Recorded");
+        assert_eq!(trajectory.closed.as_literal(), Some(&false));
+
+        let vertices = &trajectory.shape.polyline.as_ref().unwrap().vertices;
+        assert_eq!(vertices.len(), 4);
+        let samples: Vec<_> = vertices
+            .iter()
+            .map(|vertex| {
+                let world = vertex.position.world_position.as_ref().unwrap();
+                (
+                    *vertex.time.as_literal().unwrap(),
+                    *world.x.as_literal().unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(samples, [(0.0, 0.0), (1.0, 10.0), (2.0, 20.0), (3.0, 30.0)]);
+        let last = vertices[3].position.world_position.as_ref().unwrap();
+        assert_eq!(last.h.as_ref().unwrap().as_literal(), Some(&0.15));
+
+        let xml = quick_xml::se::to_string(&trajectory).unwrap();
+        let parsed: Trajectory = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, trajectory);
+    }
+
+    #[test]
+    fn test_time_reference_round_trip() {
+        let mut action = FollowTrajectoryAction::with_catalog_reference(
+            CatalogReference::new("TrajectoryCatalog".to_string(), "Lane".to_string()),
+            FollowingMode::Position,
+        );
+        action.time_reference = TimeReference::with_timing(Timing {
+            domain_absolute_relative: OSString::literal("relative".to_string()),
+            scale: Double::literal(1.5),
+            offset: Double::literal(2.0),
+        });
+
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert!(xml.contains(
+            r#"<TimeReference><Timing domainAbsoluteRelative="relative" scale="1.5" offset="2"/></TimeReference>"#
+        ));
+        let parsed: FollowTrajectoryAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, action);
+
+        // Trajectories followed without timing keep the None choice
+        action.time_reference = TimeReference::none();
+        assert!(action.validate().is_ok());
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert!(xml.contains("<TimeReference><None/></TimeReference>"));
+        let parsed: FollowTrajectoryAction = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.time_reference, TimeReference::none());
+
+        action.time_reference.timing = Some(Timing::default());
+        assert!(action.validate().is_err());
+    }
+
+    #[test]
+    fn test_speed_action_target_conversion() {
+        let mut action = SpeedAction {
+            speed_action_dynamics: TransitionDynamics::default(),
+            speed_action_target: SpeedActionTarget::default(),
+        };
+        action.to_absolute(30.0);
+        assert_eq!(
+            action.speed_action_target.absolute,
+            Some(AbsoluteTargetSpeed {
+                value: Double::literal(30.0)
+            })
+        );
+
+        action.to_relative("lead", 5.0);
+        assert!(action.speed_action_target.absolute.is_none());
+        let relative = action.speed_action_target.relative.as_ref().unwrap();
+        assert_eq!(relative.entity_ref, "lead");
+        assert_eq!(relative.value.as_literal(), Some(&5.0));
+        assert_eq!(relative.value_type, SpeedTargetValueType::Delta);
+
+        let xml = quick_xml::se::to_string(&action).unwrap();
+        assert!(xml.contains("<RelativeTargetSpeed"));
+        assert!(!xml.contains("AbsoluteTargetSpeed"));
+
+        action.to_absolute(30.0);
+        assert!(action.speed_action_target.relative.is_none());
+        assert_eq!(
+            action.speed_action_target.absolute.as_ref().unwrap().value,
+            Double::literal(30.0)
+        );
+    }
 }
 
 // Add movement action validation
@@ -8,6 +8,8 @@
 //! - Background traffic definition and distribution specifications
 //!
 use crate::types::basic::{Boolean, Double, OSString, UnsignedInt};
+use crate::types::catalogs::references::ControllerCatalogReference;
+use crate::types::controllers::Controller;
 use crate::types::positions::Position;
 use serde::{Deserialize, Serialize};
 
@@ -161,9 +163,13 @@ pub struct TrafficSignalControllerAction {
 pub struct TrafficSignalController {
     #[serde(rename = "@name")]
     pub name: OSString,
-    #[serde(rename = "@delay")]
+    #[serde(rename = "@delay", skip_serializing_if = "Option::is_none", default)]
     pub delay: Option<Double>,
-    #[serde(rename = "@reference")]
+    #[serde(
+        rename = "@reference",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
     pub reference: Option<OSString>,
     #[serde(rename = "Phase", default)]
     pub phases: Vec<Phase>,
@@ -178,7 +184,11 @@ pub struct Phase {
     pub duration: Double,
     #[serde(rename = "TrafficSignalState", default)]
     pub traffic_signal_states: Vec<TrafficSignalState>,
-    #[serde(rename = "TrafficSignalGroupState")]
+    #[serde(
+        rename = "TrafficSignalGroupState",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
     pub traffic_signal_group_state: Option<TrafficSignalGroupState>,
 }
 
@@ -213,10 +223,13 @@ pub struct TrafficStopAction {
 ///
 /// # Fields
 ///
+/// * `name` - Name of the traffic definition
 /// * `vehicle_category_distribution` - Distribution of vehicle categories (cars, trucks, etc.)
 /// * `controller_distribution` - Distribution of controller behaviors
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct TrafficDefinition {
+    #[serde(rename = "@name")]
+    pub name: OSString,
     #[serde(rename = "VehicleCategoryDistribution")]
     pub vehicle_category_distribution: Option<VehicleCategoryDistribution>,
     #[serde(rename = "ControllerDistribution")]
@@ -270,12 +283,16 @@ pub struct ControllerDistribution {
 }
 
 /// Controller distribution entry
+///
+/// Holds either a direct controller definition or a catalog reference.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ControllerDistributionEntry {
     #[serde(rename = "@weight")]
     pub weight: Double,
-    #[serde(rename = "Controller")]
-    pub controller: OSString,
+    #[serde(rename = "Controller", skip_serializing_if = "Option::is_none")]
+    pub controller: Option<Controller>,
+    #[serde(rename = "CatalogReference", skip_serializing_if = "Option::is_none")]
+    pub catalog_reference: Option<ControllerCatalogReference>,
 }
 
 /// Central swarm object specification
@@ -420,6 +437,7 @@ impl Default for TrafficStopAction {
 impl Default for TrafficDefinition {
     fn default() -> Self {
         Self {
+            name: OSString::literal("DefaultTraffic".to_string()),
             vehicle_category_distribution: Some(VehicleCategoryDistribution::default()),
             controller_distribution: None,
         }
@@ -452,7 +470,8 @@ impl Default for ControllerDistribution {
         Self {
             entries: vec![ControllerDistributionEntry {
                 weight: Double::literal(1.0),
-                controller: OSString::literal("DefaultTrafficController".to_string()),
+                controller: Some(Controller::default()),
+                catalog_reference: None,
             }],
         }
     }
@@ -775,6 +794,7 @@ impl TrafficDefinition {
     /// Create traffic definition with vehicle categories only
     pub fn with_vehicles(distribution: VehicleCategoryDistribution) -> Self {
         Self {
+            name: OSString::literal("TrafficDefinition".to_string()),
             vehicle_category_distribution: Some(distribution),
             controller_distribution: None,
         }
@@ -783,6 +803,7 @@ impl TrafficDefinition {
     /// Create traffic definition with controllers only
     pub fn with_controllers(distribution: ControllerDistribution) -> Self {
         Self {
+            name: OSString::literal("TrafficDefinition".to_string()),
             vehicle_category_distribution: None,
             controller_distribution: Some(distribution),
         }
@@ -794,6 +815,7 @@ impl TrafficDefinition {
         controllers: ControllerDistribution,
     ) -> Self {
         Self {
+            name: OSString::literal("TrafficDefinition".to_string()),
             vehicle_category_distribution: Some(vehicles),
             controller_distribution: Some(controllers),
         }
@@ -839,11 +861,12 @@ impl VehicleCategoryDistribution {
 
 impl ControllerDistribution {
     /// Create distribution with single controller
-    pub fn single_controller(controller: String, weight: f64) -> Self {
+    pub fn single_controller(controller: Controller, weight: f64) -> Self {
         Self {
             entries: vec![ControllerDistributionEntry {
                 weight: Double::literal(weight),
-                controller: OSString::literal(controller),
+                controller: Some(controller),
+                catalog_reference: None,
             }],
         }
     }
@@ -916,6 +939,7 @@ impl TrafficArea {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::enums::ControllerType;
     use crate::types::positions::Position;
 
     #[test]
@@ -1063,7 +1087,10 @@ mod tests {
     #[test]
     fn test_traffic_definition_creation() {
         let vehicles = VehicleCategoryDistribution::urban_traffic();
-        let controllers = ControllerDistribution::single_controller("AI1".to_string(), 1.0);
+        let controllers = ControllerDistribution::single_controller(
+            Controller::new("AI1".to_string(), ControllerType::Movement),
+            1.0,
+        );
 
         let definition = TrafficDefinition::with_both(vehicles, controllers);
 
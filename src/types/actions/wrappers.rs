@@ -5,7 +5,7 @@
 
 use crate::types::basic::{Boolean, Double, OSString, UnsignedInt};
 use crate::types::positions::Position;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 // Import individual action types
 use super::{
@@ -109,7 +109,7 @@ pub struct AddEntityAction {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct DeleteEntityAction {}
 
-// UserDefinedAction placeholder
+// UserDefinedAction type
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 #[derive(Default)]
 pub struct UserDefinedAction {
@@ -117,10 +117,13 @@ pub struct UserDefinedAction {
     pub custom_command_action: CustomCommandAction,
 }
 
-// CustomCommandAction placeholder
+// CustomCommandAction type - simulator specific command with free text content
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct CustomCommandAction {
-    // Implementation depends on specific use case
+    #[serde(rename = "@type")]
+    pub command_type: OSString,
+    #[serde(rename = "$text", default)]
+    pub content: String,
 }
 
 // Environment Action (placeholder for now)
@@ -195,9 +198,10 @@ pub struct ParameterAction {
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "PascalCase")]
 pub enum ParameterActionChoice {
+    #[serde(rename = "SetAction")]
     ParameterSetAction(ParameterSetAction),
+    #[serde(rename = "ModifyAction")]
     ParameterModifyAction(ParameterModifyAction),
 }
 
@@ -209,14 +213,67 @@ pub struct ParameterSetAction {
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParameterModifyAction {
-    #[serde(flatten)]
+    #[serde(
+        rename = "Rule",
+        serialize_with = "serialize_modify_rule",
+        deserialize_with = "deserialize_modify_rule"
+    )]
     pub rule: ModifyRule,
 }
 
+/// `Rule` element content, with the rule choice as optional children
+///
+/// A `$value` choice cannot be read back through the flattened
+/// `ParameterActionChoice`, so the choice is spelled out field by field.
+#[derive(Serialize, Deserialize)]
+struct RuleElement {
+    #[serde(rename = "AddValue", skip_serializing_if = "Option::is_none")]
+    add_value: Option<ParameterAddValueRule>,
+    #[serde(rename = "MultiplyByValue", skip_serializing_if = "Option::is_none")]
+    multiply_by_value: Option<ParameterMultiplyByValueRule>,
+}
+
+fn serialize_modify_rule<S>(rule: &ModifyRule, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let element = match rule {
+        ModifyRule::ParameterAddValueRule(add) => RuleElement {
+            add_value: Some(add.clone()),
+            multiply_by_value: None,
+        },
+        ModifyRule::ParameterMultiplyByValueRule(multiply) => RuleElement {
+            add_value: None,
+            multiply_by_value: Some(multiply.clone()),
+        },
+    };
+    element.serialize(serializer)
+}
+
+fn deserialize_modify_rule<'de, D>(deserializer: D) -> Result<ModifyRule, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match RuleElement::deserialize(deserializer)? {
+        RuleElement {
+            add_value: Some(add),
+            multiply_by_value: None,
+        } => Ok(ModifyRule::ParameterAddValueRule(add)),
+        RuleElement {
+            add_value: None,
+            multiply_by_value: Some(multiply),
+        } => Ok(ModifyRule::ParameterMultiplyByValueRule(multiply)),
+        _ => Err(serde::de::Error::custom(
+            "Rule must contain exactly one of AddValue or MultiplyByValue",
+        )),
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "PascalCase")]
 pub enum ModifyRule {
+    #[serde(rename = "AddValue")]
     ParameterAddValueRule(ParameterAddValueRule),
+    #[serde(rename = "MultiplyByValue")]
     ParameterMultiplyByValueRule(ParameterMultiplyByValueRule),
 }
 
@@ -45,7 +45,10 @@ where
     T::Err: std::fmt::Display,
 {
     /// Resolve this value using the provided parameter map
-    pub fn resolve(&self, params: &HashMap<String, String>) -> Result<T> {
+    pub fn resolve(&self, params: &HashMap<String, String>) -> Result<T>
+    where
+        T: LenientParse,
+    {
         match self {
             Value::Literal(value) => Ok(value.clone()),
             Value::Parameter(param_name) => {
@@ -53,7 +56,7 @@ where
                     .get(param_name)
                     .ok_or_else(|| Error::parameter_error(param_name, "parameter not found"))?;
 
-                param_value.parse::<T>().map_err(|e| {
+                T::parse_lenient(param_value).map_err(|e| {
                     Error::parameter_error(
                         param_name,
                         &format!("failed to parse '{}': {}", param_value, e),
@@ -64,7 +67,7 @@ where
                 // For now, we'll treat expressions as parameters that need to be resolved
                 // In a full implementation, we would parse and evaluate the mathematical expression
                 let resolved_expr = resolve_expression::<T>(expr, params)?;
-                resolved_expr.parse::<T>().map_err(|e| {
+                T::parse_lenient(&resolved_expr).map_err(|e| {
                     Error::parameter_error(
                         expr,
                         &format!(
@@ -131,7 +134,7 @@ impl<T: Clone> Value<T> {
 // Custom serde implementation to handle ${param} and ${expression} syntax
 impl<'de, T> Deserialize<'de> for Value<T>
 where
-    T: Deserialize<'de> + FromStr,
+    T: Deserialize<'de> + LenientParse,
     T::Err: std::fmt::Display,
 {
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
@@ -164,7 +167,7 @@ where
                 Ok(Value::Parameter(content.to_string()))
             } else {
                 // Not a valid parameter, treat as literal
-                match s.parse::<T>() {
+                match T::parse_lenient(&s) {
                     Ok(value) => Ok(Value::Literal(value)),
                     Err(e) => Err(serde::de::Error::custom(format!(
                         "Failed to parse '{}': {}",
@@ -174,7 +177,7 @@ where
             }
         } else {
             // Try to parse as literal value
-            match s.parse::<T>() {
+            match T::parse_lenient(&s) {
                 Ok(value) => Ok(Value::Literal(value)),
                 Err(e) => Err(serde::de::Error::custom(format!(
                     "Failed to parse '{}': {}",
@@ -225,6 +228,79 @@ pub type Boolean = Value<bool>;
 
 pub type DateTime = Value<chrono::DateTime<chrono::Utc>>;
 
+/// Defaults to a literal of the type's default, e.g. `0.0` for `Double`
+impl<T: Default> Default for Value<T> {
+    fn default() -> Self {
+        Value::Literal(T::default())
+    }
+}
+
+/// Wraps a plain value as a literal, e.g. `Double::from(30.0)` or `let b: Boolean = true.into()`
+///
+/// Conversions always produce [`Value::Literal`]; use [`Value::parameter`] or
+/// [`Value::expression`] for references.
+impl<T> From<T> for Value<T> {
+    fn from(value: T) -> Self {
+        Value::Literal(value)
+    }
+}
+
+/// Wraps a string slice as a literal; `"${Speed}"` stays literal text, not a parameter reference
+impl From<&str> for OSString {
+    fn from(value: &str) -> Self {
+        Value::Literal(value.to_string())
+    }
+}
+
+impl Value<f64> {
+    /// Whether this is a literal zero
+    ///
+    /// Used to omit optional attributes that default to 0 when serializing.
+    /// Parameter references and expressions are never considered zero.
+    pub fn is_zero(&self) -> bool {
+        matches!(self, Value::Literal(value) if *value == 0.0)
+    }
+}
+
+/// Whether an optional attribute is unset or a literal zero
+pub(crate) fn is_none_or_zero(value: &Option<Double>) -> bool {
+    value.as_ref().is_none_or(Value::is_zero)
+}
+
+/// Parsing of literal values read from XML or parameter values
+///
+/// The default parses the text unchanged, so string values keep their
+/// whitespace. Numeric types tolerate common lexical variations: values may be
+/// surrounded by whitespace and use a leading `+` sign or scientific notation
+/// (` +1.5e3 ` parses as 1500). Comma decimal separators (`1,5`) are rejected
+/// rather than guessed, since `1,500` is ambiguous between locales.
+pub trait LenientParse: FromStr {
+    /// Parse a literal value
+    fn parse_lenient(s: &str) -> std::result::Result<Self, Self::Err> {
+        s.parse()
+    }
+}
+
+macro_rules! impl_numeric_lenient_parse {
+    ($($numeric:ty),*) => {
+        $(
+            impl LenientParse for $numeric {
+                fn parse_lenient(s: &str) -> std::result::Result<Self, Self::Err> {
+                    s.trim().parse()
+                }
+            }
+        )*
+    };
+}
+
+impl_numeric_lenient_parse!(
+    f32, f64, i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize
+);
+
+impl LenientParse for String {}
+impl LenientParse for bool {}
+impl LenientParse for chrono::DateTime<chrono::Utc> {}
+
 
 
 /// Parse a parameter reference from a string
@@ -290,6 +366,56 @@ mod tests {
     use super::*;
     use quick_xml;
 
+    #[test]
+    fn test_literal_conversions() {
+        let speed: Double = 30.0.into();
+        assert_eq!(speed, Double::literal(30.0));
+        assert_eq!(Boolean::from(true), Boolean::literal(true));
+        assert_eq!(Int::from(-2), Int::literal(-2));
+        assert_eq!(OSString::from("ego"), OSString::literal("ego".to_string()));
+        assert_eq!(
+            OSString::from("lead".to_string()),
+            OSString::literal("lead".to_string())
+        );
+
+        // Parameter syntax is not interpreted by the conversion
+        let text = OSString::from("${Speed}");
+        assert_eq!(text.as_literal().map(String::as_str), Some("${Speed}"));
+        assert!(text.as_parameter().is_none());
+    }
+
+    #[test]
+    fn test_lenient_numeric_parsing() {
+        let mut params = HashMap::new();
+        params.insert("Gap".to_string(), " +1.5e3 ".to_string());
+        params.insert("Lanes".to_string(), " +2".to_string());
+        params.insert("Name".to_string(), " ego ".to_string());
+        params.insert("Bad".to_string(), "abc".to_string());
+        params.insert("Comma".to_string(), "1,5".to_string());
+
+        let double = |name: &str| Double::parameter(name.to_string()).resolve(&params);
+        assert_eq!(double("Gap").unwrap(), 1500.0);
+        assert!(double("Bad").is_err());
+        assert!(double("Comma").is_err());
+
+        let lanes = Int::parameter("Lanes".to_string()).resolve(&params);
+        assert_eq!(lanes.unwrap(), 2);
+        let name = OSString::parameter("Name".to_string()).resolve(&params);
+        assert_eq!(name.unwrap(), " ego ");
+        let count = Value::<usize>::parameter("Lanes".to_string()).resolve(&params);
+        assert_eq!(count.unwrap(), 2);
+
+        #[derive(Deserialize)]
+        struct Attr {
+            #[serde(rename = "@value")]
+            value: Double,
+        }
+        let attr: Attr = quick_xml::de::from_str(r#"<Attr value=" +1.5e3 "/>"#).unwrap();
+        assert_eq!(attr.value.as_literal(), Some(&1500.0));
+        assert!(quick_xml::de::from_str::<Attr>(r#"<Attr value="abc"/>"#).is_err());
+        assert!(quick_xml::de::from_str::<Attr>(r#"<Attr value="1,5"/>"#).is_err());
+    }
+
     #[test]
     fn test_parameter_reference_parsing() {
         assert_eq!(
@@ -404,6 +530,40 @@ mod tests {
         assert_eq!(constraints.value_constraints.len(), 2);
     }
 
+    #[test]
+    fn test_check_constraints_greater_than_zero() {
+        let mut param =
+            ParameterDeclaration::new("Speed".to_string(), ParameterType::Double, "10".to_string());
+        assert!(param.check_constraints("-5").is_ok());
+
+        param.add_constraint(ValueConstraint::greater_than("0".to_string()));
+        assert!(param.check_constraints("10").is_ok());
+        assert!(param.check_constraints("0.5").is_ok());
+
+        let error = param.check_constraints("0").unwrap_err();
+        assert!(matches!(error, Error::ConstraintViolation { .. }));
+        assert!(error.to_string().contains("greaterThan 0"));
+        assert!(param.check_constraints("-3.2").is_err());
+        assert!(param.check_constraints("fast").is_err());
+    }
+
+    #[test]
+    fn test_check_constraints_any_group() {
+        let param = ParameterDeclaration::with_constraints(
+            "Direction".to_string(),
+            ParameterType::String,
+            "left".to_string(),
+            vec![
+                ValueConstraintGroup::new(vec![ValueConstraint::equal_to("left".to_string())]),
+                ValueConstraintGroup::new(vec![ValueConstraint::equal_to("right".to_string())]),
+            ],
+        );
+        assert!(param.check_constraints("left").is_ok());
+        assert!(param.check_constraints("right").is_ok());
+        let error = param.check_constraints("up").unwrap_err();
+        assert!(error.to_string().contains("equalTo left"));
+    }
+
     #[test]
     fn test_value_constraint_helpers() {
         let eq_constraint = ValueConstraint::equal_to("test".to_string());
@@ -684,6 +844,112 @@ mod tests {
         let boolean_expr = Boolean::expression("speed > 30".to_string());
         assert_eq!(format!("{}", boolean_expr), "${speed > 30}");
     }
+
+    #[test]
+    fn test_parameter_declarations_validate_values() {
+        let mut declarations = ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration::new(
+                "Enabled".to_string(),
+                ParameterType::Boolean,
+                "true".to_string(),
+            )],
+        };
+        assert!(declarations.validate_values().is_ok());
+
+        declarations
+            .parameter_declarations
+            .push(ParameterDeclaration::new(
+                "EgoSpeed".to_string(),
+                ParameterType::Double,
+                "fast".to_string(),
+            ));
+        match declarations.validate_values() {
+            Err(Error::TypeMismatch {
+                field,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(field, "EgoSpeed");
+                assert_eq!(expected, "double");
+                assert_eq!(actual, "'fast'");
+            }
+            other => panic!("expected type mismatch, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_parameter_declarations_validate_overrides() {
+        let declarations = ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration::new(
+                "LaneCount".to_string(),
+                ParameterType::UnsignedInt,
+                "3".to_string(),
+            )],
+        };
+
+        let valid = HashMap::from([("LaneCount".to_string(), "2".to_string())]);
+        assert!(declarations.validate_overrides(&valid).is_ok());
+
+        let negative = HashMap::from([("LaneCount".to_string(), "-1".to_string())]);
+        assert!(matches!(
+            declarations.validate_overrides(&negative),
+            Err(Error::TypeMismatch { .. })
+        ));
+
+        let unknown = HashMap::from([("Lanes".to_string(), "2".to_string())]);
+        assert!(matches!(
+            declarations.validate_overrides(&unknown),
+            Err(Error::ParameterNotFound { .. })
+        ));
+    }
+
+    #[test]
+    fn test_parameter_declaration_constraint_group_parsing() {
+        let plain: ParameterDeclaration = quick_xml::de::from_str(
+            r#"<ParameterDeclaration name="Speed" parameterType="double" value="30.0"/>"#,
+        )
+        .unwrap();
+        assert!(plain.constraint_groups.is_empty());
+
+        let constrained: ParameterDeclaration = quick_xml::de::from_str(
+            r#"<ParameterDeclaration name="Lane" parameterType="string" value="left">
+                <ConstraintGroup>
+                    <ValueConstraint rule="equalTo" value="left"/>
+                    <ValueConstraint rule="equalTo" value="right"/>
+                </ConstraintGroup>
+                <ConstraintGroup>
+                    <ValueConstraint rule="equalTo" value="center"/>
+                </ConstraintGroup>
+            </ParameterDeclaration>"#,
+        )
+        .unwrap();
+        assert_eq!(constrained.constraint_groups.len(), 2);
+        assert_eq!(constrained.constraint_groups[0].value_constraints.len(), 2);
+        assert_eq!(
+            constrained.constraint_groups[1].value_constraints[0]
+                .value
+                .as_literal()
+                .unwrap(),
+            "center"
+        );
+
+        let lower_case: ParameterDeclaration = quick_xml::de::from_str(
+            r#"<ParameterDeclaration name="Lane" parameterType="string" value="left">
+                <constraintGroup>
+                    <ValueConstraint rule="equalTo" value="left"/>
+                </constraintGroup>
+            </ParameterDeclaration>"#,
+        )
+        .unwrap();
+        assert_eq!(lower_case.constraint_groups.len(), 1);
+
+        // Serialization always uses the XSD element name
+        let xml = quick_xml::se::to_string(&lower_case).unwrap();
+        assert!(xml.contains("<ConstraintGroup>"));
+        assert!(!xml.contains("constraintGroup"));
+        let reparsed: ParameterDeclaration = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(reparsed, lower_case);
+    }
 }
 
 // Data Container Types for Scenario Structure
@@ -697,6 +963,44 @@ pub struct ParameterDeclarations {
     pub parameter_declarations: Vec<ParameterDeclaration>,
 }
 
+impl ParameterDeclarations {
+    /// Check that every literal default value matches its declared type
+    ///
+    /// Values given as `${...}` references are not checked. Returns a
+    /// type mismatch error naming the first offending parameter.
+    pub fn validate_values(&self) -> Result<()> {
+        for declaration in &self.parameter_declarations {
+            if let Some(value) = declaration.value.as_literal() {
+                declaration.check_value(value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Check parameter overrides against the declared types
+    ///
+    /// Every key must name a declared parameter and its value must parse as
+    /// that parameter's type.
+    pub fn validate_overrides(&self, overrides: &HashMap<String, String>) -> Result<()> {
+        for (name, value) in overrides {
+            let declaration = self
+                .parameter_declarations
+                .iter()
+                .find(|d| d.name.as_literal() == Some(name))
+                .ok_or_else(|| {
+                    let available: Vec<String> = self
+                        .parameter_declarations
+                        .iter()
+                        .map(|d| d.name.to_string())
+                        .collect();
+                    Error::parameter_not_found(name, &available)
+                })?;
+            declaration.check_value(value)?;
+        }
+        Ok(())
+    }
+}
+
 /// Individual parameter declaration
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ParameterDeclaration {
@@ -706,8 +1010,11 @@ pub struct ParameterDeclaration {
     pub parameter_type: ParameterType,
     #[serde(rename = "@value")]
     pub value: OSString,
+    /// Constraint groups, also accepted as lower-case `constraintGroup`
+    /// elements written by some tools
     #[serde(
         rename = "ConstraintGroup",
+        alias = "constraintGroup",
         default,
         skip_serializing_if = "Vec::is_empty"
     )]
@@ -728,7 +1035,7 @@ impl Default for ParameterDeclaration {
 /// Parameter constraints container
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Default)]
 pub struct ValueConstraintGroup {
-    #[serde(rename = "ValueConstraint")]
+    #[serde(rename = "ValueConstraint", default)]
     pub value_constraints: Vec<ValueConstraint>,
 }
 
@@ -810,6 +1117,48 @@ impl ParameterDeclaration {
     pub fn has_constraints(&self) -> bool {
         !self.constraint_groups.is_empty()
     }
+
+    /// Check that `value` is valid for this parameter's declared type
+    pub fn check_value(&self, value: &str) -> Result<()> {
+        if self.parameter_type.accepts(value) {
+            Ok(())
+        } else {
+            Err(Error::type_mismatch(
+                &self.name.to_string(),
+                &self.parameter_type.to_string(),
+                &format!("'{}'", value),
+            ))
+        }
+    }
+
+    /// Check `value` against this parameter's constraint groups
+    ///
+    /// Constraints within a group must all hold; the value is accepted if any
+    /// group holds. Returns the first violation of the first group otherwise.
+    /// Parameters without constraints accept any value.
+    pub fn check_constraints(&self, value: &str) -> Result<()> {
+        let mut first_violation = None;
+        for group in &self.constraint_groups {
+            let violation = group
+                .value_constraints
+                .iter()
+                .find_map(|constraint| match constraint.is_satisfied_by(value) {
+                    Ok(true) => None,
+                    Ok(false) => Some(Error::constraint_violation(&format!(
+                        "parameter '{}' value '{}' must be {} {}",
+                        self.name, value, constraint.rule, constraint.value
+                    ))),
+                    Err(error) => Some(error),
+                });
+            match violation {
+                None => return Ok(()),
+                Some(error) => {
+                    first_violation.get_or_insert(error);
+                }
+            }
+        }
+        first_violation.map_or(Ok(()), Err)
+    }
 }
 
 /// Directory path reference for catalog files
@@ -901,6 +1250,30 @@ impl ValueConstraint {
     pub fn less_than(value: String) -> Self {
         Self::new(Rule::LessThan, value)
     }
+
+    /// Evaluate `value <rule> constraint value`
+    ///
+    /// Values are compared numerically if both parse as numbers and as strings
+    /// otherwise, where only `equalTo` and `notEqualTo` are defined. Constraint
+    /// values that are not literals cannot be evaluated and are considered
+    /// satisfied.
+    pub fn is_satisfied_by(&self, value: &str) -> Result<bool> {
+        let Some(bound) = self.value.as_literal() else {
+            return Ok(true);
+        };
+        if let (Ok(lhs), Ok(rhs)) = (value.trim().parse::<f64>(), bound.trim().parse::<f64>()) {
+            return Ok(self.rule.compare(lhs, rhs));
+        }
+        match self.rule {
+            Rule::EqualTo => Ok(value == bound),
+            Rule::NotEqualTo => Ok(value != bound),
+            _ => Err(Error::type_mismatch(
+                "ValueConstraint",
+                "numeric values",
+                &format!("'{}' {} '{}'", value, self.rule, bound),
+            )),
+        }
+    }
 }
 
 // Helper methods for Range
@@ -5,8 +5,9 @@
 
 use super::entities::{
     CatalogController, CatalogEnvironment, CatalogManeuver, CatalogMiscObject, CatalogPedestrian,
-    CatalogRoute, CatalogTrajectory, CatalogVehicle,
+    CatalogRoute, CatalogVehicle,
 };
+use super::trajectories::CatalogTrajectory;
 use crate::types::basic::Value;
 use crate::FileHeader;
 use serde::{Deserialize, Serialize};
@@ -91,6 +92,39 @@ impl CatalogFile {
         }
     }
 
+    /// Create a vehicle catalog file containing the given vehicles
+    pub fn from_vehicles(name: String, vehicles: Vec<CatalogVehicle>) -> Self {
+        let mut file = Self::new(
+            name,
+            "openscenario-rs".to_string(),
+            "Vehicle catalog".to_string(),
+        );
+        file.catalog.vehicles = vehicles;
+        file
+    }
+
+    /// Create a controller catalog file containing the given controllers
+    pub fn from_controllers(name: String, controllers: Vec<CatalogController>) -> Self {
+        let mut file = Self::new(
+            name,
+            "openscenario-rs".to_string(),
+            "Controller catalog".to_string(),
+        );
+        file.catalog.controllers = controllers;
+        file
+    }
+
+    /// Create a pedestrian catalog file containing the given pedestrians
+    pub fn from_pedestrians(name: String, pedestrians: Vec<CatalogPedestrian>) -> Self {
+        let mut file = Self::new(
+            name,
+            "openscenario-rs".to_string(),
+            "Pedestrian catalog".to_string(),
+        );
+        file.catalog.pedestrians = pedestrians;
+        file
+    }
+
     /// Get the catalog name
     pub fn catalog_name(&self) -> &OSString {
         &self.catalog.name
@@ -0,0 +1,91 @@
+//! Maneuver catalog types for OpenSCENARIO reusable maneuver definitions
+//!
+//! A catalog maneuver has the same content as a maneuver written inline in a
+//! maneuver group, so entries are stored as storyboard [`Maneuver`]s.
+
+use crate::types::basic::{Int, Value};
+use crate::types::scenario::story::Maneuver;
+use serde::{Deserialize, Serialize};
+
+/// Maneuver catalog containing reusable maneuver definitions
+///
+/// Represents a collection of maneuvers that maneuver groups can reference
+/// instead of declaring their events inline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename = "ManeuverCatalog")]
+pub struct ManeuverCatalog {
+    /// Version information for catalog compatibility
+    #[serde(rename = "@revMajor")]
+    pub rev_major: Int,
+
+    #[serde(rename = "@revMinor")]
+    pub rev_minor: Int,
+
+    /// Collection of maneuver entries in this catalog
+    #[serde(rename = "Maneuver", default)]
+    pub maneuvers: Vec<Maneuver>,
+}
+
+impl Default for ManeuverCatalog {
+    fn default() -> Self {
+        Self {
+            rev_major: Int::literal(1),
+            rev_minor: Int::literal(0),
+            maneuvers: Vec::new(),
+        }
+    }
+}
+
+impl ManeuverCatalog {
+    /// Creates a new maneuver catalog with version information
+    pub fn new(rev_major: i32, rev_minor: i32) -> Self {
+        Self {
+            rev_major: Value::Literal(rev_major),
+            rev_minor: Value::Literal(rev_minor),
+            maneuvers: Vec::new(),
+        }
+    }
+
+    /// Adds a maneuver to this catalog
+    pub fn add_maneuver(&mut self, maneuver: Maneuver) {
+        self.maneuvers.push(maneuver);
+    }
+
+    /// Finds a maneuver by name in this catalog
+    pub fn find_maneuver(&self, name: &str) -> Option<&Maneuver> {
+        self.maneuvers
+            .iter()
+            .find(|m| m.name.as_literal().is_some_and(|n| n == name))
+    }
+
+    /// Gets all literal maneuver names in this catalog
+    pub fn maneuver_names(&self) -> Vec<&str> {
+        self.maneuvers
+            .iter()
+            .filter_map(|m| m.name.as_literal().map(String::as_str))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::basic::OSString;
+
+    #[test]
+    fn test_maneuver_catalog_lookup() {
+        let mut catalog = ManeuverCatalog::default();
+        catalog.add_maneuver(Maneuver {
+            name: OSString::literal("CutIn".to_string()),
+            ..Maneuver::default()
+        });
+        catalog.add_maneuver(Maneuver {
+            name: OSString::literal("Brake".to_string()),
+            ..Maneuver::default()
+        });
+
+        assert_eq!(catalog.maneuver_names(), ["CutIn", "Brake"]);
+        assert!(catalog.find_maneuver("Brake").is_some());
+        assert!(catalog.find_maneuver("Overtake").is_none());
+    }
+}
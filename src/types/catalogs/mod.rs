@@ -12,6 +12,7 @@ pub mod entities;
 pub mod environments;
 pub mod files;
 pub mod locations;
+pub mod maneuvers;
 pub mod references;
 pub mod routes;
 pub mod trajectories;
@@ -20,6 +21,7 @@ pub mod trajectories;
 // Main catalog containers
 pub use controllers::ControllerCatalog;
 pub use environments::EnvironmentCatalog;
+pub use maneuvers::ManeuverCatalog;
 pub use routes::RouteCatalog;
 pub use trajectories::TrajectoryCatalog;
 
@@ -52,8 +54,8 @@ pub use locations::{
     RouteCatalogLocation, TrajectoryCatalogLocation, VehicleCatalogLocation,
 };
 pub use references::{
-    CatalogReference, ControllerCatalogReference, ParameterAssignment, PedestrianCatalogReference,
-    VehicleCatalogReference,
+    CatalogReference, ControllerCatalogReference, ManeuverCatalogReference, ParameterAssignment,
+    PedestrianCatalogReference, VehicleCatalogReference,
 };
 
 // Import necessary types for catalog groups
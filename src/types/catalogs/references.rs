@@ -12,6 +12,7 @@ use std::collections::HashMap;
 use std::marker::PhantomData;
 
 use crate::types::basic::OSString;
+pub use crate::types::routing::{ParameterAssignment, ParameterAssignments};
 
 /// Enhanced catalog reference with generic type parameter for type safety
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -29,25 +30,13 @@ pub struct CatalogReference<T: CatalogEntity> {
         rename = "ParameterAssignments",
         skip_serializing_if = "Option::is_none"
     )]
-    pub parameter_assignments: Option<Vec<ParameterAssignment>>,
+    pub parameter_assignments: Option<ParameterAssignments>,
 
     /// Phantom data to maintain type safety
     #[serde(skip)]
     phantom: PhantomData<T>,
 }
 
-/// Parameter assignment for catalog reference resolution
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct ParameterAssignment {
-    /// Reference to the parameter name
-    #[serde(rename = "@parameterRef")]
-    pub parameter_ref: OSString,
-
-    /// Value to assign to the parameter
-    #[serde(rename = "@value")]
-    pub value: OSString,
-}
-
 /// Trait for types that can resolve catalog references
 pub trait CatalogResolvable<T: CatalogEntity> {
     /// Resolve this catalog reference to the actual entity
@@ -74,7 +63,9 @@ impl<T: CatalogEntity> CatalogReference<T> {
         Self {
             catalog_name: Value::Literal(catalog_name),
             entry_name: Value::Literal(entry_name),
-            parameter_assignments: Some(parameter_assignments),
+            parameter_assignments: Some(ParameterAssignments {
+                assignments: parameter_assignments,
+            }),
             phantom: PhantomData,
         }
     }
@@ -97,7 +88,7 @@ impl<T: CatalogEntity> CatalogReference<T> {
         let mut parameters = HashMap::new();
 
         if let Some(assignments) = &self.parameter_assignments {
-            for assignment in assignments {
+            for assignment in &assignments.assignments {
                 let param_name = assignment.parameter_ref.resolve(context_params)?;
                 let param_value = assignment.value.resolve(context_params)?;
                 parameters.insert(param_name, param_value);
@@ -106,6 +97,37 @@ impl<T: CatalogEntity> CatalogReference<T> {
 
         Ok(parameters)
     }
+
+    /// Collect the literal parameter assignments of this reference
+    ///
+    /// Unlike [`build_parameter_map`](Self::build_parameter_map) there is no
+    /// context to resolve `${param}` references against, so a parameterized
+    /// name or value is reported as a catalog error naming the assignment.
+    pub fn to_parameter_map(&self) -> Result<HashMap<String, String>> {
+        let mut parameters = HashMap::new();
+
+        for assignment in self
+            .parameter_assignments
+            .iter()
+            .flat_map(|assignments| &assignments.assignments)
+        {
+            let name = assignment.parameter_ref.as_literal().ok_or_else(|| {
+                crate::error::Error::catalog_error(&format!(
+                    "Cannot resolve parameterized parameter name '{}'",
+                    assignment.parameter_ref
+                ))
+            })?;
+            let value = assignment.value.as_literal().ok_or_else(|| {
+                crate::error::Error::catalog_error(&format!(
+                    "Cannot resolve parameterized value '{}' for parameter '{}'",
+                    assignment.value, name
+                ))
+            })?;
+            parameters.insert(name.clone(), value.clone());
+        }
+
+        Ok(parameters)
+    }
 }
 
 impl<T: CatalogEntity> Default for CatalogReference<T> {
@@ -162,11 +184,12 @@ impl Default for ParameterAssignment {
 }
 
 // Type aliases for common catalog reference types
-use super::entities::{CatalogController, CatalogPedestrian, CatalogVehicle};
+use super::entities::{CatalogController, CatalogManeuver, CatalogPedestrian, CatalogVehicle};
 
 pub type VehicleCatalogReference = CatalogReference<CatalogVehicle>;
 pub type ControllerCatalogReference = CatalogReference<CatalogController>;
 pub type PedestrianCatalogReference = CatalogReference<CatalogPedestrian>;
+pub type ManeuverCatalogReference = CatalogReference<CatalogManeuver>;
 
 #[cfg(test)]
 mod tests {
@@ -332,4 +355,32 @@ mod tests {
         assert_eq!(param_map.get("Speed").unwrap(), "150.0");
         assert_eq!(param_map.get("Color").unwrap(), "Blue");
     }
+
+    #[test]
+    fn test_to_parameter_map() {
+        let reference = VehicleCatalogReference::with_parameters(
+            "VehicleCatalog".to_string(),
+            "SportsCar".to_string(),
+            vec![
+                ParameterAssignment::new("MaxSpeed".to_string(), "60".to_string()),
+                ParameterAssignment::new("Color".to_string(), "red".to_string()),
+            ],
+        );
+
+        let parameters = reference.to_parameter_map().unwrap();
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters.get("MaxSpeed"), Some(&"60".to_string()));
+        assert_eq!(parameters.get("Color"), Some(&"red".to_string()));
+
+        let parameterized = VehicleCatalogReference::with_parameters(
+            "VehicleCatalog".to_string(),
+            "SportsCar".to_string(),
+            vec![ParameterAssignment::with_values(
+                Value::Parameter("SpeedName".to_string()),
+                Value::Literal("60".to_string()),
+            )],
+        );
+        let error = parameterized.to_parameter_map().unwrap_err();
+        assert!(error.to_string().contains("${SpeedName}"));
+    }
 }
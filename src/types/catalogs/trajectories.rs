@@ -5,7 +5,7 @@
 
 use crate::types::basic::{Boolean, Double, Int, OSString, ParameterDeclarations, Value};
 use crate::types::positions::Position;
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 /// Trajectory catalog containing reusable trajectory definitions
 ///
@@ -59,10 +59,35 @@ pub struct CatalogTrajectory {
     pub parameter_declarations: Option<ParameterDeclarations>,
 
     /// Shape definition of the trajectory
-    #[serde(rename = "Shape")]
+    #[serde(
+        rename = "Shape",
+        serialize_with = "serialize_shape",
+        deserialize_with = "deserialize_shape"
+    )]
     pub shape: CatalogTrajectoryShape,
 }
 
+/// `Shape` element content: the shape choice is the element's only child
+#[derive(Serialize, Deserialize)]
+struct ShapeElement<T> {
+    #[serde(rename = "$value")]
+    shape: T,
+}
+
+fn serialize_shape<S>(shape: &CatalogTrajectoryShape, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    ShapeElement { shape }.serialize(serializer)
+}
+
+fn deserialize_shape<'de, D>(deserializer: D) -> Result<CatalogTrajectoryShape, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    ShapeElement::deserialize(deserializer).map(|element| element.shape)
+}
+
 impl Default for CatalogTrajectory {
     fn default() -> Self {
         Self {
@@ -96,7 +121,7 @@ pub enum CatalogTrajectoryShape {
 #[serde(rename = "Polyline")]
 pub struct CatalogPolyline {
     /// Vertices defining the polyline (can be parameterized)
-    #[serde(rename = "Vertex")]
+    #[serde(rename = "Vertex", default)]
     pub vertices: Vec<CatalogVertex>,
 }
 
@@ -607,4 +632,51 @@ mod tests {
         assert_eq!(clothoid.curvature.as_literal().unwrap(), &0.0);
         assert_eq!(nurbs.order.as_literal().unwrap(), &2);
     }
+
+    #[test]
+    fn test_polyline_trajectory_catalog_round_trip() {
+        use crate::parser::xml::{parse_catalog_from_str, serialize_catalog_to_string};
+        use crate::types::catalogs::files::CatalogFile;
+        use crate::types::positions::WorldPosition;
+
+        let mut polyline = CatalogPolyline {
+            vertices: Vec::new(),
+        };
+        for (time, x) in [(0.0, 0.0), (2.0, 20.0), (4.0, 45.0)] {
+            let position = Position {
+                world_position: Some(WorldPosition::new(x, 0.0)),
+                ..Position::empty()
+            };
+            polyline.add_vertex(position, Some(Value::Literal(time)));
+        }
+        let trajectory = CatalogTrajectory::with_closed(
+            "Loop".to_string(),
+            CatalogTrajectoryShape::Polyline(polyline),
+            true,
+        );
+        let mut catalog = CatalogFile::new(
+            "TrajectoryCatalog".to_string(),
+            "Test".to_string(),
+            String::new(),
+        );
+        catalog.catalog.trajectories.push(trajectory.clone());
+
+        let xml = serialize_catalog_to_string(&catalog).unwrap();
+        assert!(xml.contains("<Trajectory name=\"Loop\" closed=\"true\"><Shape><Polyline>"));
+
+        let parsed = parse_catalog_from_str(&xml).unwrap();
+        let parsed_trajectory = &parsed.catalog.trajectories[0];
+        assert_eq!(parsed_trajectory, &trajectory);
+        match &parsed_trajectory.shape {
+            CatalogTrajectoryShape::Polyline(polyline) => {
+                let times: Vec<f64> = polyline
+                    .vertices
+                    .iter()
+                    .map(|v| *v.time.as_ref().unwrap().as_literal().unwrap())
+                    .collect();
+                assert_eq!(times, vec![0.0, 2.0, 4.0]);
+            }
+            _ => panic!("Expected polyline shape"),
+        }
+    }
 }
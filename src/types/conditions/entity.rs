@@ -124,6 +124,10 @@ pub struct TimeHeadwayCondition {
     #[serde(rename = "@freespace")]
     pub freespace: Boolean,
 
+    /// Whether to measure along the route (deprecated)
+    #[serde(rename = "@alongRoute", skip_serializing_if = "Option::is_none")]
+    pub along_route: Option<Boolean>,
+
     /// Optional coordinate system for measurement
     #[serde(rename = "@coordinateSystem", skip_serializing_if = "Option::is_none")]
     pub coordinate_system: Option<CoordinateSystem>,
@@ -227,31 +231,42 @@ pub struct RelativeSpeedCondition {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RelativeLaneRange {
     /// Starting lane offset
+    #[serde(rename = "@from", skip_serializing_if = "Option::is_none")]
     pub from: Option<Int>,
 
     /// Ending lane offset
+    #[serde(rename = "@to", skip_serializing_if = "Option::is_none")]
     pub to: Option<Int>,
 }
 
 /// Clearance monitoring between entities
+///
+/// True when the lanes around the triggering entity are free within the
+/// given forward and backward distances.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct RelativeClearanceCondition {
-    /// Lane ranges to check for clearance
+    /// Lane ranges to check for clearance, relative to the triggering entity's lane
+    #[serde(rename = "RelativeLaneRange", default)]
     pub relative_lane_ranges: Vec<RelativeLaneRange>,
 
     /// Entity references to check clearance against
+    #[serde(rename = "EntityRef", default)]
     pub entity_refs: Vec<EntityRef>,
 
     /// Whether to check opposite lanes
+    #[serde(rename = "@oppositeLanes")]
     pub opposite_lanes: Boolean,
 
     /// Distance to check forward (optional)
+    #[serde(rename = "@distanceForward", skip_serializing_if = "Option::is_none")]
     pub distance_forward: Option<Double>,
 
     /// Distance to check backward (optional)
+    #[serde(rename = "@distanceBackward", skip_serializing_if = "Option::is_none")]
     pub distance_backward: Option<Double>,
 
     /// Whether to use free space measurement
+    #[serde(rename = "@freeSpace")]
     pub free_space: Boolean,
 }
 
@@ -681,6 +696,7 @@ impl TimeHeadwayCondition {
             value: Double::literal(value),
             rule,
             freespace: Boolean::literal(freespace),
+            along_route: None,
             coordinate_system: None,
             relative_distance_type: None,
             routing_algorithm: None,
@@ -870,6 +886,7 @@ impl Default for TimeHeadwayCondition {
             value: Double::literal(2.0),
             rule: Rule::LessThan,
             freespace: Boolean::literal(true),
+            along_route: None,
             coordinate_system: None,
             relative_distance_type: None,
             routing_algorithm: None,
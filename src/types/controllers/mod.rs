@@ -3,7 +3,7 @@
 //! This module provides comprehensive controller functionality for entity behavior management,
 //! including controller definitions, activation actions, and parameter management.
 
-use crate::types::basic::{Boolean, OSString, ParameterDeclarations, Value};
+use crate::types::basic::{OSString, ParameterDeclarations, Value};
 use crate::types::catalogs::references::ControllerCatalogReference;
 use crate::types::distributions::ParameterValueDistribution;
 use crate::types::entities::vehicle::{File, Properties, Property};
@@ -238,36 +238,6 @@ impl Default for ActivateControllerAction {
     }
 }
 
-/// Action to override controller parameter values.
-///
-/// This action modifies controller behavior by overriding specific parameter values
-/// and can activate or deactivate the override.
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-#[serde(rename_all = "camelCase")]
-pub struct OverrideControllerValueAction {
-    /// Reference to the controller to override
-    #[serde(rename = "@controllerRef")]
-    pub controller_ref: OSString,
-
-    /// Parameter assignments for the override
-    #[serde(rename = "ParameterAssignments")]
-    pub parameter_assignments: ParameterAssignments,
-
-    /// Whether the override is active
-    #[serde(rename = "@active")]
-    pub active: Boolean,
-}
-
-impl Default for OverrideControllerValueAction {
-    fn default() -> Self {
-        Self {
-            controller_ref: Value::Literal("DefaultController".to_string()),
-            parameter_assignments: ParameterAssignments::default(),
-            active: Value::Literal(true),
-        }
-    }
-}
-
 /// Assignment of a controller to a specific entity.
 ///
 /// Defines the relationship between a controller and the entity it manages.
@@ -394,6 +364,16 @@ impl Controller {
             properties: Some(properties),
         }
     }
+
+    /// Returns the value of the named property, if the controller has one.
+    pub fn get_property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .as_ref()?
+            .properties
+            .iter()
+            .find(|property| property.name == name)
+            .map(|property| property.value.as_str())
+    }
 }
 
 impl ObjectController {
@@ -476,21 +456,6 @@ impl ActivateControllerAction {
     }
 }
 
-impl OverrideControllerValueAction {
-    /// Creates an action to override controller values.
-    pub fn new(
-        controller_ref: String,
-        parameter_assignments: ParameterAssignments,
-        active: bool,
-    ) -> Self {
-        Self {
-            controller_ref: Value::Literal(controller_ref),
-            parameter_assignments,
-            active: Value::Literal(active),
-        }
-    }
-}
-
 impl ControllerAssignment {
     /// Creates a controller assignment.
     pub fn new(controller_ref: String, target_entity: String) -> Self {
@@ -534,19 +499,6 @@ mod tests {
         assert!(action.parameter_assignments.is_none());
     }
 
-    #[test]
-    fn test_override_controller_action() {
-        let assignments = ParameterAssignments::default();
-        let action =
-            OverrideControllerValueAction::new("TestController".to_string(), assignments, true);
-
-        assert_eq!(
-            action.controller_ref.as_literal().unwrap(),
-            "TestController"
-        );
-        assert_eq!(action.active.as_literal().unwrap(), &true);
-    }
-
     #[test]
     fn test_controller_assignment() {
         let assignment =
@@ -573,6 +525,51 @@ mod tests {
         assert_eq!(controller, deserialized);
     }
 
+    #[test]
+    fn test_controller_properties_round_trip() {
+        use crate::types::basic::ParameterDeclaration;
+        use crate::types::enums::ParameterType;
+
+        let mut controller = Controller::with_properties(
+            "Driver".to_string(),
+            ControllerType::Movement,
+            Properties {
+                properties: vec![
+                    Property {
+                        name: "esminiController".to_string(),
+                        value: "InteractiveController".to_string(),
+                    },
+                    Property {
+                        name: "speedFactor".to_string(),
+                        value: "1.2".to_string(),
+                    },
+                ],
+                files: Vec::new(),
+            },
+        );
+        controller.parameter_declarations = Some(ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration {
+                name: Value::Literal("MaxSpeed".to_string()),
+                parameter_type: ParameterType::Double,
+                value: Value::Literal("30.0".to_string()),
+                constraint_groups: Vec::new(),
+            }],
+        });
+
+        let xml = quick_xml::se::to_string(&controller).unwrap();
+        let reparsed: Controller = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(reparsed, controller);
+        assert_eq!(quick_xml::se::to_string(&reparsed).unwrap(), xml);
+
+        assert_eq!(
+            reparsed.get_property("esminiController"),
+            Some("InteractiveController")
+        );
+        assert_eq!(reparsed.get_property("speedFactor"), Some("1.2"));
+        assert_eq!(reparsed.get_property("missing"), None);
+        assert_eq!(Controller::default().get_property("speedFactor"), None);
+    }
+
     #[test]
     fn test_controller_distribution() {
         let distribution = ControllerDistribution::default();
@@ -603,7 +600,6 @@ mod tests {
         let object_controller = ObjectController::default();
         let properties = ControllerProperties::default();
         let activate_action = ActivateControllerAction::default();
-        let override_action = OverrideControllerValueAction::default();
         let assignment = ControllerAssignment::default();
 
         // All defaults should be valid
@@ -611,7 +607,6 @@ mod tests {
         assert!(object_controller.controller.is_some());
         assert!(properties.properties.is_empty());
         assert!(activate_action.controller_ref.as_literal().is_some());
-        assert!(override_action.active.as_literal().is_some());
         assert!(assignment.target_entity.as_literal().is_some());
     }
 
@@ -5,6 +5,9 @@ use crate::types::basic::{OSString, Value};
 use crate::types::distributions::{DistributionSampler, ValidateDistribution};
 use serde::{Deserialize, Serialize};
 
+/// Maximum number of values a [`DistributionRange`] may enumerate
+pub const MAX_RANGE_STEPS: usize = 1_000_000;
+
 /// Container for deterministic parameter distributions (matches XSD Deterministic type)
 /// This version handles interspersed elements by collecting them all in one place
 #[derive(Debug, Clone, PartialEq)]
@@ -120,6 +123,33 @@ impl Deterministic {
     ) -> impl Iterator<Item = &DeterministicMultiParameterDistribution> {
         self.multi_distributions.iter()
     }
+
+    /// Number of parameter combinations the distributions expand to
+    ///
+    /// Each distribution contributes a factor: the number of elements of a
+    /// set, the number of steps of a range, or the number of value sets of a
+    /// multi-parameter distribution. Returns `None` if a factor cannot be
+    /// determined, e.g. for user defined distributions, parameterized ranges
+    /// or ranges above [`MAX_RANGE_STEPS`].
+    pub fn combination_count(&self) -> Option<usize> {
+        let singles = self.single_distributions.iter().map(|dist| {
+            if let Some(set) = &dist.distribution_set {
+                Some(set.elements.len())
+            } else if let Some(range) = &dist.distribution_range {
+                range.value_count().ok()
+            } else {
+                None
+            }
+        });
+        let multis = self
+            .multi_distributions
+            .iter()
+            .map(|dist| Some(dist.distribution_type.parameter_value_sets.len()));
+
+        singles
+            .chain(multis)
+            .try_fold(1usize, |total, count| total.checked_mul(count?))
+    }
 }
 
 /// Wrapper for deterministic parameter distributions
@@ -516,11 +546,93 @@ impl DistributionSampler for DistributionRange {
         }
     }
 
+    /// List every step from the lower limit up to and including the upper limit
+    ///
+    /// Values are rounded to the decimal places of the lower limit and the
+    /// step width, so 0..1 with step 0.1 yields "0.3" rather than
+    /// "0.30000000000000004".
+    fn enumerate(&self) -> Result<Vec<Self::Output>> {
+        let (lower, step, steps) = self.steps()?;
+        // f64 carries at most 15 significant decimal digits
+        let decimals = decimal_places(lower).max(decimal_places(step)).min(15);
+        let scale = 10f64.powi(decimals as i32);
+        Ok((0..=steps)
+            .map(|i| (((lower + i as f64 * step) * scale).round() / scale).to_string())
+            .collect())
+    }
+
     fn is_deterministic(&self) -> bool {
         true
     }
 }
 
+impl DistributionRange {
+    /// Number of values [`enumerate`](DistributionSampler::enumerate) yields,
+    /// computed from the limits without building them
+    pub fn value_count(&self) -> Result<usize> {
+        let (_, _, steps) = self.steps()?;
+        Ok(steps + 1)
+    }
+
+    /// Validate the literal limits and step width
+    ///
+    /// Returns the lower limit, the step width and the number of steps after
+    /// the lower limit. Ranges with more than [`MAX_RANGE_STEPS`] values are
+    /// rejected so that a tiny step width cannot exhaust memory.
+    fn steps(&self) -> Result<(f64, f64, usize)> {
+        let literal = |value: &crate::types::basic::Double, field: &str| {
+            value.as_literal().copied().ok_or_else(|| {
+                crate::error::Error::validation_error(
+                    field,
+                    "Cannot enumerate parameterized range without parameter resolution",
+                )
+            })
+        };
+        let lower = literal(&self.range.lower_limit, "lower_limit")?;
+        let upper = literal(&self.range.upper_limit, "upper_limit")?;
+        let step = self
+            .step_width
+            .as_literal()
+            .and_then(|step| step.parse::<f64>().ok())
+            .ok_or_else(|| {
+                crate::error::Error::validation_error(
+                    "step_width",
+                    "Cannot enumerate range without a literal numeric step width",
+                )
+            })?;
+        if !step.is_finite() || step <= 0.0 {
+            return Err(crate::error::Error::validation_error(
+                "step_width",
+                "Step width must be a positive number",
+            ));
+        }
+        if upper < lower {
+            return Err(crate::error::Error::validation_error(
+                "upper_limit",
+                "Upper limit must not be below the lower limit",
+            ));
+        }
+
+        // Tolerate rounding so that e.g. 0..1 with step 0.1 includes 1
+        let steps = ((upper - lower) / step + 1e-9).floor();
+        if steps.is_nan() || steps >= MAX_RANGE_STEPS as f64 {
+            return Err(crate::error::Error::validation_error(
+                "step_width",
+                &format!("Range must not enumerate more than {MAX_RANGE_STEPS} values"),
+            ));
+        }
+        Ok((lower, step, steps as usize))
+    }
+}
+
+/// Number of digits after the decimal point in the shortest form of a value
+fn decimal_places(value: f64) -> usize {
+    value
+        .to_string()
+        .split_once('.')
+        .map_or(0, |(_, fraction)| fraction.len())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -564,6 +676,59 @@ mod tests {
         assert!(dist_set.is_deterministic());
     }
 
+    #[test]
+    fn test_distribution_set_enumeration() {
+        let dist_set = DistributionSet {
+            elements: ["1", "2", "3"]
+                .iter()
+                .map(|value| DistributionSetElement {
+                    value: Value::Literal(value.to_string()),
+                })
+                .collect(),
+        };
+        assert_eq!(dist_set.enumerate().unwrap(), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_distribution_range_enumeration() {
+        let range = |lower: f64, upper: f64, step: &str| DistributionRange {
+            step_width: Value::Literal(step.to_string()),
+            range: crate::types::basic::Range {
+                lower_limit: Value::Literal(lower),
+                upper_limit: Value::Literal(upper),
+            },
+        };
+
+        let dist_range = range(0.0, 10.0, "2");
+        assert!(dist_range.is_deterministic());
+        assert_eq!(
+            dist_range.enumerate().unwrap(),
+            vec!["0", "2", "4", "6", "8", "10"]
+        );
+        assert_eq!(
+            range(0.0, 1.0, "0.25").enumerate().unwrap(),
+            vec!["0", "0.25", "0.5", "0.75", "1"]
+        );
+        assert_eq!(
+            range(0.0, 1.0, "0.1").enumerate().unwrap(),
+            vec!["0", "0.1", "0.2", "0.3", "0.4", "0.5", "0.6", "0.7", "0.8", "0.9", "1"]
+        );
+        assert_eq!(
+            range(0.05, 0.3, "0.1").enumerate().unwrap(),
+            vec!["0.05", "0.15", "0.25"]
+        );
+
+        assert!(range(0.0, 10.0, "0").enumerate().is_err());
+        assert!(range(10.0, 0.0, "2").enumerate().is_err());
+        assert!(range(0.0, 10.0, "${Step}").enumerate().is_err());
+        assert!(range(0.0, 1000.0, "1e-12").enumerate().is_err());
+        assert!(range(0.0, f64::MAX, "1e-300").enumerate().is_err());
+        assert!(range(0.0, 1.0, "1e-6").enumerate().is_err());
+        assert_eq!(range(0.0, 1.0, "1e-5").enumerate().unwrap().len(), 100_001);
+        assert_eq!(range(0.0, 1.0, "0.1").value_count().unwrap(), 11);
+        assert!(range(0.0, 1000.0, "1e-12").value_count().is_err());
+    }
+
     #[test]
     fn test_parameter_value_set_validation() {
         let valid_set = ParameterValueSet {
@@ -1,7 +1,9 @@
 //! Entity definitions for OpenSCENARIO scenarios
 
+use crate::error::{Error, Result};
 use crate::types::basic::OSString;
 use crate::types::controllers::ObjectController;
+use crate::types::enums::ObjectType;
 use serde::{Deserialize, Serialize};
 
 pub mod axles;
@@ -17,7 +19,7 @@ pub use selection::{
     ExternalObjectReference, ScenarioObjectTemplate, SelectedEntities, TemplateProperties,
     TemplateProperty,
 };
-pub use vehicle::{Properties, Vehicle};
+pub use vehicle::{AxleSummary, Properties, Vehicle};
 
 /// Union type for all entity objects
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -34,7 +36,12 @@ pub enum EntityObject {
 /// This enum wraps typed catalog references to handle the XSD constraint that
 /// only one CatalogReference element can exist per ScenarioObject. The actual
 /// type (vehicle vs pedestrian) is determined at runtime during catalog resolution.
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+///
+/// The XML does not say which kind of entry a reference points to, so a
+/// parsed reference is always the `Vehicle` variant. `Pedestrian` is only
+/// produced by code that knows the kind, such as
+/// [`ScenarioObject::new_pedestrian_catalog_reference`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
 #[serde(untagged)]
 pub enum ScenarioEntityReference {
     /// Vehicle catalog reference
@@ -51,6 +58,22 @@ pub enum ScenarioEntityReference {
     ),
 }
 
+impl<'de> Deserialize<'de> for ScenarioEntityReference {
+    /// Both variants share the same XML shape and the kind of the referenced
+    /// entry cannot be inferred from it, so a parsed reference is always read
+    /// as `Vehicle`. Catalog resolution falls back to the pedestrian catalog
+    /// when the vehicle lookup fails. Reading the element directly instead of
+    /// through the untagged enum buffer keeps the nested `ParameterAssignment`
+    /// list.
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        crate::types::catalogs::references::CatalogReference::deserialize(deserializer)
+            .map(ScenarioEntityReference::Vehicle)
+    }
+}
+
 /// Wrapper for scenario objects containing entity information
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ScenarioObject {
@@ -73,9 +96,13 @@ pub struct ScenarioObject {
     #[serde(rename = "CatalogReference", skip_serializing_if = "Option::is_none")]
     pub entity_catalog_reference: Option<ScenarioEntityReference>,
 
-    /// Object controller configuration (optional)
-    #[serde(rename = "ObjectController", skip_serializing_if = "Option::is_none")]
-    pub object_controller: Option<ObjectController>,
+    /// Controllers of the object, at most one before OpenSCENARIO 1.2
+    #[serde(
+        rename = "ObjectController",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub object_controllers: Vec<ObjectController>,
 }
 
 /// Container for all entities in the scenario
@@ -94,7 +121,7 @@ impl ScenarioObject {
             vehicle: Some(vehicle),
             pedestrian: None,
             entity_catalog_reference: None,
-            object_controller: Some(ObjectController::default()),
+            object_controllers: vec![ObjectController::default()],
         }
     }
 
@@ -105,7 +132,7 @@ impl ScenarioObject {
             vehicle: None,
             pedestrian: Some(pedestrian),
             entity_catalog_reference: None,
-            object_controller: Some(ObjectController::default()),
+            object_controllers: vec![ObjectController::default()],
         }
     }
 
@@ -121,7 +148,7 @@ impl ScenarioObject {
             vehicle: None,
             pedestrian: None,
             entity_catalog_reference: Some(ScenarioEntityReference::Vehicle(catalog_reference)),
-            object_controller: Some(ObjectController::default()),
+            object_controllers: vec![ObjectController::default()],
         }
     }
 
@@ -137,7 +164,7 @@ impl ScenarioObject {
             vehicle: None,
             pedestrian: None,
             entity_catalog_reference: Some(ScenarioEntityReference::Pedestrian(catalog_reference)),
-            object_controller: Some(ObjectController::default()),
+            object_controllers: vec![ObjectController::default()],
         }
     }
 
@@ -169,6 +196,16 @@ impl ScenarioObject {
         }
     }
 
+    /// Get the parameter assignments of the entity catalog reference if present
+    pub fn catalog_parameter_assignments(
+        &self,
+    ) -> Option<&crate::types::catalogs::references::ParameterAssignments> {
+        match self.entity_catalog_reference.as_ref()? {
+            ScenarioEntityReference::Vehicle(r) => r.parameter_assignments.as_ref(),
+            ScenarioEntityReference::Pedestrian(r) => r.parameter_assignments.as_ref(),
+        }
+    }
+
     /// Get the entity object as an enum variant
     pub fn get_entity_object(&self) -> Option<EntityObject> {
         if let Some(vehicle) = &self.vehicle {
@@ -184,6 +221,25 @@ impl ScenarioObject {
     pub fn get_name(&self) -> Option<&str> {
         self.name.as_literal().map(|s| s.as_str())
     }
+
+    /// Get the object type, following catalog references to the referenced kind
+    ///
+    /// Returns `None` if the object has neither an inline definition nor a
+    /// catalog reference. Miscellaneous objects are not modelled on
+    /// `ScenarioObject` yet, so they are never reported.
+    pub fn object_type(&self) -> Option<ObjectType> {
+        if self.vehicle.is_some() {
+            Some(ObjectType::Vehicle)
+        } else if self.pedestrian.is_some() {
+            Some(ObjectType::Pedestrian)
+        } else {
+            match &self.entity_catalog_reference {
+                Some(ScenarioEntityReference::Vehicle(_)) => Some(ObjectType::Vehicle),
+                Some(ScenarioEntityReference::Pedestrian(_)) => Some(ObjectType::Pedestrian),
+                None => None,
+            }
+        }
+    }
 }
 
 impl Entities {
@@ -192,6 +248,26 @@ impl Entities {
         Self::default()
     }
 
+    /// Create an entities container with one scenario object per named vehicle
+    pub fn from_vehicles(vehicles: impl IntoIterator<Item = (String, Vehicle)>) -> Self {
+        Self {
+            scenario_objects: vehicles
+                .into_iter()
+                .map(|(name, vehicle)| ScenarioObject::new_vehicle(name, vehicle))
+                .collect(),
+        }
+    }
+
+    /// Create an entities container with one scenario object per named pedestrian
+    pub fn from_pedestrians(pedestrians: impl IntoIterator<Item = (String, Pedestrian)>) -> Self {
+        Self {
+            scenario_objects: pedestrians
+                .into_iter()
+                .map(|(name, pedestrian)| ScenarioObject::new_pedestrian(name, pedestrian))
+                .collect(),
+        }
+    }
+
     /// Add a scenario object to the entities
     pub fn add_object(&mut self, object: ScenarioObject) {
         self.scenario_objects.push(object);
@@ -203,6 +279,39 @@ impl Entities {
             .iter()
             .find(|obj| obj.get_name() == Some(name))
     }
+
+    /// Rename the scenario object `old` to `new`
+    ///
+    /// Only the object itself is renamed; use `OpenScenario::rename_entity` to
+    /// also update references in the storyboard and count the updated names.
+    pub fn rename_entity(&mut self, old: &str, new: &str) -> Result<()> {
+        if new.is_empty() {
+            return Err(Error::invalid_value(
+                "name",
+                new,
+                "entity names must not be empty",
+            ));
+        }
+        if old != new && self.find_object(new).is_some() {
+            return Err(Error::validation_error(
+                "name",
+                &format!("an entity named '{}' already exists", new),
+            ));
+        }
+
+        let available: Vec<String> = self
+            .scenario_objects
+            .iter()
+            .map(|obj| obj.name.to_string())
+            .collect();
+        let object = self
+            .scenario_objects
+            .iter_mut()
+            .find(|obj| obj.get_name() == Some(old))
+            .ok_or_else(|| Error::entity_not_found(old, &available))?;
+        object.name = OSString::literal(new.to_string());
+        Ok(())
+    }
 }
 
 // ObjectController is now imported from crate::types::controllers
@@ -251,6 +360,35 @@ mod tests {
         assert!(not_found.is_none());
     }
 
+    #[test]
+    fn test_entities_from_vehicles() {
+        let entities = Entities::from_vehicles(
+            ["Ego", "Target1", "Target2"]
+                .iter()
+                .map(|name| (name.to_string(), Vehicle::default())),
+        );
+
+        assert_eq!(entities.scenario_objects.len(), 3);
+        let names: Vec<_> = entities
+            .scenario_objects
+            .iter()
+            .filter_map(|obj| obj.get_name())
+            .collect();
+        assert_eq!(names, ["Ego", "Target1", "Target2"]);
+        assert!(entities
+            .scenario_objects
+            .iter()
+            .all(|obj| obj.vehicle.is_some()));
+
+        let pedestrians =
+            Entities::from_pedestrians(vec![("Walker".to_string(), Pedestrian::default())]);
+        assert!(pedestrians
+            .find_object("Walker")
+            .unwrap()
+            .pedestrian
+            .is_some());
+    }
+
     #[test]
     fn test_entities_serialization() {
         let mut entities = Entities::new();
@@ -264,4 +402,84 @@ mod tests {
         assert!(xml.contains("ScenarioObject"));
         assert!(xml.contains("name=\"TestVehicle\""));
     }
+
+    #[test]
+    fn test_catalog_parameter_assignments() {
+        let xml = r#"<ScenarioObject name="Ego">
+            <CatalogReference catalogName="VehicleCatalog" entryName="car">
+                <ParameterAssignments>
+                    <ParameterAssignment parameterRef="MaxSpeed" value="50"/>
+                </ParameterAssignments>
+            </CatalogReference>
+        </ScenarioObject>"#;
+        let obj: ScenarioObject = quick_xml::de::from_str(xml).unwrap();
+
+        let assignments = obj.catalog_parameter_assignments().unwrap();
+        assert_eq!(assignments.assignments.len(), 1);
+        assert_eq!(
+            assignments.assignments[0]
+                .parameter_ref
+                .as_literal()
+                .unwrap(),
+            "MaxSpeed"
+        );
+        assert_eq!(assignments.assignments[0].value.as_literal().unwrap(), "50");
+
+        let serialized = quick_xml::se::to_string(&obj).unwrap();
+        assert!(serialized.contains("<ParameterAssignments><ParameterAssignment "));
+        assert_eq!(
+            quick_xml::de::from_str::<ScenarioObject>(&serialized).unwrap(),
+            obj
+        );
+
+        let inline = ScenarioObject::new_vehicle("Inline".to_string(), Vehicle::default());
+        assert!(inline.catalog_parameter_assignments().is_none());
+    }
+
+    #[test]
+    fn test_multiple_object_controllers() {
+        let xml = r#"<ScenarioObject name="Ego">
+            <CatalogReference catalogName="VehicleCatalog" entryName="car"/>
+            <ObjectController name="Lateral">
+                <CatalogReference catalogName="ControllerCatalog" entryName="laneKeeping"/>
+            </ObjectController>
+            <ObjectController name="Longitudinal">
+                <CatalogReference catalogName="ControllerCatalog" entryName="acc"/>
+            </ObjectController>
+        </ScenarioObject>"#;
+        let obj: ScenarioObject = quick_xml::de::from_str(xml).unwrap();
+
+        assert_eq!(obj.object_controllers.len(), 2);
+        let entries: Vec<_> = obj
+            .object_controllers
+            .iter()
+            .map(|c| c.catalog_reference.as_ref().unwrap().entry_name.to_string())
+            .collect();
+        assert_eq!(entries, ["laneKeeping", "acc"]);
+
+        let serialized = quick_xml::se::to_string(&obj).unwrap();
+        assert_eq!(serialized.matches("<ObjectController ").count(), 2);
+        assert_eq!(
+            quick_xml::de::from_str::<ScenarioObject>(&serialized).unwrap(),
+            obj
+        );
+
+        // A single controller still parses, and none are written when absent
+        let single = xml.replacen(
+            r#"<ObjectController name="Longitudinal">
+                <CatalogReference catalogName="ControllerCatalog" entryName="acc"/>
+            </ObjectController>"#,
+            "",
+            1,
+        );
+        let obj: ScenarioObject = quick_xml::de::from_str(&single).unwrap();
+        assert_eq!(obj.object_controllers.len(), 1);
+
+        let bare = ScenarioObject {
+            object_controllers: Vec::new(),
+            ..obj
+        };
+        let serialized = quick_xml::se::to_string(&bare).unwrap();
+        assert!(!serialized.contains("ObjectController"));
+    }
 }
@@ -5,6 +5,7 @@ use crate::types::basic::{Double, OSString};
 use crate::types::enums::VehicleCategory;
 use crate::types::geometry::BoundingBox;
 use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
 
 /// Vehicle performance characteristics
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -17,12 +18,29 @@ pub struct Performance {
     pub max_deceleration: Double,
 }
 
+/// Resolved axle geometry of a vehicle, see [`Vehicle::axle_summary`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AxleSummary {
+    /// Longitudinal distance between front and rear axle in meters
+    pub wheelbase: f64,
+    /// Front axle track width in meters
+    pub front_track_width: f64,
+    /// Rear axle track width in meters
+    pub rear_track_width: f64,
+    /// Front wheel diameter in meters
+    pub front_wheel_diameter: f64,
+    /// Rear wheel diameter in meters
+    pub rear_wheel_diameter: f64,
+    /// Largest steering angle of any axle in radians
+    pub max_steering: f64,
+}
+
 /// Vehicle properties container
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct Properties {
     #[serde(rename = "Property", default)]
     pub properties: Vec<Property>,
-    #[serde(rename = "File", default)]
+    #[serde(rename = "File", default, skip_serializing_if = "Vec::is_empty")]
     pub files: Vec<File>,
 }
 
@@ -42,6 +60,28 @@ pub struct File {
     pub filepath: String,
 }
 
+impl File {
+    /// Resolve the file path against a base directory
+    ///
+    /// Relative paths are joined onto `base`, absolute paths are returned
+    /// unchanged. A `file://` scheme prefix is stripped first and the rest is
+    /// read as a plain path: `file:///maps/road.xodr` is absolute, while
+    /// `file://maps/road.xodr` is treated as the relative path
+    /// `maps/road.xodr` even though RFC 8089 reads `maps` as the host.
+    pub fn resolve(&self, base: &Path) -> PathBuf {
+        let path = Path::new(
+            self.filepath
+                .strip_prefix("file://")
+                .unwrap_or(&self.filepath),
+        );
+        if path.is_absolute() {
+            path.to_path_buf()
+        } else {
+            base.join(path)
+        }
+    }
+}
+
 /// Vehicle entity definition
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Vehicle {
@@ -152,6 +192,37 @@ impl Vehicle {
         self.axles.axle_count()
     }
 
+    /// Summarize the axle geometry for vehicle dynamics sanity checks
+    ///
+    /// Values are resolved without parameters, so parameterized axle
+    /// attributes produce a parameter error. The rear axle is always present,
+    /// but a wheelbase cannot be computed without a front axle, so vehicles
+    /// without one (e.g. trailers) are rejected.
+    pub fn axle_summary(&self) -> crate::error::Result<AxleSummary> {
+        let params = std::collections::HashMap::new();
+        let front = self.axles.front_axle.as_ref().ok_or_else(|| {
+            crate::error::Error::validation_error(
+                "FrontAxle",
+                "an axle summary requires both a front and a rear axle",
+            )
+        })?;
+        let rear = &self.axles.rear_axle;
+
+        let mut max_steering = 0.0_f64;
+        for axle in self.axles.all_axles() {
+            max_steering = max_steering.max(axle.max_steering.resolve(&params)?);
+        }
+
+        Ok(AxleSummary {
+            wheelbase: self.axles.wheelbase(&params)?,
+            front_track_width: front.track_width.resolve(&params)?,
+            rear_track_width: rear.track_width.resolve(&params)?,
+            front_wheel_diameter: front.wheel_diameter.resolve(&params)?,
+            rear_wheel_diameter: rear.wheel_diameter.resolve(&params)?,
+            max_steering,
+        })
+    }
+
     /// Calculate the vehicle's footprint area
     pub fn footprint_area(
         &self,
@@ -266,6 +337,44 @@ mod tests {
         assert!(car.is_steerable(&params).unwrap());
     }
 
+    #[test]
+    fn test_vehicle_axle_summary() {
+        use crate::types::entities::axles::Axle;
+
+        let axle = |max_steering: f64, diameter: f64, track: f64, x: f64| Axle {
+            max_steering: Double::literal(max_steering),
+            wheel_diameter: Double::literal(diameter),
+            track_width: Double::literal(track),
+            position_x: Double::literal(x),
+            position_z: Double::literal(diameter / 2.0),
+        };
+
+        let mut vehicle = Vehicle::new_car("SummaryCar".to_string());
+        vehicle.axles = Axles {
+            front_axle: Some(axle(0.5, 0.7, 1.6, 2.9)),
+            rear_axle: axle(0.0, 0.72, 1.55, 0.0),
+            additional_axles: vec![],
+        };
+
+        let summary = vehicle.axle_summary().unwrap();
+        assert_eq!(summary.wheelbase, 2.9);
+        assert_eq!(summary.front_track_width, 1.6);
+        assert_eq!(summary.rear_track_width, 1.55);
+        assert_eq!(summary.front_wheel_diameter, 0.7);
+        assert_eq!(summary.rear_wheel_diameter, 0.72);
+        assert_eq!(summary.max_steering, 0.5);
+
+        vehicle.axles.front_axle = None;
+        let error = vehicle.axle_summary().unwrap_err();
+        assert!(error.to_string().contains("FrontAxle"));
+
+        vehicle.axles.front_axle = Some(Axle {
+            max_steering: Double::parameter("MaxSteer".to_string()),
+            ..axle(0.5, 0.7, 1.6, 2.9)
+        });
+        assert!(vehicle.axle_summary().is_err());
+    }
+
     #[test]
     fn test_vehicle_footprint_area() {
         use std::collections::HashMap;
@@ -276,4 +385,56 @@ mod tests {
         let area = car.footprint_area(&params).unwrap();
         assert!(area > 0.0);
     }
+
+    #[test]
+    fn test_file_resolve() {
+        let base = Path::new("scenarios/highway");
+        let file = |filepath: &str| File {
+            filepath: filepath.to_string(),
+        };
+
+        assert_eq!(
+            file("../models/car.osgb").resolve(base),
+            PathBuf::from("scenarios/highway/../models/car.osgb")
+        );
+
+        let absolute = std::env::temp_dir().join("road.xodr");
+        let absolute_str = absolute.to_str().unwrap();
+        assert_eq!(file(absolute_str).resolve(base), absolute);
+
+        assert_eq!(
+            file(&format!("file://{}", absolute_str)).resolve(base),
+            absolute
+        );
+        assert_eq!(
+            file("file://maps/road.xodr").resolve(base),
+            PathBuf::from("scenarios/highway/maps/road.xodr")
+        );
+    }
+
+    #[test]
+    fn test_vehicle_properties_with_files_roundtrip() {
+        let mut vehicle = Vehicle::new_car("ModelCar".to_string());
+        vehicle.properties = Some(Properties {
+            properties: vec![Property {
+                name: "color".to_string(),
+                value: "red".to_string(),
+            }],
+            files: vec![File {
+                filepath: "models/car.osgb".to_string(),
+            }],
+        });
+
+        let xml = quick_xml::se::to_string(&vehicle).unwrap();
+        assert!(xml.contains(r#"<Property name="color" value="red"/>"#));
+        assert!(xml.contains(r#"<File filepath="models/car.osgb"/>"#));
+
+        let deserialized: Vehicle = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, vehicle);
+
+        // Empty file lists are omitted
+        vehicle.properties.as_mut().unwrap().files.clear();
+        let xml = quick_xml::se::to_string(&vehicle).unwrap();
+        assert!(!xml.contains("<File"));
+    }
 }
@@ -98,12 +98,16 @@ pub enum TriggeringEntitiesRule {
 /// Priority level for events and actions
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum Priority {
+    /// Deprecated since OpenSCENARIO 1.2, use [`Priority::Override`]
     #[serde(rename = "overwrite")]
     Overwrite,
+    /// Stop all running events in the maneuver and start this one
     #[serde(rename = "override")]
     Override,
+    /// Run alongside any events already running in the maneuver
     #[serde(rename = "parallel")]
     Parallel,
+    /// Do not start while other events in the maneuver are running
     #[serde(rename = "skip")]
     Skip,
 }
@@ -275,6 +279,24 @@ impl FromStr for VehicleCategory {
     }
 }
 
+impl Rule {
+    /// Evaluate `lhs <rule> rhs`
+    ///
+    /// Equality is exact, so callers comparing computed values should round
+    /// or use an ordered rule. Any comparison involving NaN is false, except
+    /// `notEqualTo`.
+    pub fn compare(&self, lhs: f64, rhs: f64) -> bool {
+        match self {
+            Rule::EqualTo => lhs == rhs,
+            Rule::GreaterThan => lhs > rhs,
+            Rule::LessThan => lhs < rhs,
+            Rule::GreaterOrEqual => lhs >= rhs,
+            Rule::LessOrEqual => lhs <= rhs,
+            Rule::NotEqualTo => lhs != rhs,
+        }
+    }
+}
+
 impl fmt::Display for Rule {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -305,6 +327,61 @@ impl FromStr for Rule {
     }
 }
 
+impl ParameterType {
+    /// Whether a literal value is valid for this parameter type
+    ///
+    /// Follows the XSD lexical forms: booleans are `true`, `false`, `1` or `0`,
+    /// and dateTime values must look like `2021-12-10T11:00:00` (fractional
+    /// seconds and a timezone suffix are allowed). Strings accept anything.
+    pub fn accepts(&self, value: &str) -> bool {
+        let value = value.trim();
+        match self {
+            ParameterType::Boolean => matches!(value, "true" | "false" | "1" | "0"),
+            ParameterType::DateTime => is_date_time(value),
+            ParameterType::Double => value.parse::<f64>().is_ok(),
+            ParameterType::Int => value.parse::<i32>().is_ok(),
+            ParameterType::String => true,
+            ParameterType::UnsignedInt => value.parse::<u32>().is_ok(),
+            ParameterType::UnsignedShort => value.parse::<u16>().is_ok(),
+        }
+    }
+}
+
+/// Check the `YYYY-MM-DDThh:mm:ss` prefix of an xsd:dateTime value
+fn is_date_time(value: &str) -> bool {
+    let bytes = value.as_bytes();
+    if bytes.len() < 19 {
+        return false;
+    }
+    let digits = |range: std::ops::Range<usize>| bytes[range].iter().all(u8::is_ascii_digit);
+    digits(0..4)
+        && bytes[4] == b'-'
+        && digits(5..7)
+        && bytes[7] == b'-'
+        && digits(8..10)
+        && bytes[10] == b'T'
+        && digits(11..13)
+        && bytes[13] == b':'
+        && digits(14..16)
+        && bytes[16] == b':'
+        && digits(17..19)
+}
+
+impl fmt::Display for ParameterType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ParameterType::Boolean => "boolean",
+            ParameterType::DateTime => "dateTime",
+            ParameterType::Double => "double",
+            ParameterType::Int => "int",
+            ParameterType::String => "string",
+            ParameterType::UnsignedInt => "unsignedInt",
+            ParameterType::UnsignedShort => "unsignedShort",
+        };
+        write!(f, "{}", s)
+    }
+}
+
 impl fmt::Display for ConditionEdge {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -403,6 +480,32 @@ impl FromStr for RelativeDistanceType {
     }
 }
 
+impl fmt::Display for CoordinateSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            CoordinateSystem::Entity => "entity",
+            CoordinateSystem::Lane => "lane",
+            CoordinateSystem::Road => "road",
+            CoordinateSystem::Trajectory => "trajectory",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+impl FromStr for CoordinateSystem {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "entity" => Ok(CoordinateSystem::Entity),
+            "lane" => Ok(CoordinateSystem::Lane),
+            "road" => Ok(CoordinateSystem::Road),
+            "trajectory" => Ok(CoordinateSystem::Trajectory),
+            _ => Err(format!("Invalid coordinate system: {}", s)),
+        }
+    }
+}
+
 impl fmt::Display for FollowingMode {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         let s = match self {
@@ -873,15 +976,17 @@ pub enum LightMode {
 }
 
 /// Automatic gear type enumeration
+///
+/// The spelled-out names are accepted for documents written by older versions.
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AutomaticGearType {
-    #[serde(rename = "n")]
+    #[serde(rename = "n", alias = "neutral")]
     Neutral,
-    #[serde(rename = "p")]
+    #[serde(rename = "p", alias = "park")]
     Park,
-    #[serde(rename = "r")]
+    #[serde(rename = "r", alias = "reverse")]
     Reverse,
-    #[serde(rename = "d")]
+    #[serde(rename = "d", alias = "drive")]
     Drive,
 }
 
@@ -1491,6 +1596,48 @@ mod tests {
         assert_eq!(Rule::GreaterThan.to_string(), "greaterThan");
     }
 
+    #[test]
+    fn test_parameter_type_accepts() {
+        assert!(ParameterType::Double.accepts("-3.5e2"));
+        assert!(!ParameterType::Double.accepts("fast"));
+        assert!(ParameterType::Boolean.accepts("1"));
+        assert!(!ParameterType::Boolean.accepts("yes"));
+        assert!(!ParameterType::UnsignedShort.accepts("70000"));
+        assert!(ParameterType::DateTime.accepts("2021-12-10T11:00:00+01:00"));
+        assert!(!ParameterType::DateTime.accepts("2021-12-10"));
+        assert_eq!(ParameterType::UnsignedInt.to_string(), "unsignedInt");
+    }
+
+    #[test]
+    fn test_rule_compare() {
+        assert!(Rule::EqualTo.compare(2.0, 2.0));
+        assert!(!Rule::EqualTo.compare(2.0, 2.5));
+
+        assert!(Rule::GreaterThan.compare(3.0, 2.0));
+        assert!(!Rule::GreaterThan.compare(2.0, 2.0));
+
+        assert!(Rule::LessThan.compare(1.0, 2.0));
+        assert!(!Rule::LessThan.compare(2.0, 2.0));
+
+        assert!(Rule::GreaterOrEqual.compare(2.0, 2.0));
+        assert!(Rule::GreaterOrEqual.compare(3.0, 2.0));
+        assert!(!Rule::GreaterOrEqual.compare(1.0, 2.0));
+
+        assert!(Rule::LessOrEqual.compare(2.0, 2.0));
+        assert!(Rule::LessOrEqual.compare(1.0, 2.0));
+        assert!(!Rule::LessOrEqual.compare(3.0, 2.0));
+
+        assert!(Rule::NotEqualTo.compare(1.0, 2.0));
+        assert!(!Rule::NotEqualTo.compare(2.0, 2.0));
+    }
+
+    #[test]
+    fn test_rule_compare_nan() {
+        assert!(!Rule::EqualTo.compare(f64::NAN, f64::NAN));
+        assert!(!Rule::GreaterOrEqual.compare(f64::NAN, 0.0));
+        assert!(Rule::NotEqualTo.compare(f64::NAN, 0.0));
+    }
+
     #[test]
     fn test_misc_object_category_display() {
         assert_eq!(MiscObjectCategory::Barrier.to_string(), "barrier");
@@ -8,6 +8,8 @@
 //! - Integration with rendering and physics systems
 //!
 use crate::types::basic::{Boolean, OSString};
+use crate::types::enums::LightMode;
+use chrono::Timelike;
 use serde::{Deserialize, Serialize};
 
 pub mod road;
@@ -49,6 +51,47 @@ impl Default for Environment {
     }
 }
 
+/// First hour of the night, inclusive
+const NIGHT_START_HOUR: u32 = 20;
+/// First hour of the day after the night, inclusive
+const NIGHT_END_HOUR: u32 = 6;
+
+impl Environment {
+    /// Whether the scenario takes place at night, judged by the time of day
+    ///
+    /// Night is taken to be from 20:00 until 06:00. Returns `None` when the
+    /// `dateTime` attribute cannot be parsed.
+    pub fn is_night(&self) -> Option<bool> {
+        self.time_of_day
+            .hour()
+            .map(|hour| !(NIGHT_END_HOUR..NIGHT_START_HOUR).contains(&hour))
+    }
+
+    /// Street and vehicle light mode suited to the time of day
+    ///
+    /// Lights are `On` at night and `Off` during the day.
+    pub fn light_mode(&self) -> Option<LightMode> {
+        self.is_night()
+            .map(|night| if night { LightMode::On } else { LightMode::Off })
+    }
+}
+
+impl TimeOfDay {
+    /// Hour of the day (0-23) of the `dateTime` attribute
+    ///
+    /// Accepts RFC 3339 timestamps as well as the zone-less
+    /// `YYYY-MM-DDTHH:MM:SS` form commonly found in scenario files. Returns
+    /// `None` if the value cannot be parsed.
+    pub fn hour(&self) -> Option<u32> {
+        if let Ok(parsed) = chrono::DateTime::parse_from_rfc3339(&self.date_time) {
+            return Some(parsed.hour());
+        }
+        chrono::NaiveDateTime::parse_from_str(&self.date_time, "%Y-%m-%dT%H:%M:%S%.f")
+            .ok()
+            .map(|parsed| parsed.hour())
+    }
+}
+
 impl Default for TimeOfDay {
     fn default() -> Self {
         Self {
@@ -89,6 +132,21 @@ mod tests {
         assert!(!environment.time_of_day.animation.as_literal().unwrap());
     }
 
+    #[test]
+    fn test_environment_is_night() {
+        let mut environment = Environment::default();
+        environment.time_of_day.date_time = "2021-12-10T23:00:00".to_string();
+        assert_eq!(environment.is_night(), Some(true));
+        assert_eq!(environment.light_mode(), Some(LightMode::On));
+
+        environment.time_of_day.date_time = "2021-12-10T12:00:00+01:00".to_string();
+        assert_eq!(environment.is_night(), Some(false));
+        assert_eq!(environment.light_mode(), Some(LightMode::Off));
+
+        environment.time_of_day.date_time = "noon".to_string();
+        assert_eq!(environment.is_night(), None);
+    }
+
     #[test]
     fn test_time_of_day_serialization() {
         let time_of_day = TimeOfDay {
@@ -1,6 +1,6 @@
 //! Basic geometric shapes for OpenSCENARIO
 
-use crate::error::Result;
+use crate::error::{Error, Result};
 use crate::types::basic::Double;
 use crate::types::positions::Position;
 use serde::{Deserialize, Serialize};
@@ -379,6 +379,44 @@ pub struct Vertex {
     pub position: Position,
 }
 
+impl Polyline {
+    /// Length of the polyline through its vertices in order
+    ///
+    /// Vertices must be given as world positions; a missing z coordinate is
+    /// treated as 0. Parameterized coordinates fail to resolve; use
+    /// [`length_with`](Self::length_with) to supply parameter values.
+    pub fn length(&self) -> Result<f64> {
+        self.length_with(&HashMap::new())
+    }
+
+    /// Length of the polyline with coordinates resolved against `params`
+    pub fn length_with(&self, params: &HashMap<String, String>) -> Result<f64> {
+        let points = self
+            .vertices
+            .iter()
+            .map(|vertex| {
+                let world = vertex.position.world_position.as_ref().ok_or_else(|| {
+                    Error::validation_error("Vertex", "polyline length requires world positions")
+                })?;
+                let z = match &world.z {
+                    Some(z) => z.resolve(params)?,
+                    None => 0.0,
+                };
+                Ok([world.x.resolve(params)?, world.y.resolve(params)?, z])
+            })
+            .collect::<Result<Vec<[f64; 3]>>>()?;
+
+        Ok(points
+            .windows(2)
+            .map(|pair| {
+                let [x1, y1, z1] = pair[0];
+                let [x2, y2, z2] = pair[1];
+                ((x2 - x1).powi(2) + (y2 - y1).powi(2) + (z2 - z1).powi(2)).sqrt()
+            })
+            .sum())
+    }
+}
+
 impl Default for Shape {
     fn default() -> Self {
         Self {
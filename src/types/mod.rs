@@ -91,8 +91,9 @@ pub use distributions::{
 // Re-export controller types
 pub use controllers::{
     ActivateControllerAction, Controller, ControllerAssignment, ControllerDistribution,
-    ControllerProperties, ObjectController, OverrideControllerValueAction,
+    ControllerProperties, ObjectController,
 };
+pub use actions::OverrideControllerValueAction;
 
 // Re-export catalog location types
 pub use catalogs::locations::{
@@ -112,7 +113,7 @@ pub use conditions::{
 pub use routing::{Route, RouteRef, Waypoint};
 
 // Re-export entity types
-pub use entities::{Axle, Axles, Entities, Pedestrian, ScenarioObject, Vehicle};
+pub use entities::{Axle, AxleSummary, Axles, Entities, Pedestrian, ScenarioObject, Vehicle};
 
 /// Common trait for types that support validation
 ///
@@ -222,7 +223,7 @@ impl<T> Validate for Value<T> {
 
 impl<T: Clone> Resolve<T> for Value<T>
 where
-    T: std::str::FromStr,
+    T: basic::LenientParse,
     T::Err: std::fmt::Display,
 {
     fn resolve(&self, ctx: &ParameterContext) -> crate::Result<T> {
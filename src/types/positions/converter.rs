@@ -0,0 +1,320 @@
+//! Conversion of road and lane positions to world coordinates
+//!
+//! This file contains:
+//! - ReferenceLine trait through which callers supply road network geometry
+//! - PositionConverter for turning world/road/lane positions into WorldPosition
+//! - NoRoadNetwork placeholder geometry for world-only (identity) conversion
+//!
+//! The crate does not parse OpenDRIVE, so anything that depends on road geometry
+//! is delegated to a caller-provided [`ReferenceLine`] implementation.
+//!
+use crate::error::{Error, Result};
+use crate::types::basic::Double;
+use crate::types::positions::road::Orientation;
+use crate::types::positions::{LanePosition, Position, RoadPosition, WorldPosition};
+use std::collections::HashMap;
+
+/// Pose of a road reference line at a given s-coordinate
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReferencePose {
+    /// X coordinate in meters
+    pub x: f64,
+    /// Y coordinate in meters
+    pub y: f64,
+    /// Z coordinate in meters
+    pub z: f64,
+    /// Heading of the reference line in radians
+    pub heading: f64,
+}
+
+/// Road network geometry used to place road and lane coordinates in the world
+///
+/// Implement this on top of an OpenDRIVE reader (or any other map source) to
+/// enable [`PositionConverter`] for `RoadPosition` and `LanePosition`.
+pub trait ReferenceLine {
+    /// Evaluate the reference line of `road_id` at `s`
+    ///
+    /// Returns `None` if the road is unknown or `s` is outside its length.
+    fn pose_at(&self, road_id: &str, s: f64) -> Option<ReferencePose>;
+
+    /// Lateral offset (t) of the centre of `lane_id` on `road_id` at `s`
+    ///
+    /// Returns `None` if the lane does not exist at that s-coordinate.
+    fn lane_center_offset(&self, road_id: &str, lane_id: &str, s: f64) -> Option<f64>;
+}
+
+/// Placeholder geometry for scenarios without a road network
+///
+/// Every lookup fails, so a converter using it only accepts world positions.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoRoadNetwork;
+
+impl ReferenceLine for NoRoadNetwork {
+    fn pose_at(&self, _road_id: &str, _s: f64) -> Option<ReferencePose> {
+        None
+    }
+
+    fn lane_center_offset(&self, _road_id: &str, _lane_id: &str, _s: f64) -> Option<f64> {
+        None
+    }
+}
+
+/// Converts positions to absolute world coordinates
+///
+/// World positions are passed through unchanged. Road and lane positions are
+/// projected from the reference line: the point lies `t` meters to the left of
+/// the reference line, and its heading is the road heading plus any relative
+/// orientation given on the position.
+#[derive(Debug, Clone, Default)]
+pub struct PositionConverter<R = NoRoadNetwork> {
+    reference_line: R,
+    parameters: HashMap<String, String>,
+}
+
+impl PositionConverter<NoRoadNetwork> {
+    /// Create a converter that only accepts world positions
+    pub fn identity() -> Self {
+        Self::default()
+    }
+}
+
+impl<R: ReferenceLine> PositionConverter<R> {
+    /// Create a converter backed by the given road geometry
+    pub fn new(reference_line: R) -> Self {
+        Self {
+            reference_line,
+            parameters: HashMap::new(),
+        }
+    }
+
+    /// Use these parameter values to resolve `${param}` coordinates
+    pub fn with_parameters(mut self, parameters: HashMap<String, String>) -> Self {
+        self.parameters = parameters;
+        self
+    }
+
+    /// Convert a position to world coordinates
+    ///
+    /// Supports `WorldPosition`, `RoadPosition` and `LanePosition`. Relative,
+    /// trajectory and geographic positions need scenario state and are rejected.
+    pub fn to_world(&self, position: &Position) -> Result<WorldPosition> {
+        if let Some(world) = &position.world_position {
+            Ok(world.clone())
+        } else if let Some(road) = &position.road_position {
+            self.road_to_world(road)
+        } else if let Some(lane) = &position.lane_position {
+            self.lane_to_world(lane)
+        } else {
+            Err(Error::validation_error(
+                "Position",
+                "only WorldPosition, RoadPosition and LanePosition can be converted to world coordinates",
+            ))
+        }
+    }
+
+    /// Convert a road position to world coordinates
+    pub fn road_to_world(&self, road: &RoadPosition) -> Result<WorldPosition> {
+        let road_id = road.road_id.resolve(&self.parameters)?;
+        let s = road.s.resolve(&self.parameters)?;
+        let t = road.t.resolve(&self.parameters)?;
+        let pose = self.pose_at(&road_id, s)?;
+        self.project(pose, t, road.orientation.as_ref())
+    }
+
+    /// Convert a lane position to world coordinates
+    pub fn lane_to_world(&self, lane: &LanePosition) -> Result<WorldPosition> {
+        let road_id = lane.road_id.resolve(&self.parameters)?;
+        let lane_id = lane.lane_id.resolve(&self.parameters)?;
+        let s = lane.s.resolve(&self.parameters)?;
+        let offset = lane.offset.resolve(&self.parameters)?;
+        let pose = self.pose_at(&road_id, s)?;
+        let center = self
+            .reference_line
+            .lane_center_offset(&road_id, &lane_id, s)
+            .ok_or_else(|| {
+                Error::validation_error(
+                    "LanePosition",
+                    &format!(
+                        "lane '{}' not found on road '{}' at s={}",
+                        lane_id, road_id, s
+                    ),
+                )
+            })?;
+        self.project(pose, center + offset, lane.orientation.as_ref())
+    }
+
+    fn pose_at(&self, road_id: &str, s: f64) -> Result<ReferencePose> {
+        self.reference_line.pose_at(road_id, s).ok_or_else(|| {
+            Error::validation_error(
+                "RoadPosition",
+                &format!("road '{}' has no reference line at s={}", road_id, s),
+            )
+        })
+    }
+
+    fn project(
+        &self,
+        pose: ReferencePose,
+        t: f64,
+        orientation: Option<&Orientation>,
+    ) -> Result<WorldPosition> {
+        let angle = |value: Option<&Double>| -> Result<Option<f64>> {
+            value.map(|v| v.resolve(&self.parameters)).transpose()
+        };
+        let (h, p, r) = match orientation {
+            Some(o) => (
+                angle(o.h.as_ref())?,
+                angle(o.p.as_ref())?,
+                angle(o.r.as_ref())?,
+            ),
+            None => (None, None, None),
+        };
+
+        Ok(WorldPosition {
+            x: Double::literal(pose.x - t * pose.heading.sin()),
+            y: Double::literal(pose.y + t * pose.heading.cos()),
+            z: Some(Double::literal(pose.z)),
+            h: Some(Double::literal(pose.heading + h.unwrap_or(0.0))),
+            p: p.map(Double::literal),
+            r: r.map(Double::literal),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::f64::consts::FRAC_PI_2;
+
+    /// Straight road along +x (road "1") or +y (road "2"), lanes 3.5m wide
+    struct FlatRoad;
+
+    impl ReferenceLine for FlatRoad {
+        fn pose_at(&self, road_id: &str, s: f64) -> Option<ReferencePose> {
+            if !(0.0..=1000.0).contains(&s) {
+                return None;
+            }
+            match road_id {
+                "1" => Some(ReferencePose {
+                    x: s,
+                    y: 0.0,
+                    z: 0.0,
+                    heading: 0.0,
+                }),
+                "2" => Some(ReferencePose {
+                    x: 0.0,
+                    y: s,
+                    z: 0.0,
+                    heading: FRAC_PI_2,
+                }),
+                _ => None,
+            }
+        }
+
+        fn lane_center_offset(&self, _road_id: &str, lane_id: &str, _s: f64) -> Option<f64> {
+            let lane: i32 = lane_id.parse().ok()?;
+            match lane {
+                0 => None,
+                l if l > 0 => Some((l as f64 - 0.5) * 3.5),
+                l => Some((l as f64 + 0.5) * 3.5),
+            }
+        }
+    }
+
+    fn coords(world: &WorldPosition) -> (f64, f64, f64) {
+        (
+            *world.x.as_literal().unwrap(),
+            *world.y.as_literal().unwrap(),
+            *world.h.as_ref().unwrap().as_literal().unwrap(),
+        )
+    }
+
+    #[test]
+    fn test_identity_converter_passes_world_positions_through() {
+        let position = Position::default();
+        let world = PositionConverter::identity().to_world(&position).unwrap();
+        assert_eq!(Some(world), position.world_position);
+    }
+
+    #[test]
+    fn test_identity_converter_rejects_road_positions() {
+        let position = Position {
+            road_position: Some(RoadPosition::new("1".to_string(), 10.0, 0.0)),
+            ..Position::empty()
+        };
+        assert!(PositionConverter::identity().to_world(&position).is_err());
+    }
+
+    #[test]
+    fn test_road_position_on_flat_road() {
+        let converter = PositionConverter::new(FlatRoad);
+
+        let road = RoadPosition::new("1".to_string(), 50.0, 2.0);
+        let (x, y, h) = coords(&converter.road_to_world(&road).unwrap());
+        assert!((x - 50.0).abs() < 1e-9);
+        assert!((y - 2.0).abs() < 1e-9);
+        assert!(h.abs() < 1e-9);
+
+        // Positive t is to the left of a road heading along +y, i.e. towards -x
+        let road = RoadPosition::new("2".to_string(), 20.0, 1.0);
+        let (x, y, h) = coords(&converter.road_to_world(&road).unwrap());
+        assert!((x + 1.0).abs() < 1e-9);
+        assert!((y - 20.0).abs() < 1e-9);
+        assert!((h - FRAC_PI_2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_lane_position_with_offset_and_orientation() {
+        let converter = PositionConverter::new(FlatRoad);
+        let lane = LanePosition::with_orientation(
+            "1".to_string(),
+            "-1".to_string(),
+            100.0,
+            0.25,
+            Orientation {
+                h: Some(Double::literal(0.1)),
+                p: None,
+                r: None,
+            },
+        );
+        let position = Position {
+            lane_position: Some(lane),
+            ..Position::empty()
+        };
+
+        let (x, y, h) = coords(&converter.to_world(&position).unwrap());
+        assert!((x - 100.0).abs() < 1e-9);
+        assert!((y - (-1.75 + 0.25)).abs() < 1e-9);
+        assert!((h - 0.1).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_parameterized_road_position() {
+        let mut road = RoadPosition::new("1".to_string(), 0.0, 0.0);
+        road.s = Double::parameter("StartS".to_string());
+
+        let converter = PositionConverter::new(FlatRoad);
+        assert!(converter.road_to_world(&road).is_err());
+
+        let converter =
+            converter.with_parameters(HashMap::from([("StartS".to_string(), "75".to_string())]));
+        let (x, _, _) = coords(&converter.road_to_world(&road).unwrap());
+        assert!((x - 75.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_unknown_road_and_lane_are_errors() {
+        let converter = PositionConverter::new(FlatRoad);
+        assert!(converter
+            .road_to_world(&RoadPosition::new("9".to_string(), 10.0, 0.0))
+            .is_err());
+        assert!(converter
+            .lane_to_world(&LanePosition::new(
+                "1".to_string(),
+                "0".to_string(),
+                10.0,
+                0.0
+            ))
+            .is_err());
+    }
+}
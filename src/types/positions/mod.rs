@@ -10,11 +10,13 @@
 use crate::types::basic::{Double, OSString};
 use serde::{Deserialize, Serialize};
 
+pub mod converter;
 pub mod relative;
 pub mod road;
 pub mod trajectory;
 pub mod world;
 
+pub use converter::{NoRoadNetwork, PositionConverter, ReferenceLine, ReferencePose};
 pub use relative::RelativeObjectPosition;
 pub use road::{
     LaneCoordinate, LanePosition, Orientation, RelativeLanePosition, RelativeRoadPosition,
@@ -70,6 +72,9 @@ pub struct RelativeWorldPosition {
     pub dy: Double,
     #[serde(rename = "@dz")]
     pub dz: Double,
+    /// Orientation relative to the reference entity
+    #[serde(rename = "Orientation", skip_serializing_if = "Option::is_none")]
+    pub orientation: Option<Orientation>,
 }
 
 // Default implementations
@@ -96,6 +101,7 @@ impl Default for RelativeWorldPosition {
             dx: Double::literal(0.0),
             dy: Double::literal(0.0),
             dz: Double::literal(0.0),
+            orientation: None,
         }
     }
 }
@@ -239,6 +245,30 @@ mod tests {
         assert_eq!(rwp.dx.as_literal().unwrap(), &0.0);
     }
 
+    #[test]
+    fn test_relative_world_position_orientation_roundtrip() {
+        let pos = Position {
+            relative_world_position: Some(RelativeWorldPosition {
+                entity_ref: OSString::literal("Ego".to_string()),
+                dx: Double::literal(-10.0),
+                dy: Double::literal(3.5),
+                dz: Double::literal(0.0),
+                orientation: Some(Orientation::heading(1.57)),
+            }),
+            ..Position::empty()
+        };
+
+        let xml = quick_xml::se::to_string(&pos).unwrap();
+        assert!(xml.contains(r#"<Orientation h="1.57"/>"#));
+        let deserialized: Position = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(deserialized, pos);
+        let relative = deserialized.relative_world_position.unwrap();
+        assert_eq!(
+            relative.orientation.unwrap().h.unwrap().as_literal(),
+            Some(&1.57)
+        );
+    }
+
     #[test]
     fn test_position_xml_roundtrip() {
         let pos = Position::default();
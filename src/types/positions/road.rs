@@ -7,23 +7,23 @@
 //! - RelativeLanePosition for lane-relative positioning
 //! - Road network integration and coordinate validation
 //!
-use crate::types::basic::{Double, Int, OSString};
+use crate::types::basic::{is_none_or_zero, Double, Int, OSString, Value};
 use serde::{Deserialize, Serialize};
 
 /// Orientation definition for positions
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[derive(Default)]
 pub struct Orientation {
-    /// Heading angle (rotation around z-axis)
-    #[serde(rename = "@h", skip_serializing_if = "Option::is_none")]
+    /// Heading angle (rotation around z-axis), omitted when 0
+    #[serde(rename = "@h", skip_serializing_if = "is_none_or_zero")]
     pub h: Option<Double>,
 
-    /// Pitch angle (rotation around y-axis)
-    #[serde(rename = "@p", skip_serializing_if = "Option::is_none")]
+    /// Pitch angle (rotation around y-axis), omitted when 0
+    #[serde(rename = "@p", skip_serializing_if = "is_none_or_zero")]
     pub p: Option<Double>,
 
-    /// Roll angle (rotation around x-axis)
-    #[serde(rename = "@r", skip_serializing_if = "Option::is_none")]
+    /// Roll angle (rotation around x-axis), omitted when 0
+    #[serde(rename = "@r", skip_serializing_if = "is_none_or_zero")]
     pub r: Option<Double>,
 }
 
@@ -62,8 +62,8 @@ pub struct LanePosition {
     #[serde(rename = "@s")]
     pub s: Double,
 
-    /// Offset from lane center
-    #[serde(rename = "@offset")]
+    /// Offset from lane center, omitted when 0
+    #[serde(rename = "@offset", default, skip_serializing_if = "Value::is_zero")]
     pub offset: Double,
 
     /// Orientation relative to lane direction
@@ -106,8 +106,8 @@ pub struct RelativeLanePosition {
     #[serde(rename = "@ds")]
     pub ds: Double,
 
-    /// Offset from lane center
-    #[serde(rename = "@offset")]
+    /// Offset from lane center, omitted when 0
+    #[serde(rename = "@offset", default, skip_serializing_if = "Value::is_zero")]
     pub offset: Double,
 
     /// Orientation relative to lane direction
@@ -438,7 +438,30 @@ mod tests {
         assert!(xml.contains("roadId=\"0\""));
         assert!(xml.contains("laneId=\"-4\""));
         assert!(xml.contains("s=\"5\""));
-        assert!(xml.contains("offset=\"0\""));
+        assert!(!xml.contains("offset"));
+    }
+
+    #[test]
+    fn test_default_offset_and_orientation_round_trip() {
+        let mut pos = LanePosition::new("0".to_string(), "-4".to_string(), 5.0, 0.0);
+        pos.orientation = Some(Orientation {
+            h: Some(Double::literal(1.5)),
+            p: Some(Double::literal(0.0)),
+            r: None,
+        });
+        let xml = quick_xml::se::to_string(&pos).unwrap();
+        assert_eq!(
+            xml,
+            "<LanePosition roadId=\"0\" laneId=\"-4\" s=\"5\"><Orientation h=\"1.5\"/></LanePosition>"
+        );
+
+        let parsed: LanePosition = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed.offset, Double::literal(0.0));
+
+        // Parameterized offsets are kept even if they may resolve to 0
+        pos.offset = Double::parameter("LaneOffset".to_string());
+        let xml = quick_xml::se::to_string(&pos).unwrap();
+        assert!(xml.contains("offset=\"${LaneOffset}\""));
     }
 
     #[test]
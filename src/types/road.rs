@@ -1,9 +1,12 @@
 //! Road network types for OpenSCENARIO
 //!
 //! This module defines types for road network definitions including
-//! logic files and road network references.
+//! logic files, road network references, traffic signal controllers and
+//! the used area of the road network.
 
+use crate::types::actions::traffic::TrafficSignalController;
 use crate::types::basic::OSString;
+use crate::types::positions::Position;
 use serde::{Deserialize, Serialize};
 
 /// Road network definition for scenario
@@ -17,6 +20,14 @@ pub struct RoadNetwork {
     /// Scene graph file reference (optional)
     #[serde(rename = "SceneGraphFile", skip_serializing_if = "Option::is_none")]
     pub scene_graph_file: Option<SceneGraphFile>,
+
+    /// Traffic signal controllers for the signals of the road network (optional)
+    #[serde(rename = "TrafficSignals", skip_serializing_if = "Option::is_none")]
+    pub traffic_signals: Option<TrafficSignals>,
+
+    /// Area of the road network used by the scenario (optional)
+    #[serde(rename = "UsedArea", skip_serializing_if = "Option::is_none")]
+    pub used_area: Option<UsedArea>,
 }
 
 /// Logic file containing road network definition
@@ -35,12 +46,32 @@ pub struct SceneGraphFile {
     pub filepath: OSString,
 }
 
+/// Container for the traffic signal controllers of a road network
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct TrafficSignals {
+    /// Traffic signal controllers
+    #[serde(rename = "TrafficSignalController", default)]
+    pub controllers: Vec<TrafficSignalController>,
+}
+
+/// Area of the road network used by the scenario
+///
+/// The positions span a polygon; the standard requires at least two.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+pub struct UsedArea {
+    /// Positions spanning the used area
+    #[serde(rename = "Position", default)]
+    pub positions: Vec<Position>,
+}
+
 impl RoadNetwork {
     /// Create a new road network with a logic file
     pub fn new(logic_file: LogicFile) -> Self {
         Self {
             logic_file: Some(logic_file),
             scene_graph_file: None,
+            traffic_signals: None,
+            used_area: None,
         }
     }
 
@@ -48,6 +79,41 @@ impl RoadNetwork {
     pub fn from_logic_file_path(filepath: String) -> Self {
         Self::new(LogicFile::new(filepath))
     }
+
+    /// File path of the OpenDRIVE logic file
+    ///
+    /// Returns `None` if there is no logic file or its path is parameterized.
+    pub fn logic_file_path(&self) -> Option<&str> {
+        self.logic_file
+            .as_ref()
+            .and_then(|file| file.filepath.as_literal())
+            .map(String::as_str)
+    }
+
+    /// File path of the scene graph file
+    ///
+    /// Returns `None` if there is no scene graph file or its path is parameterized.
+    pub fn scene_graph_file_path(&self) -> Option<&str> {
+        self.scene_graph_file
+            .as_ref()
+            .and_then(|file| file.filepath.as_literal())
+            .map(String::as_str)
+    }
+
+    /// Add a traffic signal controller to the road network
+    pub fn with_traffic_signal_controller(mut self, controller: TrafficSignalController) -> Self {
+        self.traffic_signals
+            .get_or_insert_with(TrafficSignals::default)
+            .controllers
+            .push(controller);
+        self
+    }
+
+    /// Set the area of the road network used by the scenario
+    pub fn with_used_area(mut self, positions: Vec<Position>) -> Self {
+        self.used_area = Some(UsedArea { positions });
+        self
+    }
 }
 
 impl LogicFile {
@@ -124,5 +190,65 @@ mod tests {
         assert!(xml.contains("RoadNetwork"));
         assert!(xml.contains("LogicFile"));
         assert!(xml.contains("filepath=\"test.xodr\""));
+        assert!(!xml.contains("TrafficSignals"));
+        assert!(!xml.contains("UsedArea"));
+    }
+
+    #[test]
+    fn test_road_network_file_paths_from_scenario() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OpenSCENARIO>
+  <FileHeader revMajor="1" revMinor="2" date="2024-01-01T00:00:00" description="Map" author="Test"/>
+  <CatalogLocations/>
+  <RoadNetwork>
+    <LogicFile filepath="map.xodr"/>
+    <SceneGraphFile filepath="map.osgb"/>
+  </RoadNetwork>
+  <Entities/>
+  <Storyboard>
+    <Init><Actions/></Init>
+  </Storyboard>
+</OpenSCENARIO>"#;
+
+        let scenario = crate::parse_from_str(xml).unwrap();
+        let road_network = scenario.road_network.unwrap();
+        assert_eq!(road_network.logic_file_path(), Some("map.xodr"));
+        assert_eq!(road_network.scene_graph_file_path(), Some("map.osgb"));
+
+        let mut parameterized = RoadNetwork::from_logic_file_path("map.xodr".to_string());
+        parameterized.logic_file.as_mut().unwrap().filepath =
+            OSString::parameter("MapFile".to_string());
+        assert_eq!(parameterized.logic_file_path(), None);
+        assert_eq!(parameterized.scene_graph_file_path(), None);
+    }
+
+    #[test]
+    fn test_road_network_traffic_signals_roundtrip() {
+        use crate::types::actions::traffic::Phase;
+        use crate::types::positions::WorldPosition;
+
+        let corner = |x: f64, y: f64| Position {
+            world_position: Some(WorldPosition::new(x, y)),
+            ..Position::empty()
+        };
+
+        let controller = TrafficSignalController::new("Junction1")
+            .with_delay(2.0)
+            .add_phase(Phase::new("Go", 20.0).add_signal_state("101", "off;off;on"))
+            .add_phase(Phase::new("Stop", 30.0).add_signal_state("101", "on;off;off"));
+        let road_network = RoadNetwork::from_logic_file_path("test.xodr".to_string())
+            .with_traffic_signal_controller(controller)
+            .with_used_area(vec![corner(0.0, 0.0), corner(200.0, 100.0)]);
+
+        let xml = quick_xml::se::to_string(&road_network).unwrap();
+        assert!(xml.contains("<TrafficSignals><TrafficSignalController"));
+        assert!(xml.contains("<UsedArea><Position>"));
+
+        let parsed: RoadNetwork = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(parsed, road_network);
+        let controllers = &parsed.traffic_signals.unwrap().controllers;
+        assert_eq!(controllers[0].name.as_literal().unwrap(), "Junction1");
+        assert_eq!(controllers[0].phases.len(), 2);
+        assert_eq!(parsed.used_area.unwrap().positions.len(), 2);
     }
 }
@@ -9,7 +9,7 @@
 //!
 use crate::types::basic::{Boolean, Double, OSString};
 use crate::types::enums::RouteStrategy;
-use crate::types::positions::Position;
+use crate::types::positions::{GeographicPosition, Position, RelativeWorldPosition};
 use serde::{Deserialize, Serialize};
 
 /// Simple catalog reference for routes
@@ -37,7 +37,7 @@ pub struct CatalogReference {
 #[serde(rename = "ParameterAssignments")]
 pub struct ParameterAssignments {
     /// List of parameter assignments
-    #[serde(rename = "ParameterAssignment")]
+    #[serde(rename = "ParameterAssignment", default)]
     pub assignments: Vec<ParameterAssignment>,
 }
 
@@ -59,7 +59,7 @@ pub struct ParameterAssignment {
 #[serde(rename = "ParameterDeclarations")]
 pub struct ParameterDeclarations {
     /// List of parameter declarations
-    #[serde(rename = "ParameterDeclaration")]
+    #[serde(rename = "ParameterDeclaration", default)]
     pub parameter_declarations: Vec<ParameterDeclaration>,
 }
 
@@ -79,9 +79,10 @@ pub struct ParameterDeclaration {
     #[serde(rename = "@value")]
     pub value: OSString,
 
-    /// Constraint groups (simplified for now)
+    /// Constraint groups, also accepted as lower-case `constraintGroup`
     #[serde(
         rename = "ConstraintGroup",
+        alias = "constraintGroup",
         skip_serializing_if = "Vec::is_empty",
         default
     )]
@@ -105,6 +106,7 @@ pub enum ParameterType {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ValueConstraintGroup {
     /// List of constraints
+    #[serde(rename = "ValueConstraint", default)]
     pub constraints: Vec<ValueConstraint>,
 }
 
@@ -112,8 +114,10 @@ pub struct ValueConstraintGroup {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct ValueConstraint {
     /// Constraint rule
+    #[serde(rename = "@rule")]
     pub rule: String,
     /// Constraint value
+    #[serde(rename = "@value")]
     pub value: String,
 }
 
@@ -320,7 +324,11 @@ impl Route {
 
     /// Calculate distance between two waypoints
     ///
-    /// This is a simplified implementation using Euclidean distance for WorldPosition.
+    /// This is a simplified implementation using Euclidean distance for WorldPosition
+    /// and great-circle distance for GeographicPosition. Two RelativeWorldPositions
+    /// referencing the same entity use the Euclidean distance between their offsets;
+    /// waypoints relative to different entities need runtime entity poses and fall
+    /// back to the default distance like other unsupported position types.
     /// In a real implementation, this would consider the routing strategy and road network.
     fn calculate_waypoint_distance(&self, wp1: &Waypoint, wp2: &Waypoint) -> crate::Result<f64> {
         // Simplified distance calculation - only handles WorldPosition for now
@@ -339,14 +347,74 @@ impl Route {
                 _ => 0.0, // If z coordinates are missing, assume 2D distance
             };
             Ok((dx * dx + dy * dy + dz * dz).sqrt())
+        } else if let (Some(geo1), Some(geo2)) = (
+            &wp1.position.geographic_position,
+            &wp2.position.geographic_position,
+        ) {
+            Self::geodetic_distance(geo1, geo2)
+        } else if let Some(distance) = Self::relative_world_distance(
+            wp1.position.relative_world_position.as_ref(),
+            wp2.position.relative_world_position.as_ref(),
+        )? {
+            Ok(distance)
         } else {
             // For other position types, return a default distance
             // In a real implementation, this would handle all position types
             Ok(100.0) // Default distance in meters
         }
     }
+
+    /// Distance between two world positions relative to the same entity
+    ///
+    /// Returns `None` if either position is missing or they reference different
+    /// entities, since the distance then depends on where those entities are.
+    fn relative_world_distance(
+        rel1: Option<&RelativeWorldPosition>,
+        rel2: Option<&RelativeWorldPosition>,
+    ) -> crate::Result<Option<f64>> {
+        let (Some(rel1), Some(rel2)) = (rel1, rel2) else {
+            return Ok(None);
+        };
+        let params = std::collections::HashMap::new();
+        if rel1.entity_ref.resolve(&params)? != rel2.entity_ref.resolve(&params)? {
+            return Ok(None);
+        }
+
+        let dx = rel2.dx.resolve(&params)? - rel1.dx.resolve(&params)?;
+        let dy = rel2.dy.resolve(&params)? - rel1.dy.resolve(&params)?;
+        let dz = rel2.dz.resolve(&params)? - rel1.dz.resolve(&params)?;
+        Ok(Some((dx * dx + dy * dy + dz * dz).sqrt()))
+    }
+
+    /// Great-circle distance in meters between two geographic positions
+    ///
+    /// Uses the haversine formula on a spherical Earth. If both positions have a
+    /// height, the height difference is combined with the surface distance.
+    fn geodetic_distance(
+        geo1: &GeographicPosition,
+        geo2: &GeographicPosition,
+    ) -> crate::Result<f64> {
+        let params = std::collections::HashMap::new();
+        let lat1 = geo1.latitude.resolve(&params)?.to_radians();
+        let lat2 = geo2.latitude.resolve(&params)?.to_radians();
+        let dlat = lat2 - lat1;
+        let dlon =
+            (geo2.longitude.resolve(&params)? - geo1.longitude.resolve(&params)?).to_radians();
+
+        let a = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+        let surface = 2.0 * EARTH_MEAN_RADIUS * a.sqrt().min(1.0).asin();
+
+        let dh = match (&geo1.height, &geo2.height) {
+            (Some(h1), Some(h2)) => h2.resolve(&params)? - h1.resolve(&params)?,
+            _ => 0.0, // If heights are missing, use the surface distance only
+        };
+        Ok((surface * surface + dh * dh).sqrt())
+    }
 }
 
+/// Mean Earth radius in meters used for great-circle distances
+const EARTH_MEAN_RADIUS: f64 = 6_371_000.0;
+
 // Implementation methods for Waypoint
 impl Waypoint {
     /// Create a new waypoint with the given position and routing strategy
@@ -405,6 +473,16 @@ impl Waypoint {
         Self::new(position, strategy)
     }
 
+    /// Create a waypoint with a geographic position (degrees)
+    pub fn geographic_position(latitude: f64, longitude: f64, strategy: RouteStrategy) -> Self {
+        let position = Position {
+            geographic_position: Some(GeographicPosition::new(latitude, longitude)),
+            ..Position::empty()
+        };
+
+        Self::new(position, strategy)
+    }
+
     /// Create a waypoint with a relative world position
     pub fn relative_world_position(
         entity_ref: impl Into<String>,
@@ -413,8 +491,6 @@ impl Waypoint {
         dz: f64,
         strategy: RouteStrategy,
     ) -> Self {
-        use crate::types::positions::RelativeWorldPosition;
-
         let mut position = Position::default();
         position.world_position = None;
         position.relative_world_position = Some(RelativeWorldPosition {
@@ -422,6 +498,7 @@ impl Waypoint {
             dx: Double::literal(dx),
             dy: Double::literal(dy),
             dz: Double::literal(dz),
+            orientation: None,
         });
         position.road_position = None;
         position.lane_position = None;
@@ -650,6 +727,32 @@ mod tests {
         assert!(reachability.iter().all(|&x| x)); // All should be reachable in this simple implementation
     }
 
+    #[test]
+    fn test_parameter_declaration_constraint_groups_parse() {
+        let declarations: ParameterDeclarations = quick_xml::de::from_str(
+            r#"<ParameterDeclarations>
+                <ParameterDeclaration name="routeSpeed" parameterType="double" value="50.0"/>
+                <ParameterDeclaration name="routeSide" parameterType="string" value="left">
+                    <ConstraintGroup>
+                        <ValueConstraint rule="equalTo" value="left"/>
+                    </ConstraintGroup>
+                </ParameterDeclaration>
+            </ParameterDeclarations>"#,
+        )
+        .unwrap();
+
+        let [speed, side] = declarations.parameter_declarations.as_slice() else {
+            panic!("expected two declarations");
+        };
+        assert!(speed.constraint_groups.is_empty());
+        assert_eq!(side.constraint_groups[0].constraints[0].rule, "equalTo");
+        assert_eq!(side.constraint_groups[0].constraints[0].value, "left");
+
+        let xml = quick_xml::se::to_string(&declarations).unwrap();
+        let reparsed: ParameterDeclarations = quick_xml::de::from_str(&xml).unwrap();
+        assert_eq!(reparsed, declarations);
+    }
+
     #[test]
     fn test_parameter_declarations_support() {
         let mut declarations = ParameterDeclarations::default();
@@ -688,4 +791,80 @@ mod tests {
             1
         );
     }
+
+    #[test]
+    fn test_geographic_route_distance() {
+        // Paris to London, roughly 343.5 km along a great circle
+        let route = Route::new("GeoRoute", false)
+            .add_waypoint(Waypoint::geographic_position(
+                48.8566,
+                2.3522,
+                RouteStrategy::Shortest,
+            ))
+            .add_waypoint(Waypoint::geographic_position(
+                51.5074,
+                -0.1278,
+                RouteStrategy::Shortest,
+            ));
+
+        let distance = route.total_distance().unwrap();
+        assert!((distance - 343_556.0).abs() < 100.0, "got {}", distance);
+    }
+
+    #[test]
+    fn test_geographic_distance_with_height() {
+        // 0.001 degrees of longitude at the equator is about 111.2 m
+        let mut start = Waypoint::geographic_position(0.0, 0.0, RouteStrategy::Shortest);
+        let mut end = Waypoint::geographic_position(0.0, 0.001, RouteStrategy::Shortest);
+
+        let flat = Route::new("Flat", false)
+            .add_waypoint(start.clone())
+            .add_waypoint(end.clone());
+        assert!((flat.total_distance().unwrap() - 111.195).abs() < 0.01);
+
+        start.position.geographic_position = Some(GeographicPosition::with_height(0.0, 0.0, 0.0));
+        end.position.geographic_position = Some(GeographicPosition::with_height(0.0, 0.001, 100.0));
+        let climbing = Route::new("Climbing", false)
+            .add_waypoint(start)
+            .add_waypoint(end);
+        assert!((climbing.total_distance().unwrap() - 149.547).abs() < 0.01);
+    }
+
+    #[test]
+    fn test_relative_world_route_distance() {
+        let route = Route::new("RelativeRoute", false)
+            .add_waypoint(Waypoint::relative_world_position(
+                "lead",
+                10.0,
+                0.0,
+                0.0,
+                RouteStrategy::Shortest,
+            ))
+            .add_waypoint(Waypoint::relative_world_position(
+                "lead",
+                40.0,
+                40.0,
+                0.0,
+                RouteStrategy::Shortest,
+            ));
+        assert!((route.total_distance().unwrap() - 50.0).abs() < 0.001);
+
+        // Offsets from different entities cannot be compared statically
+        let route = Route::new("CrossEntity", false)
+            .add_waypoint(Waypoint::relative_world_position(
+                "lead",
+                10.0,
+                0.0,
+                0.0,
+                RouteStrategy::Shortest,
+            ))
+            .add_waypoint(Waypoint::relative_world_position(
+                "ego",
+                40.0,
+                40.0,
+                0.0,
+                RouteStrategy::Shortest,
+            ));
+        assert_eq!(route.total_distance().unwrap(), 100.0);
+    }
 }
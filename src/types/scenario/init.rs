@@ -12,6 +12,7 @@ use crate::types::actions::movement::{
     LongitudinalDistanceAction, RoutingAction, SpeedAction, SpeedProfileAction, SynchronizeAction,
     TeleportAction,
 };
+use crate::types::actions::wrappers::{EntityAction, ParameterAction};
 use crate::types::basic::OSString;
 use crate::types::environment::Environment;
 use serde::{Deserialize, Serialize};
@@ -38,7 +39,21 @@ pub struct Actions {
 pub struct GlobalAction {
     #[serde(rename = "EnvironmentAction", skip_serializing_if = "Option::is_none")]
     pub environment_action: Option<EnvironmentAction>,
-    // EntityAction and InfrastructureAction can be added later as Option fields
+    /// Deprecated in OpenSCENARIO 1.2 in favour of VariableAction, kept for compatibility
+    #[serde(
+        rename = "ParameterAction",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub parameter_action: Option<ParameterAction>,
+    /// Adds or deletes an entity
+    #[serde(
+        rename = "EntityAction",
+        skip_serializing_if = "Option::is_none",
+        default
+    )]
+    pub entity_action: Option<EntityAction>,
+    // InfrastructureAction can be added later as an Option field
 }
 
 /// Environment setup action containing complete environment definition
@@ -246,6 +261,8 @@ impl Default for GlobalAction {
     fn default() -> Self {
         Self {
             environment_action: Some(EnvironmentAction::default()),
+            parameter_action: None,
+            entity_action: None,
         }
     }
 }
@@ -289,6 +306,8 @@ mod tests {
                     environment_action: Some(EnvironmentAction {
                         environment: Environment::default(),
                     }),
+                    parameter_action: None,
+                    entity_action: None,
                 }],
                 private_actions: vec![Private::new("Ego")],
             },
@@ -375,6 +394,8 @@ mod tests {
             actions: Actions {
                 global_actions: vec![GlobalAction {
                     environment_action: Some(EnvironmentAction::default()),
+                    parameter_action: None,
+                    entity_action: None,
                 }],
                 private_actions: vec![Private::new("Ego")],
             },
@@ -463,4 +484,24 @@ mod tests {
         };
         assert!(invalid_multiple.validate().is_err());
     }
+
+    #[test]
+    fn test_global_entity_action_roundtrip() {
+        let xml = r#"<GlobalAction><EntityAction entityRef="obstacle"><DeleteEntityAction/></EntityAction></GlobalAction>"#;
+        let action: GlobalAction = quick_xml::de::from_str(xml).unwrap();
+        assert!(action.environment_action.is_none());
+        assert_eq!(
+            action
+                .entity_action
+                .as_ref()
+                .unwrap()
+                .entity_ref
+                .as_literal()
+                .unwrap(),
+            "obstacle"
+        );
+
+        let serialized = quick_xml::se::to_string(&action).unwrap();
+        assert_eq!(serialized, xml);
+    }
 }
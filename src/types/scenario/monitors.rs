@@ -3,7 +3,7 @@
 //! This module contains monitor declaration types for runtime monitoring
 //! and validation of scenario conditions.
 
-use crate::types::basic::OSString;
+use crate::types::basic::{Boolean, OSString};
 use serde::{Deserialize, Serialize};
 
 /// Monitor declarations container
@@ -14,25 +14,22 @@ pub struct MonitorDeclarations {
 }
 
 /// Individual monitor declaration
+///
+/// A monitor is a named boolean flag set by `SetMonitorAction` during
+/// simulation; `value` is its initial state.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct MonitorDeclaration {
     #[serde(rename = "@name")]
     pub name: OSString,
-    #[serde(rename = "@condition")]
-    pub condition: OSString,
-    #[serde(rename = "@frequency", skip_serializing_if = "Option::is_none")]
-    pub frequency: Option<f64>,
-    #[serde(rename = "@enabled", skip_serializing_if = "Option::is_none")]
-    pub enabled: Option<bool>,
+    #[serde(rename = "@value")]
+    pub value: Boolean,
 }
 
 impl Default for MonitorDeclaration {
     fn default() -> Self {
         Self {
             name: OSString::literal("DefaultMonitor".to_string()),
-            condition: OSString::literal("true".to_string()),
-            frequency: None,
-            enabled: Some(true),
+            value: Boolean::literal(true),
         }
     }
 }
@@ -44,31 +41,16 @@ impl MonitorDeclarations {
     }
 
     /// Create with single monitor
-    pub fn with_monitor(name: String, condition: String) -> Self {
+    pub fn with_monitor(name: String, value: bool) -> Self {
         Self {
-            monitor_declarations: vec![MonitorDeclaration {
-                name: OSString::literal(name),
-                condition: OSString::literal(condition),
-                frequency: None,
-                enabled: Some(true),
-            }],
+            monitor_declarations: vec![MonitorDeclaration::new(name, value)],
         }
     }
 
     /// Add a monitor declaration
-    pub fn add_monitor(
-        &mut self,
-        name: String,
-        condition: String,
-        frequency: Option<f64>,
-        enabled: Option<bool>,
-    ) {
-        self.monitor_declarations.push(MonitorDeclaration {
-            name: OSString::literal(name),
-            condition: OSString::literal(condition),
-            frequency,
-            enabled,
-        });
+    pub fn add_monitor(&mut self, name: String, value: bool) {
+        self.monitor_declarations
+            .push(MonitorDeclaration::new(name, value));
     }
 
     /// Check if declarations is empty
@@ -83,33 +65,11 @@ impl MonitorDeclarations {
 }
 
 impl MonitorDeclaration {
-    /// Create new monitor declaration
-    pub fn new(name: String, condition: String) -> Self {
-        Self {
-            name: OSString::literal(name),
-            condition: OSString::literal(condition),
-            frequency: None,
-            enabled: Some(true),
-        }
-    }
-
-    /// Create monitor with frequency
-    pub fn with_frequency(name: String, condition: String, frequency: f64) -> Self {
+    /// Create new monitor declaration with its initial value
+    pub fn new(name: String, value: bool) -> Self {
         Self {
             name: OSString::literal(name),
-            condition: OSString::literal(condition),
-            frequency: Some(frequency),
-            enabled: Some(true),
-        }
-    }
-
-    /// Create disabled monitor
-    pub fn disabled(name: String, condition: String) -> Self {
-        Self {
-            name: OSString::literal(name),
-            condition: OSString::literal(condition),
-            frequency: None,
-            enabled: Some(false),
+            value: Boolean::literal(value),
         }
     }
 }
@@ -124,51 +84,39 @@ mod tests {
         assert!(decls.is_empty());
         assert_eq!(decls.len(), 0);
 
-        let single_monitor =
-            MonitorDeclarations::with_monitor("test_monitor".to_string(), "speed > 50".to_string());
+        let single_monitor = MonitorDeclarations::with_monitor("test_monitor".to_string(), true);
         assert!(!single_monitor.is_empty());
         assert_eq!(single_monitor.len(), 1);
     }
 
     #[test]
     fn test_monitor_declaration_creation() {
-        let basic_monitor =
-            MonitorDeclaration::new("speed_monitor".to_string(), "speed > 50".to_string());
-        assert_eq!(basic_monitor.enabled, Some(true));
-        assert_eq!(basic_monitor.frequency, None);
-
-        let freq_monitor = MonitorDeclaration::with_frequency(
-            "speed_monitor".to_string(),
-            "speed > 50".to_string(),
-            10.0,
-        );
-        assert_eq!(freq_monitor.frequency, Some(10.0));
+        let monitor = MonitorDeclaration::new("speed_monitor".to_string(), true);
+        assert_eq!(monitor.name.as_literal().unwrap(), "speed_monitor");
+        assert_eq!(monitor.value.as_literal(), Some(&true));
 
-        let disabled_monitor =
-            MonitorDeclaration::disabled("debug_monitor".to_string(), "false".to_string());
-        assert_eq!(disabled_monitor.enabled, Some(false));
+        let disabled_monitor = MonitorDeclaration::new("debug_monitor".to_string(), false);
+        assert_eq!(disabled_monitor.value.as_literal(), Some(&false));
     }
 
     #[test]
     fn test_add_monitor() {
         let mut decls = MonitorDeclarations::new();
-        decls.add_monitor(
-            "monitor1".to_string(),
-            "condition1".to_string(),
-            None,
-            Some(true),
+        decls.add_monitor("monitor1".to_string(), true);
+        decls.add_monitor("monitor2".to_string(), false);
+
+        assert_eq!(decls.len(), 2);
+        assert_eq!(
+            decls.monitor_declarations[0].value.as_literal(),
+            Some(&true)
         );
-        decls.add_monitor(
-            "monitor2".to_string(),
-            "condition2".to_string(),
-            Some(5.0),
-            Some(false),
+        assert_eq!(
+            decls.monitor_declarations[1].value.as_literal(),
+            Some(&false)
         );
 
-        assert_eq!(decls.len(), 2);
-        assert_eq!(decls.monitor_declarations[0].frequency, None);
-        assert_eq!(decls.monitor_declarations[1].frequency, Some(5.0));
-        assert_eq!(decls.monitor_declarations[0].enabled, Some(true));
-        assert_eq!(decls.monitor_declarations[1].enabled, Some(false));
+        let xml = quick_xml::se::to_string(&decls.monitor_declarations[1]).unwrap();
+        assert!(xml.contains(r#"name="monitor2""#));
+        assert!(xml.contains(r#"value="false""#));
     }
 }
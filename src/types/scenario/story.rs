@@ -8,8 +8,10 @@
 //! - Actor selection and entity assignment to maneuvers
 //!
 use crate::types::basic::{OSString, UnsignedInt};
+use crate::types::catalogs::references::ManeuverCatalogReference;
 use crate::types::enums::Priority;
 use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 
 // Import the real Trigger from triggers module
 use super::triggers::Trigger;
@@ -66,9 +68,6 @@ pub struct StoryPrivateAction {
     pub trailer_action: Option<crate::types::actions::TrailerAction>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
-pub struct CatalogReference;
-
 /// Story definition with parameter scope and act sequences
 ///
 /// A Story represents a complete narrative sequence within an OpenSCENARIO,
@@ -135,12 +134,16 @@ pub struct ManeuverGroup {
     #[serde(rename = "Actors")]
     pub actors: Actors,
 
-    /// Optional catalog reference instead of direct maneuvers
-    #[serde(rename = "CatalogReference", skip_serializing_if = "Option::is_none")]
-    pub catalog_reference: Option<CatalogReference>,
+    /// Catalog references to maneuvers, declared before direct maneuvers
+    #[serde(
+        rename = "CatalogReference",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub catalog_references: Vec<ManeuverCatalogReference>,
 
     /// Direct maneuver definitions
-    #[serde(rename = "Maneuver")]
+    #[serde(rename = "Maneuver", default, skip_serializing_if = "Vec::is_empty")]
     pub maneuvers: Vec<Maneuver>,
 }
 
@@ -254,6 +257,33 @@ impl Default for StoryPrivateAction {
     }
 }
 
+impl ScenarioStory {
+    /// Names of all entities referenced by the acts of this story
+    ///
+    /// See [`Act::involved_entities`].
+    pub fn involved_entities(&self) -> HashSet<String> {
+        self.acts.iter().flat_map(Act::involved_entities).collect()
+    }
+}
+
+impl Act {
+    /// Names of all entities this act references
+    ///
+    /// Includes the maneuver group actors, entities targeted by event actions
+    /// and entities referenced in the start and stop triggers of the act and
+    /// its events. Only literal references are collected; `${param}`
+    /// references cannot be known before the scenario is resolved.
+    pub fn involved_entities(&self) -> HashSet<String> {
+        let mut entities = HashSet::new();
+        crate::utils::visit_act_references(self, |name| {
+            if let Some(name) = name.as_literal() {
+                entities.insert(name.clone());
+            }
+        });
+        entities
+    }
+}
+
 impl Default for ScenarioStory {
     fn default() -> Self {
         Self {
@@ -281,7 +311,7 @@ impl Default for ManeuverGroup {
             name: OSString::literal("DefaultManeuverGroup".to_string()),
             maximum_execution_count: None,
             actors: Actors::default(),
-            catalog_reference: None,
+            catalog_references: Vec::new(),
             maneuvers: Vec::new(),
         }
     }
@@ -366,7 +396,7 @@ mod tests {
             name: Value::literal("TestGroup".to_string()),
             maximum_execution_count: Some(Value::literal(3)),
             actors,
-            catalog_reference: None,
+            catalog_references: Vec::new(),
             maneuvers: vec![Maneuver::default()],
         };
 
@@ -459,4 +489,58 @@ mod tests {
         let serialized = quick_xml::se::to_string(&story).expect("Serialization should succeed");
         assert!(serialized.contains("DefaultStory"));
     }
+
+    #[test]
+    fn test_act_involved_entities() {
+        let xml = r#"<Act name="CutIn">
+            <ManeuverGroup name="EgoGroup" maximumExecutionCount="1">
+                <Actors selectTriggeringEntities="false">
+                    <EntityRef entityRef="ego"/>
+                </Actors>
+                <Maneuver name="Brake">
+                    <Event name="BrakeEvent" priority="override">
+                        <Action name="BrakeAction">
+                            <PrivateAction>
+                                <LongitudinalAction>
+                                    <SpeedAction>
+                                        <SpeedActionDynamics dynamicsShape="step" value="0" dynamicsDimension="time"/>
+                                        <SpeedActionTarget>
+                                            <AbsoluteTargetSpeed value="0"/>
+                                        </SpeedActionTarget>
+                                    </SpeedAction>
+                                </LongitudinalAction>
+                            </PrivateAction>
+                        </Action>
+                    </Event>
+                </Maneuver>
+            </ManeuverGroup>
+            <StartTrigger>
+                <ConditionGroup>
+                    <Condition name="LeadClose" delay="0" conditionEdge="rising">
+                        <ByEntityCondition>
+                            <TriggeringEntities triggeringEntitiesRule="any">
+                                <EntityRef entityRef="ego"/>
+                            </TriggeringEntities>
+                            <EntityCondition>
+                                <TimeHeadwayCondition entityRef="lead" value="1.5" freespace="true" rule="lessThan"/>
+                            </EntityCondition>
+                        </ByEntityCondition>
+                    </Condition>
+                </ConditionGroup>
+            </StartTrigger>
+        </Act>"#;
+        let act: Act = quick_xml::de::from_str(xml).unwrap();
+
+        let entities = act.involved_entities();
+        assert_eq!(
+            entities,
+            HashSet::from(["ego".to_string(), "lead".to_string()])
+        );
+
+        let story = ScenarioStory {
+            acts: vec![act, Act::default()],
+            ..ScenarioStory::default()
+        };
+        assert_eq!(story.involved_entities(), entities);
+    }
 }
@@ -1,12 +1,19 @@
 //! Storyboard and main scenario structure types
 
-use crate::types::basic::{OSString, ParameterDeclarations, UnsignedShort};
+use crate::error::{Error, Result};
+use crate::types::basic::{
+    is_valid_parameter_name, OSString, ParameterDeclarations, UnsignedShort,
+};
 use crate::types::catalogs::files::CatalogContent;
 use crate::types::distributions::ParameterValueDistribution;
 use crate::types::entities::Entities;
+use crate::types::enums::{ObjectType, Rule};
+use crate::types::positions::Position;
 use crate::types::scenario::monitors::MonitorDeclarations;
 use crate::types::scenario::variables::VariableDeclarations;
 use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap};
+use std::path::{Path, PathBuf};
 
 /// Root OpenSCENARIO document structure supporting all document types
 /// This represents the flattened XSD group structure where OpenScenarioCategory
@@ -14,6 +21,20 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename = "OpenSCENARIO")]
 pub struct OpenScenario {
+    /// `xmlns:xsi` namespace binding of the root element, kept for round-trips
+    #[serde(rename = "@xmlns:xsi", skip_serializing_if = "Option::is_none")]
+    pub xmlns_xsi: Option<String>,
+
+    /// `xsi:noNamespaceSchemaLocation` of the root element, kept for round-trips
+    #[serde(
+        rename(
+            serialize = "@xsi:noNamespaceSchemaLocation",
+            deserialize = "@noNamespaceSchemaLocation"
+        ),
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub schema_location: Option<String>,
+
     #[serde(rename = "FileHeader")]
     pub file_header: FileHeader,
 
@@ -91,6 +112,509 @@ impl OpenScenario {
     pub fn is_catalog(&self) -> bool {
         matches!(self.document_type(), OpenScenarioDocumentType::Catalog)
     }
+
+    /// Set the file header author, replacing any parameter reference
+    pub fn set_author(&mut self, author: &str) {
+        self.file_header.author = OSString::literal(author.to_string());
+    }
+
+    /// Set the file header date, replacing any parameter reference
+    pub fn set_date(&mut self, date: &str) {
+        self.file_header.date = OSString::literal(date.to_string());
+    }
+
+    /// Major and minor revision of the file header
+    ///
+    /// Parameterized revisions are resolved against the default values of the
+    /// top-level parameter declarations; parts that cannot be resolved are
+    /// reported as 0.
+    pub fn revision(&self) -> (u16, u16) {
+        let params = self.literal_parameters();
+        let resolve = |value: &UnsignedShort| value.resolve(&params).unwrap_or(0);
+        (
+            resolve(&self.file_header.rev_major),
+            resolve(&self.file_header.rev_minor),
+        )
+    }
+
+    /// Number of concrete scenarios a parameter variation document expands to
+    ///
+    /// For deterministic variations this is the product of the value counts
+    /// of all distributions; for stochastic variations it is
+    /// `numberOfTestRuns`. Returns `None` for other documents, or when the
+    /// count depends on parameters or user defined distributions.
+    pub fn variation_count(&self) -> Option<usize> {
+        let distribution = self.parameter_value_distribution.as_ref()?;
+        if let Some(deterministic) = &distribution.deterministic {
+            deterministic.combination_count()
+        } else {
+            let runs = distribution
+                .stochastic
+                .as_ref()?
+                .number_of_test_runs
+                .as_literal()?;
+            usize::try_from(*runs).ok()
+        }
+    }
+
+    /// Find pairs of entities whose Init teleport positions are within `tolerance` meters
+    ///
+    /// Only `WorldPosition` teleports with literal coordinates are compared. Positions
+    /// that are parameterized (or given in road/lane/relative coordinates) are skipped,
+    /// since they cannot be located without parameter resolution or a road network.
+    /// When an entity is teleported more than once during Init, its last position is used.
+    ///
+    /// Returns `(entity_a, entity_b, distance)` tuples in declaration order.
+    pub fn init_position_conflicts(&self, tolerance: f64) -> Vec<(String, String, f64)> {
+        let Some(storyboard) = &self.storyboard else {
+            return Vec::new();
+        };
+
+        let mut positions: Vec<(String, [f64; 3])> = Vec::new();
+        for private in &storyboard.init.actions.private_actions {
+            let Some(entity) = private.entity_ref.as_literal() else {
+                continue;
+            };
+            for action in &private.private_actions {
+                let Some(world) = action
+                    .teleport_action
+                    .as_ref()
+                    .and_then(|teleport| teleport.position.world_position.as_ref())
+                else {
+                    continue;
+                };
+                let z = match &world.z {
+                    Some(z) => z.as_literal().copied(),
+                    None => Some(0.0),
+                };
+                let (Some(&x), Some(&y), Some(z)) = (world.x.as_literal(), world.y.as_literal(), z)
+                else {
+                    continue;
+                };
+
+                match positions.iter_mut().find(|(name, _)| name == entity) {
+                    Some((_, position)) => *position = [x, y, z],
+                    None => positions.push((entity.clone(), [x, y, z])),
+                }
+            }
+        }
+
+        let mut conflicts = Vec::new();
+        for (i, (name_a, a)) in positions.iter().enumerate() {
+            for (name_b, b) in &positions[i + 1..] {
+                let distance =
+                    ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2) + (a[2] - b[2]).powi(2)).sqrt();
+                if distance <= tolerance {
+                    conflicts.push((name_a.clone(), name_b.clone(), distance));
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Largest simulation time referenced by any `SimulationTimeCondition`
+    ///
+    /// Scans the storyboard stop trigger, act start/stop triggers and event start
+    /// triggers for simulation time conditions using an ordering rule
+    /// (`greaterThan`, `lessThan`, `greaterOrEqual`, `lessOrEqual`). Parameterized
+    /// values are resolved against the document's parameter declarations; values
+    /// that cannot be resolved are skipped. This is a rough upper bound on scenario
+    /// length, not a guarantee: other conditions may end the scenario earlier or later.
+    pub fn max_simulation_time_condition(&self) -> Option<f64> {
+        let storyboard = self.storyboard.as_ref()?;
+        let params = self.literal_parameters();
+
+        let acts = storyboard.stories.iter().flat_map(|story| &story.acts);
+        let act_triggers = acts
+            .clone()
+            .flat_map(|act| act.start_trigger.iter().chain(&act.stop_trigger));
+        let event_triggers = acts
+            .flat_map(|act| &act.maneuver_groups)
+            .flat_map(|group| &group.maneuvers)
+            .flat_map(|maneuver| &maneuver.events)
+            .filter_map(|event| event.start_trigger.as_ref());
+
+        storyboard
+            .stop_trigger
+            .iter()
+            .chain(act_triggers)
+            .chain(event_triggers)
+            .flat_map(|trigger| &trigger.condition_groups)
+            .flat_map(|group| &group.conditions)
+            .filter_map(|condition| {
+                condition
+                    .by_value_condition
+                    .as_ref()?
+                    .simulation_time_condition
+                    .as_ref()
+            })
+            .filter(|time| !matches!(time.rule, Rule::EqualTo | Rule::NotEqualTo))
+            .filter_map(|time| time.value.resolve(&params).ok())
+            .reduce(f64::max)
+    }
+
+    /// Bounding rectangle of all world positions in the storyboard
+    ///
+    /// Considers every `WorldPosition` found by
+    /// [`collect_positions`](Self::collect_positions). Parameterized coordinates
+    /// are resolved against the document's parameter declarations; positions
+    /// whose x or y cannot be resolved are skipped. Returns
+    /// `(min_x, min_y, max_x, max_y)`, or `None` if no world position could be
+    /// resolved.
+    pub fn world_bounds(&self) -> Option<(f64, f64, f64, f64)> {
+        let params = self.literal_parameters();
+        self.collect_positions()
+            .into_iter()
+            .filter_map(|(_, position)| position.world_position.as_ref())
+            .filter_map(|world| {
+                Some((
+                    world.x.resolve(&params).ok()?,
+                    world.y.resolve(&params).ok()?,
+                ))
+            })
+            .fold(None, |bounds, (x, y)| {
+                let (min_x, min_y, max_x, max_y) = bounds.unwrap_or((x, y, x, y));
+                Some((min_x.min(x), min_y.min(y), max_x.max(x), max_y.max(y)))
+            })
+    }
+
+    /// List each scenario object's name together with its object type
+    ///
+    /// Catalog references report the type of the referenced entry. Objects whose
+    /// type cannot be determined are skipped. Parameterized names are returned
+    /// in `${name}` form. Returns an empty list if the document has no entities.
+    pub fn entity_summary(&self) -> Vec<(String, ObjectType)> {
+        self.entities
+            .iter()
+            .flat_map(|entities| &entities.scenario_objects)
+            .filter_map(|object| Some((object.name.to_string(), object.object_type()?)))
+            .collect()
+    }
+
+    /// Path of the scenario file referenced by a parameter variation document
+    ///
+    /// `base` is the path of this variation document. The `ScenarioFile`
+    /// filepath is resolved against the directory containing it with
+    /// [`File::resolve`](crate::types::entities::vehicle::File::resolve).
+    /// Returns `None` for other document types.
+    pub fn referenced_scenario_path(&self, base: &Path) -> Option<PathBuf> {
+        let scenario_file = &self.parameter_value_distribution.as_ref()?.scenario_file;
+        Some(scenario_file.resolve(base.parent().unwrap_or(Path::new(""))))
+    }
+
+    /// Export a concrete copy of this scenario with all parameters inlined
+    ///
+    /// Every `${name}` reference and `${expression}` in the document is
+    /// replaced by its resolved literal value and all `ParameterDeclarations`
+    /// are dropped. `params` overrides declared top-level default values and
+    /// is checked against the declared types; declared defaults may refer to
+    /// earlier declarations. Declarations nested in a story, maneuver,
+    /// controller, route, trajectory or entity apply to the remaining children
+    /// of the declaring element and shadow outer ones. Comparisons evaluate to
+    /// `true` or `false`.
+    ///
+    /// Fails if a reference names an undeclared parameter, an expression
+    /// cannot be evaluated, or a `ParameterCondition` or `ParameterAction`
+    /// refers to a parameter, since those need the declaration at runtime.
+    pub fn to_concrete(&self, params: &HashMap<String, String>) -> Result<OpenScenario> {
+        let mut values = HashMap::new();
+        if let Some(declarations) = &self.parameter_declarations {
+            declarations.validate_overrides(params)?;
+            for declaration in &declarations.parameter_declarations {
+                let name = declaration.name.resolve(&values)?;
+                let value = match params.get(&name) {
+                    Some(value) => value.clone(),
+                    None => declaration.value.resolve(&values)?,
+                };
+                values.insert(name, value);
+            }
+        } else if let Some(name) = params.keys().next() {
+            return Err(Error::parameter_not_found(name, &[]));
+        }
+
+        let xml = crate::parser::xml::serialize_to_string(self)?;
+        let concrete_xml = inline_parameters(&xml, values)?;
+        crate::parser::xml::parse_from_str(&concrete_xml)
+    }
+
+    /// Names of all parameters referenced anywhere in the document
+    ///
+    /// Collects `${name}` and `$name` attribute values as well as parameters
+    /// used inside `${expression}` values, including references made by
+    /// other parameter declarations, and the `parameterRef` of parameter
+    /// conditions and parameter actions. The `parameterRef` of a catalog
+    /// `ParameterAssignment` names a catalog parameter and is not collected.
+    /// Returns an empty set if the document cannot be serialized.
+    pub fn referenced_parameters(&self) -> BTreeSet<String> {
+        self.collect_referenced_parameters().unwrap_or_default()
+    }
+
+    /// Top-level parameter declarations that are never referenced
+    ///
+    /// Compares the declared names against
+    /// [`referenced_parameters`](Self::referenced_parameters) and returns the
+    /// unused ones in declaration order. Parameters that may be set from
+    /// outside the document, e.g. by a parameter variation, are only reported
+    /// if the document itself does not use them. Returns an empty list if the
+    /// document cannot be serialized, so no declaration is wrongly reported.
+    pub fn unused_parameters(&self) -> Vec<String> {
+        let Ok(referenced) = self.collect_referenced_parameters() else {
+            return Vec::new();
+        };
+        self.parameter_declarations
+            .iter()
+            .flat_map(|declarations| &declarations.parameter_declarations)
+            .map(|declaration| declaration.name.to_string())
+            .filter(|name| !referenced.contains(name))
+            .collect()
+    }
+
+    fn collect_referenced_parameters(&self) -> Result<BTreeSet<String>> {
+        let xml = crate::parser::xml::serialize_to_string(self)?;
+        let mut referenced = BTreeSet::new();
+        for_each_attribute(&xml, |element, key, value| {
+            let runtime_ref = key == "parameterRef"
+                && matches!(element, "ParameterCondition" | "ParameterAction");
+            if runtime_ref && is_valid_parameter_name(value) {
+                referenced.insert(value.to_string());
+            } else {
+                referenced.extend(parameter_names_in_text(value));
+            }
+        })?;
+        Ok(referenced)
+    }
+
+    /// Literal parameter declarations as a name to value map
+    fn literal_parameters(&self) -> HashMap<String, String> {
+        self.parameter_declarations
+            .iter()
+            .flat_map(|declarations| &declarations.parameter_declarations)
+            .filter_map(|declaration| {
+                Some((
+                    declaration.name.as_literal()?.clone(),
+                    declaration.value.as_literal()?.clone(),
+                ))
+            })
+            .collect()
+    }
+
+    /// Maneuver group actors that do not refer to a declared entity
+    ///
+    /// Entity names and actor references given as parameters are resolved
+    /// against the default values of the top-level parameter declarations.
+    /// Each dangling reference is listed once, in storyboard order. Fails if
+    /// a name cannot be resolved.
+    pub fn validate_actors(&self) -> Result<Vec<String>> {
+        let params = self.literal_parameters();
+        let declared = self
+            .entities
+            .iter()
+            .flat_map(|entities| &entities.scenario_objects)
+            .map(|object| object.name.resolve(&params))
+            .collect::<Result<BTreeSet<String>>>()?;
+
+        let mut dangling = Vec::new();
+        let actors = self
+            .storyboard
+            .iter()
+            .flat_map(|storyboard| &storyboard.stories)
+            .flat_map(|story| &story.acts)
+            .flat_map(|act| &act.maneuver_groups)
+            .flat_map(|group| {
+                let context = format!("maneuver group '{}'", group.name);
+                group
+                    .actors
+                    .entity_refs
+                    .iter()
+                    .map(move |actor| (actor, context.clone()))
+            });
+        for (actor, context) in actors {
+            let name = actor
+                .entity_ref
+                .resolve(&params)
+                .map_err(|e| e.with_context(&context))?;
+            if !declared.contains(&name) && !dangling.contains(&name) {
+                dangling.push(name);
+            }
+        }
+        Ok(dangling)
+    }
+
+    /// Total length of the routes and trajectories assigned to `entity`
+    ///
+    /// Sums [`Route::total_distance`](crate::types::routing::Route::total_distance)
+    /// of the routes in assign and follow route actions and the polyline
+    /// length of trajectories in follow trajectory actions, with vertices
+    /// resolved against the literal document parameters. Actions are taken
+    /// from the init actions of the entity and from the events of maneuver
+    /// groups listing it as an actor. Routes and trajectories given as catalog
+    /// references are skipped, as are trajectories without a polyline shape
+    /// (clothoid or NURBS), whose length is not computed. Returns `None` if no
+    /// measurable route or trajectory is assigned to the entity.
+    pub fn entity_path_length(&self, entity: &str) -> Result<Option<f64>> {
+        use crate::types::routing::RouteRef;
+
+        let Some(storyboard) = &self.storyboard else {
+            return Ok(None);
+        };
+        let params = self.literal_parameters();
+        let is_entity = |name: &OSString| name.as_literal().is_some_and(|name| name == entity);
+        let init = storyboard
+            .init
+            .actions
+            .private_actions
+            .iter()
+            .filter(|private| is_entity(&private.entity_ref))
+            .flat_map(|private| &private.private_actions)
+            .filter_map(|action| action.routing_action.as_ref());
+        let stories = storyboard
+            .stories
+            .iter()
+            .flat_map(|story| &story.acts)
+            .flat_map(|act| &act.maneuver_groups)
+            .filter(|group| {
+                group
+                    .actors
+                    .entity_refs
+                    .iter()
+                    .any(|actor| is_entity(&actor.entity_ref))
+            })
+            .flat_map(|group| &group.maneuvers)
+            .flat_map(|maneuver| &maneuver.events)
+            .flat_map(|event| &event.actions)
+            .filter_map(|action| action.private_action.as_ref()?.routing_action.as_ref());
+
+        let mut total = None;
+        for routing in init.chain(stories) {
+            let routes = routing
+                .assign_route_action
+                .iter()
+                .map(|assign| &assign.route)
+                .chain(
+                    routing
+                        .follow_route_action
+                        .iter()
+                        .map(|follow| &follow.route_ref),
+                );
+            for route in routes {
+                if let RouteRef::Direct(route) = route {
+                    *total.get_or_insert(0.0) += route.total_distance()?;
+                }
+            }
+
+            if let Some(follow) = &routing.follow_trajectory_action {
+                let trajectories = follow.trajectory.iter().chain(
+                    follow
+                        .trajectory_ref
+                        .iter()
+                        .filter_map(|r| r.trajectory.as_ref()),
+                );
+                for trajectory in trajectories {
+                    if let Some(polyline) = &trajectory.shape.polyline {
+                        *total.get_or_insert(0.0) += polyline.length_with(&params)?;
+                    }
+                }
+            }
+        }
+        Ok(total)
+    }
+
+    /// List every position in the storyboard with a path describing its origin
+    ///
+    /// Paths are dotted element names, e.g. `init.Ego.teleport` for an init
+    /// teleport or `stop_trigger.EndReached.reach_position` for a condition.
+    /// Route waypoints and trajectory vertices are suffixed with their index.
+    /// Positions inside catalog references are not included. Returns an empty
+    /// list if the document has no storyboard.
+    pub fn collect_positions(&self) -> Vec<(String, &Position)> {
+        self.storyboard
+            .as_ref()
+            .map(crate::utils::collect_storyboard_positions)
+            .unwrap_or_default()
+    }
+
+    /// Rename scenario object `old` to `new` and update all references to it
+    ///
+    /// References in init actions, actors, triggering entities, entity
+    /// conditions, action entity references and relative positions are
+    /// updated. Parameterized references are left unchanged. Returns the number
+    /// of names updated, counting the scenario object itself.
+    pub fn rename_entity(&mut self, old: &str, new: &str) -> Result<usize> {
+        let entities = self
+            .entities
+            .as_mut()
+            .ok_or_else(|| Error::entity_not_found(old, &[]))?;
+        entities.rename_entity(old, new)?;
+        let mut count = 1;
+        if let Some(storyboard) = &mut self.storyboard {
+            count += crate::utils::rename_storyboard_references(storyboard, old, new);
+        }
+        Ok(count)
+    }
+
+    /// Import the entities, init private actions and stories of `other`
+    ///
+    /// Entity names of `other` are prefixed with `prefix` and references to them
+    /// are updated as in [`rename_entity`](Self::rename_entity) before import.
+    /// Names are moved to temporary names first, so a prefixed name that
+    /// matches another entity of `other` (`car` and `bg_car`) does not collide.
+    /// Entities with parameterized names are imported unchanged. Global init
+    /// actions and the stop trigger of `other` are not merged. Fails if either
+    /// document lacks a storyboard or an imported name is already taken, in
+    /// which case `self` is left unchanged.
+    pub fn merge(&mut self, other: &OpenScenario, prefix: &str) -> Result<()> {
+        if self.storyboard.is_none() || other.storyboard.is_none() {
+            return Err(Error::validation_error(
+                "Storyboard",
+                "both scenarios must have a storyboard to be merged",
+            ));
+        }
+
+        let mut imported = other.clone();
+        let names: Vec<String> = imported
+            .entities
+            .iter()
+            .flat_map(|entities| &entities.scenario_objects)
+            .filter_map(|object| object.get_name().map(str::to_string))
+            .collect();
+        let temporary = |index: usize| format!("\u{0}merge{}", index);
+        for (index, name) in names.iter().enumerate() {
+            imported.rename_entity(name, &temporary(index))?;
+        }
+        for (index, name) in names.iter().enumerate() {
+            imported.rename_entity(&temporary(index), &format!("{}{}", prefix, name))?;
+        }
+
+        let objects = imported
+            .entities
+            .map(|entities| entities.scenario_objects)
+            .unwrap_or_default();
+        if let Some(object) = objects.iter().find(|object| {
+            self.entities
+                .as_ref()
+                .and_then(|entities| entities.find_object(&object.name.to_string()))
+                .is_some()
+        }) {
+            return Err(Error::validation_error(
+                "Entities",
+                &format!("an entity named '{}' already exists", object.name),
+            ));
+        }
+        self.entities
+            .get_or_insert_with(Entities::default)
+            .scenario_objects
+            .extend(objects);
+
+        if let (Some(storyboard), Some(imported)) = (&mut self.storyboard, imported.storyboard) {
+            storyboard
+                .init
+                .actions
+                .private_actions
+                .extend(imported.init.actions.private_actions);
+            storyboard.stories.extend(imported.stories);
+        }
+        Ok(())
+    }
 }
 
 /// OpenSCENARIO document types
@@ -183,11 +707,272 @@ pub use super::init::Init;
 
 // Story is now imported from story.rs module
 
+/// Call `f` with the element name, key and unescaped value of every attribute
+fn for_each_attribute(xml: &str, mut f: impl FnMut(&str, &str, &str)) -> Result<()> {
+    use quick_xml::events::Event;
+    use quick_xml::Reader;
+
+    let mut reader = Reader::from_str(xml);
+    loop {
+        match reader
+            .read_event()
+            .map_err(|e| Error::invalid_xml(&e.to_string()))?
+        {
+            Event::Eof => return Ok(()),
+            Event::Start(tag) | Event::Empty(tag) => {
+                let element = String::from_utf8_lossy(tag.name().as_ref()).into_owned();
+                for attribute in tag.attributes() {
+                    let attribute = attribute.map_err(|e| Error::invalid_xml(&e.to_string()))?;
+                    let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+                    let value = attribute
+                        .unescape_value()
+                        .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+                    f(&element, &key, &value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Copy a start tag with every attribute value passed through `f`
+fn map_attributes(
+    tag: &quick_xml::events::BytesStart,
+    mut f: impl FnMut(&str) -> Result<String>,
+) -> Result<quick_xml::events::BytesStart<'static>> {
+    let name = String::from_utf8_lossy(tag.name().as_ref()).into_owned();
+    let mut mapped = quick_xml::events::BytesStart::new(name);
+    for attribute in tag.attributes() {
+        let attribute = attribute.map_err(|e| Error::invalid_xml(&e.to_string()))?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).into_owned();
+        let value = attribute
+            .unescape_value()
+            .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+        mapped.push_attribute((key.as_str(), f(&value)?.as_str()));
+    }
+    Ok(mapped)
+}
+
+/// Unescaped value of the attribute `key`, if the tag has it
+fn attribute_value(tag: &quick_xml::events::BytesStart, key: &str) -> Result<Option<String>> {
+    for attribute in tag.attributes() {
+        let attribute = attribute.map_err(|e| Error::invalid_xml(&e.to_string()))?;
+        if attribute.key.as_ref() == key.as_bytes() {
+            let value = attribute
+                .unescape_value()
+                .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+            return Ok(Some(value.into_owned()));
+        }
+    }
+    Ok(None)
+}
+
+/// Inline parameter references in a serialized document
+///
+/// `values` holds the resolved top-level parameters. Every
+/// `ParameterDeclarations` block is dropped from the output; a nested block
+/// opens a scope for the remaining children of the element declaring it.
+fn inline_parameters(xml: &str, values: HashMap<String, String>) -> Result<String> {
+    use quick_xml::events::Event;
+    use quick_xml::{Reader, Writer};
+
+    let mut scopes = vec![values];
+    // Whether each open element declared a parameter scope
+    let mut open: Vec<bool> = Vec::new();
+    // Depth of the ParameterDeclarations element being dropped
+    let mut declarations_depth: Option<usize> = None;
+
+    let mut reader = Reader::from_str(xml);
+    let mut writer = Writer::new(Vec::new());
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| Error::invalid_xml(&e.to_string()))?;
+
+        if let Some(depth) = declarations_depth {
+            match &event {
+                Event::Start(tag) | Event::Empty(tag)
+                    if depth > 1 && tag.name().as_ref() == b"ParameterDeclaration" =>
+                {
+                    let scope = scopes.last_mut().expect("scopes start non-empty");
+                    let name = attribute_value(tag, "name")?.unwrap_or_default();
+                    let value = attribute_value(tag, "value")?.unwrap_or_default();
+                    let name = resolve_parameter_text(&name, scope)?;
+                    let value = resolve_parameter_text(&value, scope)?;
+                    scope.insert(name, value);
+                }
+                _ => {}
+            }
+            match event {
+                Event::Start(_) => open.push(false),
+                Event::End(_) => {
+                    open.pop();
+                    if open.len() == depth {
+                        declarations_depth = None;
+                    }
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+            continue;
+        }
+
+        let scope = scopes.last().expect("scopes start non-empty");
+        let event = match event {
+            Event::Eof => break,
+            Event::Start(tag) if tag.name().as_ref() == b"ParameterDeclarations" => {
+                let depth = open.len();
+                // The top-level block (child of the root) is already in scope
+                if depth > 1 {
+                    if let Some(declares) = open.last_mut() {
+                        *declares = true;
+                        scopes.push(scope.clone());
+                    }
+                }
+                open.push(false);
+                declarations_depth = Some(depth);
+                continue;
+            }
+            Event::Empty(tag) if tag.name().as_ref() == b"ParameterDeclarations" => continue,
+            Event::Start(tag) => {
+                check_runtime_parameter_ref(&tag)?;
+                open.push(false);
+                Event::Start(map_attributes(&tag, |value| {
+                    resolve_parameter_text(value, scope)
+                })?)
+            }
+            Event::Empty(tag) => {
+                check_runtime_parameter_ref(&tag)?;
+                Event::Empty(map_attributes(&tag, |value| {
+                    resolve_parameter_text(value, scope)
+                })?)
+            }
+            Event::End(tag) => {
+                if open.pop() == Some(true) {
+                    scopes.pop();
+                }
+                Event::End(tag)
+            }
+            event => event,
+        };
+        writer.write_event(event)?;
+    }
+    String::from_utf8(writer.into_inner()).map_err(|e| Error::invalid_xml(&e.to_string()))
+}
+
+/// Reject elements that read or write a parameter while the scenario runs
+///
+/// A `ParameterCondition` or `ParameterAction` names its parameter with a
+/// plain `parameterRef`, which would dangle once the declarations are dropped.
+fn check_runtime_parameter_ref(tag: &quick_xml::events::BytesStart) -> Result<()> {
+    let element = tag.name();
+    if !matches!(element.as_ref(), b"ParameterCondition" | b"ParameterAction") {
+        return Ok(());
+    }
+    match attribute_value(tag, "parameterRef")? {
+        Some(parameter) => Err(Error::parameter_error(
+            &parameter,
+            &format!(
+                "{} uses the parameter at runtime, so it cannot be inlined",
+                String::from_utf8_lossy(element.as_ref())
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Names of the parameters referenced by `${name}`, `$name` or `${expression}` text
+fn parameter_names_in_text(text: &str) -> Vec<String> {
+    if !text.starts_with('$') {
+        return Vec::new();
+    }
+    text.split('$')
+        .skip(1)
+        .map(|part| {
+            part.trim_start_matches('{')
+                .chars()
+                .take_while(|c| c.is_alphanumeric() || *c == '_')
+                .collect::<String>()
+        })
+        .filter(|name| is_valid_parameter_name(name))
+        .collect()
+}
+
+/// Resolve `${name}`, `$name` and `${expression}` text to its literal value
+fn resolve_parameter_text(text: &str, values: &HashMap<String, String>) -> Result<String> {
+    let lookup = |name: &str| {
+        values.get(name).cloned().ok_or_else(|| {
+            let available: Vec<String> = values.keys().cloned().collect();
+            Error::parameter_not_found(name, &available)
+        })
+    };
+
+    if let Some(content) = text.strip_prefix("${").and_then(|t| t.strip_suffix('}')) {
+        if is_valid_parameter_name(content) {
+            lookup(content)
+        } else {
+            evaluate_parameter_expression(content, values)
+        }
+    } else {
+        match text.strip_prefix('$') {
+            Some(name) if is_valid_parameter_name(name) => lookup(name),
+            _ => Ok(text.to_string()),
+        }
+    }
+}
+
+/// Evaluate the content of a `${expression}` to its literal text
+///
+/// A lone parameter reference keeps its value as is, so string and boolean
+/// parameters pass through. Boolean parameters count as 1 and 0 in
+/// arithmetic, and a comparison yields `true` or `false`.
+fn evaluate_parameter_expression(
+    content: &str,
+    values: &HashMap<String, String>,
+) -> Result<String> {
+    use crate::expression::{Expr, ExpressionEvaluator, ExpressionParser, Operator};
+
+    let expr = ExpressionParser::new(content)?.parse()?;
+    if let Expr::Parameter(name) = &expr {
+        return values.get(name).cloned().ok_or_else(|| {
+            let available: Vec<String> = values.keys().cloned().collect();
+            Error::parameter_not_found(name, &available)
+        });
+    }
+
+    let numeric = values
+        .iter()
+        .map(|(name, value)| {
+            let value = match value.trim() {
+                "true" => "1".to_string(),
+                "false" => "0".to_string(),
+                _ => value.clone(),
+            };
+            (name.clone(), value)
+        })
+        .collect();
+    let result = ExpressionEvaluator::new(numeric).evaluate(&expr)?;
+    match expr {
+        Expr::BinaryOp {
+            operator:
+                Operator::Greater
+                | Operator::Less
+                | Operator::GreaterEqual
+                | Operator::LessEqual
+                | Operator::Equal
+                | Operator::NotEqual,
+            ..
+        } => Ok((result != 0.0).to_string()),
+        _ => Ok(result.to_string()),
+    }
+}
 
 impl Default for OpenScenario {
     /// Default creates a concrete scenario document
     fn default() -> Self {
         Self {
+            xmlns_xsi: None,
+            schema_location: None,
             file_header: FileHeader {
                 author: crate::types::basic::Value::literal("Unknown".to_string()),
                 date: crate::types::basic::Value::literal("1970-01-01T00:00:00".to_string()),
@@ -214,6 +999,24 @@ impl Default for OpenScenario {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::types::positions::WorldPosition;
+    use crate::types::scenario::init::Private;
+
+    /// Init private actions teleporting `entity` to a world position
+    fn teleport(entity: &str, position: WorldPosition) -> Private {
+        use crate::types::actions::movement::TeleportAction;
+        use crate::types::scenario::init::PrivateAction;
+
+        Private::new(entity).add_action(PrivateAction {
+            teleport_action: Some(TeleportAction {
+                position: Position {
+                    world_position: Some(position),
+                    ..Position::empty()
+                },
+            }),
+            ..PrivateAction::default()
+        })
+    }
 
     #[test]
     fn test_open_scenario_default_is_scenario_type() {
@@ -261,6 +1064,899 @@ mod tests {
         let deserialized: OpenScenario = quick_xml::de::from_str(&xml).unwrap();
         assert_eq!(deserialized.document_type(), OpenScenarioDocumentType::Scenario);
     }
-}
 
+    #[test]
+    fn test_init_position_conflicts() {
+        let mut doc = OpenScenario::default();
+        let mut parameterized = WorldPosition::new(0.0, 0.0);
+        parameterized.x = crate::types::basic::Value::parameter("EgoX".to_string());
+        let init = &mut doc.storyboard.as_mut().unwrap().init;
+        init.actions.private_actions = vec![
+            teleport("Ego", WorldPosition::new(10.0, 5.0)),
+            teleport("Target", WorldPosition::new(10.5, 5.0)),
+            teleport("Far", WorldPosition::new(100.0, 5.0)),
+            teleport("Param", parameterized),
+        ];
+
+        let conflicts = doc.init_position_conflicts(1.0);
+        assert_eq!(conflicts.len(), 1);
+        assert_eq!(conflicts[0].0, "Ego");
+        assert_eq!(conflicts[0].1, "Target");
+        assert!((conflicts[0].2 - 0.5).abs() < 1e-9);
+
+        assert!(doc.init_position_conflicts(0.1).is_empty());
+    }
+
+    #[test]
+    fn test_collect_positions() {
+        use crate::types::actions::movement::TeleportAction;
+        use crate::types::conditions::entity::{ByEntityCondition, EntityCondition};
+        use crate::types::conditions::spatial::ReachPositionCondition;
+        use crate::types::positions::WorldPosition;
+        use crate::types::scenario::init::{Private, PrivateAction};
+        use crate::types::scenario::triggers::{
+            Condition, ConditionGroup, ConditionType, EntityRef, Trigger, TriggeringEntities,
+        };
+
+        let mut doc = OpenScenario::default();
+        assert!(doc.collect_positions().is_empty());
+
+        let storyboard = doc.storyboard.as_mut().unwrap();
+        storyboard.init.actions.private_actions =
+            vec![Private::new("Ego").add_action(PrivateAction {
+                teleport_action: Some(TeleportAction {
+                    position: Position {
+                        world_position: Some(WorldPosition::new(10.0, 5.0)),
+                        ..Position::empty()
+                    },
+                }),
+                ..PrivateAction::default()
+            })];
+        storyboard.stop_trigger = Some(Trigger::new(ConditionGroup::new(Condition::new(
+            "EndReached",
+            ConditionType::ByEntity(ByEntityCondition::new(
+                TriggeringEntities::any(vec![EntityRef::new("Ego")]),
+                EntityCondition::ReachPosition(ReachPositionCondition::at_world_position(
+                    200.0, 5.0, 0.0, 0.0, 2.0,
+                )),
+            )),
+        ))));
+
+        let positions = doc.collect_positions();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[0].0, "init.Ego.teleport");
+        assert_eq!(positions[1].0, "stop_trigger.EndReached.reach_position");
+        let world = positions[1].1.world_position.as_ref().unwrap();
+        assert_eq!(world.x.as_literal(), Some(&200.0));
+    }
+
+    #[test]
+    fn test_world_bounds() {
+        use crate::types::basic::{Double, ParameterDeclaration};
+        use crate::types::enums::ParameterType;
+
+        let mut doc = OpenScenario::default();
+        assert_eq!(doc.world_bounds(), None);
+
+        doc.parameter_declarations = Some(ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration::new(
+                "TargetX".to_string(),
+                ParameterType::Double,
+                "-20".to_string(),
+            )],
+        });
+        let mut target = WorldPosition::new(0.0, 12.0);
+        target.x = Double::parameter("TargetX".to_string());
+        let mut unresolved = WorldPosition::new(0.0, 500.0);
+        unresolved.x = Double::parameter("Unknown".to_string());
+
+        let init = &mut doc.storyboard.as_mut().unwrap().init;
+        init.actions.private_actions = vec![
+            teleport("Ego", WorldPosition::new(10.0, -3.5)),
+            teleport("Target", target),
+            teleport("Parked", WorldPosition::new(85.0, 4.0)),
+            teleport("Param", unresolved),
+        ];
+
+        assert_eq!(doc.world_bounds(), Some((-20.0, -3.5, 85.0, 12.0)));
+    }
+
+    #[test]
+    fn test_to_concrete_inlines_parameters() {
+        use crate::types::basic::{Double, ParameterDeclaration};
+        use crate::types::enums::ParameterType;
+
+        let mut doc = OpenScenario {
+            parameter_declarations: Some(ParameterDeclarations {
+                parameter_declarations: vec![
+                    ParameterDeclaration::new(
+                        "TargetX".to_string(),
+                        ParameterType::Double,
+                        "-20".to_string(),
+                    ),
+                    ParameterDeclaration::new(
+                        "EgoY".to_string(),
+                        ParameterType::Double,
+                        "3.5".to_string(),
+                    ),
+                ],
+            }),
+            ..OpenScenario::default()
+        };
+        let mut target = WorldPosition::new(0.0, 12.0);
+        target.x = Double::parameter("TargetX".to_string());
+        let mut ego = WorldPosition::new(10.0, 0.0);
+        ego.y = Double::parameter("EgoY".to_string());
+        ego.z = Some(Double::expression("$TargetX * 2".to_string()));
+        let init = &mut doc.storyboard.as_mut().unwrap().init;
+        init.actions.private_actions = vec![teleport("Ego", ego), teleport("Target", target)];
+
+        let params = HashMap::from([("TargetX".to_string(), "-30".to_string())]);
+        let concrete = doc.to_concrete(&params).unwrap();
+        assert!(concrete.parameter_declarations.is_none());
+
+        let xml = crate::parser::xml::serialize_to_string(&concrete).unwrap();
+        assert!(!xml.contains("ParameterDeclaration"));
+        assert!(!xml.contains('$'));
+
+        let positions = concrete.collect_positions();
+        let ego = positions[0].1.world_position.as_ref().unwrap();
+        assert_eq!(ego.y.as_literal(), Some(&3.5));
+        assert_eq!(ego.z.as_ref().unwrap().as_literal(), Some(&-60.0));
+        let target = positions[1].1.world_position.as_ref().unwrap();
+        assert_eq!(target.x.as_literal(), Some(&-30.0));
+
+        let unknown = HashMap::from([("Unknown".to_string(), "1".to_string())]);
+        assert!(doc.to_concrete(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_to_concrete_scoped_declarations() {
+        let xml = include_str!("../../../tests/data/multiple_actions_scenario.xosc")
+            .replace(
+                "<Entities>",
+                r#"<ParameterDeclarations>
+                    <ParameterDeclaration name="Speed" parameterType="double" value="10"/>
+                </ParameterDeclarations>
+                <Entities>"#,
+            )
+            .replace(
+                r#"<Maneuver name="TestManeuver">"#,
+                r#"<Maneuver name="TestManeuver">
+                    <ParameterDeclarations>
+                        <ParameterDeclaration name="LocalSpeed" parameterType="double" value="${$Speed * 2}"/>
+                        <ParameterDeclaration name="Speed" parameterType="double" value="5"/>
+                    </ParameterDeclarations>"#,
+            )
+            .replace(
+                r#"<AbsoluteTargetSpeed value="10"/>"#,
+                r#"<AbsoluteTargetSpeed value="${$LocalSpeed + $Speed}"/>"#,
+            )
+            .replace(r#"graphics="true""#, r#"graphics="${$Speed > 7}""#)
+            .replace(
+                r#"<SimulationTimeCondition value="10" rule="greaterThan"/>"#,
+                r#"<SimulationTimeCondition value="$Speed" rule="greaterThan"/>"#,
+            );
+        let doc = crate::parser::xml::parse_from_str(&xml).unwrap();
+
+        let concrete = doc.to_concrete(&HashMap::new()).unwrap();
+        let concrete_xml = quick_xml::se::to_string(&concrete).unwrap();
+        assert!(!concrete_xml.contains("ParameterDeclaration"));
+        assert!(!concrete_xml.contains('$'));
+        // The maneuver-local Speed shadows the top-level one inside the maneuver only
+        assert!(concrete_xml.contains(r#"<AbsoluteTargetSpeed value="25"/>"#));
+        assert!(concrete_xml.contains(r#"graphics="false""#));
+        assert!(concrete_xml.contains(r#"<SimulationTimeCondition value="10""#));
+
+        let overridden = HashMap::from([("Speed".to_string(), "4".to_string())]);
+        let concrete_xml =
+            quick_xml::se::to_string(&doc.to_concrete(&overridden).unwrap()).unwrap();
+        assert!(concrete_xml.contains(r#"<AbsoluteTargetSpeed value="13"/>"#));
+
+        // Local parameters are not visible outside the declaring maneuver
+        let outside = xml.replace(
+            r#"<WorldPosition x="0""#,
+            r#"<WorldPosition x="$LocalSpeed""#,
+        );
+        let doc = crate::parser::xml::parse_from_str(&outside).unwrap();
+        assert!(doc.to_concrete(&HashMap::new()).is_err());
+
+        // A parameter read at runtime cannot be inlined
+        let runtime = xml.replace(
+            r#"<SimulationTimeCondition value="$Speed" rule="greaterThan"/>"#,
+            r#"<ParameterCondition parameterRef="Speed" value="10" rule="greaterThan"/>"#,
+        );
+        let doc = crate::parser::xml::parse_from_str(&runtime).unwrap();
+        let error = doc.to_concrete(&HashMap::new()).unwrap_err().to_string();
+        assert!(error.contains("ParameterCondition"), "{error}");
+    }
+
+    #[test]
+    fn test_variation_count() {
+        use crate::types::basic::{Double, Range, UnsignedInt};
+        use crate::types::distributions::{
+            Deterministic, DeterministicSingleParameterDistribution, DistributionRange,
+            DistributionSet, DistributionSetElement, Stochastic,
+        };
+        use crate::types::entities::vehicle::File;
+
+        let mut deterministic = Deterministic::default();
+        deterministic.add_single(DeterministicSingleParameterDistribution {
+            parameter_name: OSString::literal("EgoSpeed".to_string()),
+            distribution_set: Some(DistributionSet {
+                elements: ["20", "25", "30"]
+                    .iter()
+                    .map(|value| DistributionSetElement {
+                        value: OSString::literal(value.to_string()),
+                    })
+                    .collect(),
+            }),
+            distribution_range: None,
+            user_defined_distribution: None,
+        });
+        deterministic.add_single(DeterministicSingleParameterDistribution {
+            parameter_name: OSString::literal("Gap".to_string()),
+            distribution_set: None,
+            distribution_range: Some(DistributionRange {
+                step_width: OSString::literal("10".to_string()),
+                range: Range {
+                    lower_limit: Double::literal(10.0),
+                    upper_limit: Double::literal(20.0),
+                },
+            }),
+            user_defined_distribution: None,
+        });
+
+        let scenario_file = File {
+            filepath: "cut_in.xosc".to_string(),
+        };
+        let mut doc = OpenScenario::default();
+        assert_eq!(doc.variation_count(), None);
+
+        doc.parameter_value_distribution = Some(ParameterValueDistribution::new_deterministic(
+            scenario_file.clone(),
+            deterministic,
+        ));
+        assert_eq!(doc.variation_count(), Some(6));
+
+        doc.parameter_value_distribution = Some(ParameterValueDistribution::new_stochastic(
+            scenario_file,
+            Stochastic {
+                distributions: Vec::new(),
+                number_of_test_runs: UnsignedInt::literal(50),
+                random_seed: None,
+            },
+        ));
+        assert_eq!(doc.variation_count(), Some(50));
+    }
+
+    #[test]
+    fn test_unused_parameters() {
+        use crate::types::basic::{Double, ParameterDeclaration};
+        use crate::types::enums::ParameterType;
+
+        let mut doc = OpenScenario {
+            parameter_declarations: Some(ParameterDeclarations {
+                parameter_declarations: vec![
+                    ParameterDeclaration::new(
+                        "EgoX".to_string(),
+                        ParameterType::Double,
+                        "10".to_string(),
+                    ),
+                    ParameterDeclaration::new(
+                        "LegacySpeed".to_string(),
+                        ParameterType::Double,
+                        "30".to_string(),
+                    ),
+                ],
+            }),
+            ..OpenScenario::default()
+        };
+        assert_eq!(doc.unused_parameters(), ["EgoX", "LegacySpeed"]);
+
+        let mut position = WorldPosition::new(0.0, 0.0);
+        position.x = Double::parameter("EgoX".to_string());
+        let init = &mut doc.storyboard.as_mut().unwrap().init;
+        init.actions.private_actions = vec![teleport("Ego", position)];
+
+        assert_eq!(
+            doc.referenced_parameters(),
+            BTreeSet::from(["EgoX".to_string()])
+        );
+        assert_eq!(doc.unused_parameters(), ["LegacySpeed"]);
+    }
+
+    #[test]
+    fn test_referenced_parameters_runtime_refs() {
+        let xml = include_str!("../../../tests/data/multiple_actions_scenario.xosc")
+            .replace(
+                "<Entities>",
+                r#"<ParameterDeclarations>
+                    <ParameterDeclaration name="Phase" parameterType="int" value="0"/>
+                    <ParameterDeclaration name="Counter" parameterType="int" value="0"/>
+                    <ParameterDeclaration name="MaxSpeed" parameterType="double" value="30"/>
+                </ParameterDeclarations>
+                <Entities>"#,
+            )
+            .replace(
+                "</Entities>",
+                r#"<ScenarioObject name="Target">
+                    <CatalogReference catalogName="Vehicles" entryName="car">
+                        <ParameterAssignments>
+                            <ParameterAssignment parameterRef="MaxSpeed" value="50"/>
+                        </ParameterAssignments>
+                    </CatalogReference>
+                </ScenarioObject>
+                </Entities>"#,
+            )
+            .replace(
+                "<Actions>",
+                r#"<Actions>
+                <GlobalAction>
+                    <ParameterAction parameterRef="Counter">
+                        <SetAction value="1"/>
+                    </ParameterAction>
+                </GlobalAction>"#,
+            )
+            .replace(
+                r#"<SimulationTimeCondition value="10" rule="greaterThan"/>"#,
+                r#"<ParameterCondition parameterRef="Phase" value="2" rule="equalTo"/>"#,
+            );
+        let doc = crate::parser::xml::parse_from_str(&xml).unwrap();
+
+        assert_eq!(
+            doc.referenced_parameters(),
+            BTreeSet::from(["Counter".to_string(), "Phase".to_string()])
+        );
+        // The catalog assignment names a catalog parameter, not this declaration
+        assert_eq!(doc.unused_parameters(), ["MaxSpeed"]);
+    }
+
+    #[test]
+    fn test_entity_summary_mixed_scenario() {
+        use crate::types::catalogs::references::VehicleCatalogReference;
+        use crate::types::entities::{Pedestrian, ScenarioObject, Vehicle};
+
+        let mut doc = OpenScenario::default();
+        let entities = doc.entities.get_or_insert_with(Entities::default);
+        entities.add_object(ScenarioObject::new_vehicle(
+            "ego".to_string(),
+            Vehicle::default(),
+        ));
+        entities.add_object(ScenarioObject::new_pedestrian(
+            "walker".to_string(),
+            Pedestrian::default(),
+        ));
+        entities.add_object(ScenarioObject::new_vehicle_catalog_reference(
+            "lead".to_string(),
+            VehicleCatalogReference::new("VehicleCatalog".to_string(), "Truck".to_string()),
+        ));
+
+        assert_eq!(
+            doc.entity_summary(),
+            vec![
+                ("ego".to_string(), ObjectType::Vehicle),
+                ("walker".to_string(), ObjectType::Pedestrian),
+                ("lead".to_string(), ObjectType::Vehicle),
+            ]
+        );
+
+        doc.entities = None;
+        assert!(doc.entity_summary().is_empty());
+    }
+
+    #[test]
+    fn test_max_simulation_time_condition() {
+        use crate::types::basic::{Double, ParameterDeclaration};
+        use crate::types::conditions::value::{ByValueCondition, SimulationTimeCondition};
+        use crate::types::enums::ParameterType;
+        use crate::types::scenario::story::{Act, ScenarioStory};
+        use crate::types::scenario::triggers::{Condition, ConditionGroup, ConditionType, Trigger};
+
+        let time_group = |value: Double, rule: Rule| {
+            ConditionGroup::new(Condition::new(
+                "SimTime",
+                ConditionType::ByValue(ByValueCondition {
+                    simulation_time_condition: Some(SimulationTimeCondition { value, rule }),
+                    ..ByValueCondition::default()
+                }),
+            ))
+        };
+
+        let mut doc = OpenScenario::default();
+        assert_eq!(doc.max_simulation_time_condition(), None);
+
+        doc.parameter_declarations = Some(ParameterDeclarations {
+            parameter_declarations: vec![ParameterDeclaration::new(
+                "StartTime".to_string(),
+                ParameterType::Double,
+                "12.5".to_string(),
+            )],
+        });
+
+        // Unresolvable parameters are skipped
+        let act = Act {
+            start_trigger: Some(Trigger::new(time_group(
+                Double::parameter("StartTime".to_string()),
+                Rule::GreaterThan,
+            ))),
+            stop_trigger: Some(Trigger::new(time_group(
+                Double::parameter("Unknown".to_string()),
+                Rule::GreaterThan,
+            ))),
+            ..Act::default()
+        };
+        let story = ScenarioStory {
+            acts: vec![act],
+            ..ScenarioStory::default()
+        };
+
+        let storyboard = doc.storyboard.as_mut().unwrap();
+        storyboard.stories = vec![story];
+        assert_eq!(doc.max_simulation_time_condition(), Some(12.5));
+
+        // Equality rules do not bound the scenario length
+        let storyboard = doc.storyboard.as_mut().unwrap();
+        storyboard.stop_trigger = Some(Trigger {
+            condition_groups: vec![
+                time_group(Double::literal(30.0), Rule::GreaterThan),
+                time_group(Double::literal(99.0), Rule::EqualTo),
+            ],
+        });
+        assert_eq!(doc.max_simulation_time_condition(), Some(30.0));
+    }
+
+    #[test]
+    fn test_rename_entity_updates_condition_references() {
+        use crate::types::conditions::ByEntityCondition;
+        use crate::types::entities::{ScenarioObject, Vehicle};
+        use crate::types::enums::RelativeDistanceType;
+        use crate::types::scenario::story::{EntityRef as ActorRef, ManeuverGroup};
+        use crate::types::scenario::triggers::{
+            Condition, ConditionGroup, ConditionType, EntityRef, Trigger, TriggeringEntities,
+        };
+
+        let mut doc = OpenScenario::default();
+        let mut entities = Entities::new();
+        entities.add_object(ScenarioObject::new_vehicle(
+            "ego".to_string(),
+            Vehicle::default(),
+        ));
+        entities.add_object(ScenarioObject::new_vehicle(
+            "target".to_string(),
+            Vehicle::default(),
+        ));
+        doc.entities = Some(entities);
 
+        let condition = ByEntityCondition::relative_distance(
+            TriggeringEntities::any(vec![EntityRef::new("target")]),
+            "ego",
+            10.0,
+            true,
+            RelativeDistanceType::Longitudinal,
+            Rule::LessThan,
+        );
+        let mut group = ManeuverGroup::default();
+        group.actors.entity_refs = vec![ActorRef {
+            entity_ref: OSString::literal("ego".to_string()),
+        }];
+        let act = crate::types::scenario::story::Act {
+            maneuver_groups: vec![group],
+            start_trigger: Some(Trigger::new(ConditionGroup::new(Condition::new(
+                "CloseToEgo",
+                ConditionType::ByEntity(condition),
+            )))),
+            ..Default::default()
+        };
+        let story = crate::types::scenario::story::ScenarioStory {
+            acts: vec![act],
+            ..Default::default()
+        };
+        doc.storyboard.as_mut().unwrap().stories = vec![story];
+
+        // Object, actor and relative distance reference
+        assert_eq!(doc.rename_entity("ego", "hero").unwrap(), 3);
+
+        let act = &doc.storyboard.as_ref().unwrap().stories[0].acts[0];
+        let condition = act.start_trigger.as_ref().unwrap().condition_groups[0].conditions[0]
+            .by_entity_condition
+            .as_ref()
+            .unwrap();
+        match &condition.entity_condition {
+            crate::types::conditions::EntityCondition::RelativeDistance(distance) => {
+                assert_eq!(distance.entity_ref.as_literal().unwrap(), "hero");
+            }
+            other => panic!("unexpected condition {:?}", other),
+        }
+        assert_eq!(
+            condition.triggering_entities.entity_refs[0]
+                .entity_ref
+                .as_literal()
+                .unwrap(),
+            "target"
+        );
+        assert_eq!(
+            act.maneuver_groups[0].actors.entity_refs[0]
+                .entity_ref
+                .as_literal()
+                .unwrap(),
+            "hero"
+        );
+        let names: Vec<_> = doc
+            .entity_summary()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["hero".to_string(), "target".to_string()]);
+
+        assert!(doc.rename_entity("ego", "other").is_err());
+        assert!(doc.rename_entity("hero", "target").is_err());
+    }
+
+    #[test]
+    fn test_rename_entity_updates_trajectory_vertices() {
+        use crate::types::actions::movement::{FollowTrajectoryAction, RoutingAction, Trajectory};
+        use crate::types::entities::{ScenarioObject, Vehicle};
+        use crate::types::positions::{Position, RelativeObjectPosition};
+        use crate::types::scenario::init::{Private, PrivateAction};
+
+        let mut doc = OpenScenario::default();
+        let mut entities = Entities::new();
+        for name in ["ego", "follower"] {
+            entities.add_object(ScenarioObject::new_vehicle(
+                name.to_string(),
+                Vehicle::default(),
+            ));
+        }
+        doc.entities = Some(entities);
+
+        let mut trajectory = Trajectory::from_world_points("Follow", &[(0.0, 0.0, 0.0, 0.0)]);
+        trajectory.shape.polyline.as_mut().unwrap().vertices[0].position =
+            Position::relative_object(RelativeObjectPosition::new("ego", -10.0, 0.0));
+        let action = PrivateAction {
+            routing_action: Some(RoutingAction {
+                follow_trajectory_action: Some(FollowTrajectoryAction {
+                    trajectory: Some(trajectory),
+                    ..FollowTrajectoryAction::default()
+                }),
+                ..RoutingAction::default()
+            }),
+            ..PrivateAction::default()
+        };
+        let private = Private {
+            entity_ref: OSString::literal("follower".to_string()),
+            private_actions: vec![action],
+        };
+        let storyboard = doc.storyboard.as_mut().unwrap();
+        storyboard.init.actions.private_actions = vec![private];
+
+        // Object and trajectory vertex reference
+        assert_eq!(doc.rename_entity("ego", "hero").unwrap(), 2);
+
+        let storyboard = doc.storyboard.as_ref().unwrap();
+        let routing = storyboard.init.actions.private_actions[0].private_actions[0]
+            .routing_action
+            .as_ref()
+            .unwrap();
+        let trajectory = routing
+            .follow_trajectory_action
+            .as_ref()
+            .unwrap()
+            .trajectory
+            .as_ref()
+            .unwrap();
+        let vertex = &trajectory.shape.polyline.as_ref().unwrap().vertices[0];
+        let relative = vertex.position.relative_object_position.as_ref().unwrap();
+        assert_eq!(relative.entity_ref.as_literal().unwrap(), "hero");
+    }
+
+    #[test]
+    fn test_validate_actors_reports_dangling_references() {
+        use crate::types::entities::{ScenarioObject, Vehicle};
+        use crate::types::scenario::story::{Act, EntityRef, ManeuverGroup, ScenarioStory};
+
+        let mut doc = OpenScenario::default();
+        let mut entities = Entities::new();
+        entities.add_object(ScenarioObject::new_vehicle(
+            "Ego".to_string(),
+            Vehicle::default(),
+        ));
+        doc.entities = Some(entities);
+
+        let mut group = ManeuverGroup::default();
+        group.actors.entity_refs = ["Ego", "Ghost"]
+            .iter()
+            .map(|name| EntityRef {
+                entity_ref: OSString::literal(name.to_string()),
+            })
+            .collect();
+        let act = Act {
+            maneuver_groups: vec![group],
+            ..Act::default()
+        };
+        let story = ScenarioStory {
+            acts: vec![act],
+            ..ScenarioStory::default()
+        };
+        doc.storyboard.as_mut().unwrap().stories = vec![story];
+
+        assert_eq!(doc.validate_actors().unwrap(), ["Ghost"]);
+
+        let group = &mut doc.storyboard.as_mut().unwrap().stories[0].acts[0].maneuver_groups[0];
+        group.actors.entity_refs.truncate(1);
+        assert!(doc.validate_actors().unwrap().is_empty());
+
+        let group = &mut doc.storyboard.as_mut().unwrap().stories[0].acts[0].maneuver_groups[0];
+        group.actors.entity_refs[0].entity_ref = OSString::parameter("Undeclared".to_string());
+        assert!(doc.validate_actors().is_err());
+    }
+
+    #[test]
+    fn test_merge_scenarios() {
+        use crate::types::entities::{ScenarioObject, Vehicle};
+        use crate::types::scenario::story::{EntityRef, ManeuverGroup, ScenarioStory};
+
+        let single_vehicle = |vehicle: &str, story: &str| {
+            let mut doc = OpenScenario::default();
+            let mut entities = Entities::new();
+            entities.add_object(ScenarioObject::new_vehicle(
+                vehicle.to_string(),
+                Vehicle::default(),
+            ));
+            doc.entities = Some(entities);
+
+            let mut group = ManeuverGroup::default();
+            group.actors.entity_refs = vec![EntityRef {
+                entity_ref: OSString::literal(vehicle.to_string()),
+            }];
+            let act = crate::types::scenario::story::Act {
+                maneuver_groups: vec![group],
+                ..Default::default()
+            };
+            let scenario_story = ScenarioStory {
+                name: OSString::literal(story.to_string()),
+                acts: vec![act],
+                ..Default::default()
+            };
+            doc.storyboard.as_mut().unwrap().stories = vec![scenario_story];
+            doc
+        };
+
+        let mut main = single_vehicle("ego", "MainStory");
+        let background = single_vehicle("car", "TrafficStory");
+        main.merge(&background, "bg_").unwrap();
+
+        let names: Vec<_> = main
+            .entity_summary()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, vec!["ego".to_string(), "bg_car".to_string()]);
+
+        let stories = &main.storyboard.as_ref().unwrap().stories;
+        assert_eq!(stories.len(), 2);
+        assert_eq!(stories[0].name.as_literal().unwrap(), "MainStory");
+        assert_eq!(stories[1].name.as_literal().unwrap(), "TrafficStory");
+        let actor = &stories[1].acts[0].maneuver_groups[0].actors.entity_refs[0];
+        assert_eq!(actor.entity_ref.as_literal().unwrap(), "bg_car");
+
+        // The background scenario is not modified
+        let background_entities = background.entities.as_ref().unwrap();
+        assert!(background_entities.find_object("car").is_some());
+
+        // Name collisions and missing storyboards are rejected
+        let before = main.clone();
+        assert!(main.merge(&single_vehicle("ego", "Other"), "").is_err());
+        assert_eq!(main.entity_summary(), before.entity_summary());
+        assert_eq!(main.storyboard, before.storyboard);
+
+        let mut no_storyboard = single_vehicle("truck", "Trucks");
+        no_storyboard.storyboard = None;
+        assert!(main.merge(&no_storyboard, "t_").is_err());
+        assert!(no_storyboard.merge(&background, "bg_").is_err());
+
+        // A prefixed name may match another imported entity
+        let mut traffic = single_vehicle("car", "Cars");
+        let prefixed = single_vehicle("bg_car", "PrefixedCars");
+        traffic
+            .entities
+            .as_mut()
+            .unwrap()
+            .scenario_objects
+            .extend(prefixed.entities.unwrap().scenario_objects);
+        traffic
+            .storyboard
+            .as_mut()
+            .unwrap()
+            .stories
+            .extend(prefixed.storyboard.unwrap().stories);
+        let mut main = single_vehicle("ego", "MainStory");
+        main.merge(&traffic, "bg_").unwrap();
+
+        let names: Vec<_> = main
+            .entity_summary()
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+        assert_eq!(names, ["ego", "bg_car", "bg_bg_car"]);
+        let actors: Vec<_> = main.storyboard.as_ref().unwrap().stories[1..]
+            .iter()
+            .map(|story| {
+                let actor = &story.acts[0].maneuver_groups[0].actors.entity_refs[0];
+                actor.entity_ref.as_literal().unwrap().clone()
+            })
+            .collect();
+        assert_eq!(actors, ["bg_car", "bg_bg_car"]);
+    }
+
+    #[test]
+    fn test_referenced_scenario_path() {
+        use crate::types::distributions::ParameterValueDistribution;
+
+        let mut doc = OpenScenario::default();
+        let base = Path::new("variations/sweep.xosc");
+        assert_eq!(doc.referenced_scenario_path(base), None);
+
+        let mut distribution = ParameterValueDistribution::default();
+        distribution.scenario_file.filepath = "../scenarios/cut_in.xosc".to_string();
+        doc.parameter_value_distribution = Some(distribution);
+        assert_eq!(
+            doc.referenced_scenario_path(base),
+            Some(PathBuf::from("variations/../scenarios/cut_in.xosc"))
+        );
+
+        // A bare file name as base resolves next to it
+        assert_eq!(
+            doc.referenced_scenario_path(Path::new("sweep.xosc")),
+            Some(PathBuf::from("../scenarios/cut_in.xosc"))
+        );
+    }
+
+    #[test]
+    fn test_referenced_scenario_path_absolute() {
+        use crate::types::distributions::ParameterValueDistribution;
+
+        let absolute = std::env::temp_dir().join("cut_in.xosc");
+        let mut distribution = ParameterValueDistribution::default();
+        distribution.scenario_file.filepath = absolute.to_string_lossy().into_owned();
+        let doc = OpenScenario {
+            parameter_value_distribution: Some(distribution),
+            ..OpenScenario::default()
+        };
+
+        assert_eq!(
+            doc.referenced_scenario_path(Path::new("variations/sweep.xosc")),
+            Some(absolute)
+        );
+    }
+
+    #[test]
+    fn test_restamp_file_header() {
+        let mut doc = OpenScenario::default();
+        doc.set_author("Batch Tool");
+        doc.set_date("2024-05-01T12:00:00");
+        assert_eq!(doc.revision(), (1, 0));
+
+        let xml = crate::parser::xml::serialize_to_string(&doc).unwrap();
+        assert!(xml.contains(r#"author="Batch Tool""#));
+        let parsed = crate::parser::xml::parse_from_str(&xml).unwrap();
+        assert_eq!(
+            parsed.file_header.author.as_literal().unwrap(),
+            "Batch Tool"
+        );
+        assert_eq!(
+            parsed.file_header.date.as_literal().unwrap(),
+            "2024-05-01T12:00:00"
+        );
+        assert_eq!(parsed.revision(), (1, 0));
+
+        // Parameterized revisions resolve against declared defaults
+        let mut declarations = ParameterDeclarations::default();
+        declarations
+            .parameter_declarations
+            .push(crate::types::basic::ParameterDeclaration::new(
+                "Minor".to_string(),
+                crate::types::enums::ParameterType::UnsignedShort,
+                "3".to_string(),
+            ));
+        doc.parameter_declarations = Some(declarations);
+        doc.file_header.rev_minor = UnsignedShort::parameter("Minor".to_string());
+        assert_eq!(doc.revision(), (1, 3));
+    }
+
+    #[test]
+    fn test_entity_path_length() {
+        use crate::types::actions::movement::{
+            AssignRouteAction, FollowTrajectoryAction, RoutingAction, Trajectory,
+        };
+        use crate::types::basic::Double;
+        use crate::types::enums::RouteStrategy;
+        use crate::types::geometry::shapes::{Polyline, Shape, Vertex};
+        use crate::types::positions::{Position, WorldPosition};
+        use crate::types::routing::{Route, RouteRef, Waypoint};
+        use crate::types::scenario::init::{Private, PrivateAction};
+
+        let waypoint = |x, y| Waypoint::world_position(x, y, 0.0, RouteStrategy::Shortest);
+        let route = Route::new("Straight", false)
+            .add_waypoint(waypoint(0.0, 0.0))
+            .add_waypoint(waypoint(30.0, 40.0));
+        let action = PrivateAction {
+            routing_action: Some(RoutingAction {
+                assign_route_action: Some(AssignRouteAction {
+                    route: RouteRef::Direct(route),
+                }),
+                ..RoutingAction::default()
+            }),
+            ..PrivateAction::default()
+        };
+        let mut doc = OpenScenario::default();
+        let init = &mut doc.storyboard.as_mut().unwrap().init;
+        let clothoid = PrivateAction {
+            routing_action: Some(RoutingAction {
+                follow_trajectory_action: Some(FollowTrajectoryAction {
+                    trajectory: Some(Trajectory {
+                        shape: Shape { polyline: None },
+                        ..Trajectory::default()
+                    }),
+                    ..FollowTrajectoryAction::default()
+                }),
+                ..RoutingAction::default()
+            }),
+            ..PrivateAction::default()
+        };
+        let vertex = |x: Double| Vertex {
+            time: Double::literal(0.0),
+            position: Position {
+                world_position: Some(WorldPosition {
+                    x,
+                    y: Double::literal(4.0),
+                    ..WorldPosition::default()
+                }),
+                ..Position::default()
+            },
+        };
+        let polyline = PrivateAction {
+            routing_action: Some(RoutingAction {
+                follow_trajectory_action: Some(FollowTrajectoryAction {
+                    trajectory: Some(Trajectory {
+                        shape: Shape {
+                            polyline: Some(Polyline {
+                                vertices: vec![
+                                    vertex(Double::literal(0.0)),
+                                    vertex(Double::parameter("Dx".to_string())),
+                                ],
+                            }),
+                        },
+                        ..Trajectory::default()
+                    }),
+                    ..FollowTrajectoryAction::default()
+                }),
+                ..RoutingAction::default()
+            }),
+            ..PrivateAction::default()
+        };
+        init.actions.private_actions = vec![
+            Private::new("Ego").add_action(action),
+            Private::new("Target").add_action(clothoid),
+            Private::new("Follower").add_action(polyline),
+        ];
+
+        let length = doc.entity_path_length("Ego").unwrap().unwrap();
+        assert!((length - 50.0).abs() < 1e-9);
+        assert_eq!(doc.entity_path_length("Target").unwrap(), None);
+        assert_eq!(doc.entity_path_length("Other").unwrap(), None);
+
+        assert!(doc.entity_path_length("Follower").is_err());
+        let mut declarations = ParameterDeclarations::default();
+        declarations
+            .parameter_declarations
+            .push(crate::types::basic::ParameterDeclaration::new(
+                "Dx".to_string(),
+                crate::types::enums::ParameterType::Double,
+                "3".to_string(),
+            ));
+        doc.parameter_declarations = Some(declarations);
+        let length = doc.entity_path_length("Follower").unwrap().unwrap();
+        assert!((length - 3.0).abs() < 1e-9);
+    }
+}
@@ -7,6 +7,7 @@
 //! - TriggeringEntities for entity-based condition evaluation
 //! - Event priority and execution order management
 //!
+use crate::error::{Error, Result};
 use crate::types::basic::{Double, OSString};
 use crate::types::conditions::{ByEntityCondition, ByValueCondition};
 use crate::types::enums::{ConditionEdge, TriggeringEntitiesRule};
@@ -167,6 +168,16 @@ impl Trigger {
             .iter()
             .any(|g| !g.conditions.is_empty())
     }
+
+    /// Validate every condition of this trigger
+    ///
+    /// Returns the first error reported by [`Condition::validate`].
+    pub fn validate(&self) -> Result<()> {
+        self.condition_groups
+            .iter()
+            .flat_map(|group| &group.conditions)
+            .try_for_each(Condition::validate)
+    }
 }
 
 impl ConditionGroup {
@@ -218,6 +229,40 @@ impl Condition {
         self.delay = Some(delay);
         self
     }
+
+    /// Validate the delay and condition type of this condition
+    ///
+    /// A literal delay must not be negative; parameterized delays are not
+    /// checked. Exactly one of `ByValueCondition` and `ByEntityCondition`
+    /// must be set.
+    pub fn validate(&self) -> Result<()> {
+        if let Some(&delay) = self.delay.as_ref().and_then(|d| d.as_literal()) {
+            if delay < 0.0 {
+                return Err(
+                    Error::out_of_range("delay", &delay.to_string(), "0", "infinity")
+                        .with_context(&format!("condition '{}'", self.name)),
+                );
+            }
+        }
+
+        match (&self.by_value_condition, &self.by_entity_condition) {
+            (Some(_), None) | (None, Some(_)) => Ok(()),
+            (None, None) => Err(Error::validation_error(
+                "Condition",
+                &format!(
+                    "condition '{}' has neither a ByValueCondition nor a ByEntityCondition",
+                    self.name
+                ),
+            )),
+            (Some(_), Some(_)) => Err(Error::validation_error(
+                "Condition",
+                &format!(
+                    "condition '{}' has both a ByValueCondition and a ByEntityCondition",
+                    self.name
+                ),
+            )),
+        }
+    }
 }
 
 impl TriggeringEntities {
@@ -382,4 +427,38 @@ mod tests {
         assert_eq!(trigger.condition_groups[1].conditions.len(), 1); // OR group
         assert!(trigger.has_conditions());
     }
+
+    #[test]
+    fn test_condition_validate_negative_delay() {
+        let condition = Condition::new("Late", ConditionType::default());
+        assert!(condition.validate().is_ok());
+        assert!(condition
+            .clone()
+            .with_delay(Value::parameter("Delay".to_string()))
+            .validate()
+            .is_ok());
+
+        let negative = condition.with_delay(Value::literal(-0.5));
+        let error = negative.validate().unwrap_err();
+        assert!(matches!(error, Error::OutOfRange { .. }));
+        assert!(error.to_string().contains("Late"));
+
+        let trigger = Trigger::new(ConditionGroup::new(negative));
+        assert!(trigger.validate().is_err());
+    }
+
+    #[test]
+    fn test_condition_validate_empty_condition() {
+        let mut condition = Condition::new("Empty", ConditionType::default());
+        condition.by_value_condition = None;
+        condition.by_entity_condition = None;
+
+        let error = condition.validate().unwrap_err();
+        assert!(matches!(error, Error::ValidationError { .. }));
+
+        let mut trigger = Trigger::default();
+        assert!(trigger.validate().is_ok());
+        trigger.add_condition_group(ConditionGroup::new(condition));
+        assert!(trigger.validate().is_err());
+    }
 }
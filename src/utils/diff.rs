@@ -0,0 +1,375 @@
+//! Structural diff between two OpenSCENARIO documents
+//!
+//! This file contains:
+//! - DiffEntry describing a single changed value by its dotted path
+//! - scenario_diff comparing file header, entities and parameter declarations
+//!
+//! Entities and parameters are matched by name, so reordering them is not
+//! reported as a change. A renamed entity shows up as one removal and one
+//! addition. Values are compared in their XML text form, which keeps
+//! `${param}` references distinct from literals.
+
+use crate::types::basic::ParameterDeclaration;
+use crate::types::entities::{ScenarioEntityReference, ScenarioObject};
+use crate::types::scenario::storyboard::OpenScenario;
+use std::fmt;
+
+/// A single difference between two scenarios
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    /// Dotted path of the changed value, e.g. `entities.ego.vehicle.name`
+    pub path: String,
+    /// Value in the first scenario, `None` if the element was added
+    pub old: Option<String>,
+    /// Value in the second scenario, `None` if the element was removed
+    pub new: Option<String>,
+}
+
+impl DiffEntry {
+    /// Whether the element only exists in the second scenario
+    pub fn is_addition(&self) -> bool {
+        self.old.is_none() && self.new.is_some()
+    }
+
+    /// Whether the element only exists in the first scenario
+    pub fn is_removal(&self) -> bool {
+        self.old.is_some() && self.new.is_none()
+    }
+}
+
+impl fmt::Display for DiffEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (&self.old, &self.new) {
+            (Some(old), Some(new)) => write!(f, "~ {}: {} -> {}", self.path, old, new),
+            (None, Some(new)) => write!(f, "+ {}: {}", self.path, new),
+            (Some(old), None) => write!(f, "- {}: {}", self.path, old),
+            (None, None) => write!(f, "  {}", self.path),
+        }
+    }
+}
+
+/// Compare two scenarios structurally
+///
+/// Currently covers the file header, entities and parameter declarations.
+/// Returns an empty vector if no differences were found in those sections.
+pub fn scenario_diff(a: &OpenScenario, b: &OpenScenario) -> Vec<DiffEntry> {
+    let mut diff = Diff::default();
+
+    let (ha, hb) = (&a.file_header, &b.file_header);
+    diff.value("file_header.author", &ha.author, &hb.author);
+    diff.value("file_header.date", &ha.date, &hb.date);
+    diff.value("file_header.description", &ha.description, &hb.description);
+    diff.value("file_header.rev_major", &ha.rev_major, &hb.rev_major);
+    diff.value("file_header.rev_minor", &ha.rev_minor, &hb.rev_minor);
+
+    diff.keyed(
+        "entities",
+        scenario_objects(a),
+        scenario_objects(b),
+        |o| o.name.to_string(),
+        entity_kind,
+        diff_entity,
+    );
+
+    diff.keyed(
+        "parameter_declarations",
+        parameter_declarations(a),
+        parameter_declarations(b),
+        |p| p.name.to_string(),
+        |p| p.value.to_string(),
+        |diff, path, pa, pb| {
+            diff.value(
+                &format!("{}.parameter_type", path),
+                &pa.parameter_type,
+                &pb.parameter_type,
+            );
+            diff.value(&format!("{}.value", path), &pa.value, &pb.value);
+        },
+    );
+
+    diff.entries
+}
+
+fn scenario_objects(scenario: &OpenScenario) -> &[ScenarioObject] {
+    scenario
+        .entities
+        .as_ref()
+        .map_or(&[], |e| e.scenario_objects.as_slice())
+}
+
+fn parameter_declarations(scenario: &OpenScenario) -> &[ParameterDeclaration] {
+    scenario
+        .parameter_declarations
+        .as_ref()
+        .map_or(&[], |p| p.parameter_declarations.as_slice())
+}
+
+/// Short description of what a scenario object contains
+fn entity_kind(object: &ScenarioObject) -> String {
+    if object.vehicle.is_some() {
+        "vehicle".to_string()
+    } else if object.pedestrian.is_some() {
+        "pedestrian".to_string()
+    } else if object.entity_catalog_reference.is_some() {
+        "catalog_reference".to_string()
+    } else {
+        "empty".to_string()
+    }
+}
+
+fn diff_entity(diff: &mut Diff, path: &str, a: &ScenarioObject, b: &ScenarioObject) {
+    let (kind_a, kind_b) = (entity_kind(a), entity_kind(b));
+    if kind_a != kind_b {
+        diff.value(&format!("{}.kind", path), kind_a, kind_b);
+        return;
+    }
+
+    if let (Some(va), Some(vb)) = (&a.vehicle, &b.vehicle) {
+        let path = format!("{}.vehicle", path);
+        diff.value(&format!("{}.name", path), &va.name, &vb.name);
+        diff.value(
+            &format!("{}.vehicle_category", path),
+            &va.vehicle_category,
+            &vb.vehicle_category,
+        );
+        let (pa, pb) = (&va.performance, &vb.performance);
+        diff.value(
+            &format!("{}.performance.max_speed", path),
+            &pa.max_speed,
+            &pb.max_speed,
+        );
+        diff.value(
+            &format!("{}.performance.max_acceleration", path),
+            &pa.max_acceleration,
+            &pb.max_acceleration,
+        );
+        diff.value(
+            &format!("{}.performance.max_deceleration", path),
+            &pa.max_deceleration,
+            &pb.max_deceleration,
+        );
+        let (da, db) = (&va.bounding_box.dimensions, &vb.bounding_box.dimensions);
+        diff.value(
+            &format!("{}.bounding_box.length", path),
+            &da.length,
+            &db.length,
+        );
+        diff.value(
+            &format!("{}.bounding_box.width", path),
+            &da.width,
+            &db.width,
+        );
+        diff.value(
+            &format!("{}.bounding_box.height", path),
+            &da.height,
+            &db.height,
+        );
+    }
+
+    if let (Some(pa), Some(pb)) = (&a.pedestrian, &b.pedestrian) {
+        let path = format!("{}.pedestrian", path);
+        diff.value(&format!("{}.name", path), &pa.name, &pb.name);
+        diff.value(
+            &format!("{}.pedestrian_category", path),
+            &pa.pedestrian_category,
+            &pb.pedestrian_category,
+        );
+        diff.value(&format!("{}.mass", path), &pa.mass, &pb.mass);
+    }
+
+    if let (Some(ra), Some(rb)) = (&a.entity_catalog_reference, &b.entity_catalog_reference) {
+        let path = format!("{}.catalog_reference", path);
+        let (catalog_a, entry_a) = catalog_entry(ra);
+        let (catalog_b, entry_b) = catalog_entry(rb);
+        diff.value(&format!("{}.catalog_name", path), catalog_a, catalog_b);
+        diff.value(&format!("{}.entry_name", path), entry_a, entry_b);
+    }
+
+    diff.value(
+        &format!("{}.object_controllers", path),
+        a.object_controllers.len(),
+        b.object_controllers.len(),
+    );
+}
+
+fn catalog_entry(reference: &ScenarioEntityReference) -> (String, String) {
+    match reference {
+        ScenarioEntityReference::Vehicle(r) => {
+            (r.catalog_name.to_string(), r.entry_name.to_string())
+        }
+        ScenarioEntityReference::Pedestrian(r) => {
+            (r.catalog_name.to_string(), r.entry_name.to_string())
+        }
+    }
+}
+
+/// Accumulates diff entries while walking both documents
+#[derive(Default)]
+struct Diff {
+    entries: Vec<DiffEntry>,
+}
+
+impl Diff {
+    /// Record a change if the text forms of `a` and `b` differ
+    fn value<T: fmt::Display>(&mut self, path: &str, a: T, b: T) {
+        let (a, b) = (a.to_string(), b.to_string());
+        if a != b {
+            self.entries.push(DiffEntry {
+                path: path.to_string(),
+                old: Some(a),
+                new: Some(b),
+            });
+        }
+    }
+
+    /// Match two lists by key and diff the pairs, recording additions and removals
+    ///
+    /// Removed items are reported first in their original order, followed by
+    /// changed and added items in the order of `b`.
+    fn keyed<T>(
+        &mut self,
+        path: &str,
+        a: &[T],
+        b: &[T],
+        key: impl Fn(&T) -> String,
+        summary: impl Fn(&T) -> String,
+        compare: impl Fn(&mut Self, &str, &T, &T),
+    ) {
+        for item in a {
+            let k = key(item);
+            if !b.iter().any(|other| key(other) == k) {
+                self.entries.push(DiffEntry {
+                    path: format!("{}.{}", path, k),
+                    old: Some(summary(item)),
+                    new: None,
+                });
+            }
+        }
+
+        for item in b {
+            let k = key(item);
+            let item_path = format!("{}.{}", path, k);
+            match a.iter().find(|other| key(other) == k) {
+                Some(old) => compare(self, &item_path, old, item),
+                None => self.entries.push(DiffEntry {
+                    path: item_path,
+                    old: None,
+                    new: Some(summary(item)),
+                }),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::basic::{OSString, ParameterDeclarations};
+    use crate::types::entities::vehicle::Vehicle;
+    use crate::types::entities::Entities;
+    use crate::types::enums::ParameterType;
+
+    fn scenario() -> OpenScenario {
+        let mut entities = Entities::new();
+        entities.add_object(ScenarioObject::new_vehicle(
+            "ego".to_string(),
+            Vehicle::new_car("EgoCar".to_string()),
+        ));
+        entities.add_object(ScenarioObject::new_vehicle(
+            "target".to_string(),
+            Vehicle::new_truck("TargetTruck".to_string()),
+        ));
+
+        OpenScenario {
+            entities: Some(entities),
+            parameter_declarations: Some(ParameterDeclarations {
+                parameter_declarations: vec![ParameterDeclaration::new(
+                    "EgoSpeed".to_string(),
+                    ParameterType::Double,
+                    "30".to_string(),
+                )],
+            }),
+            ..OpenScenario::default()
+        }
+    }
+
+    #[test]
+    fn test_identical_scenarios_have_no_diff() {
+        assert!(scenario_diff(&scenario(), &scenario()).is_empty());
+    }
+
+    #[test]
+    fn test_renamed_entity() {
+        let a = scenario();
+        let mut b = scenario();
+        b.entities.as_mut().unwrap().scenario_objects[0].name =
+            OSString::literal("hero".to_string());
+
+        let diff = scenario_diff(&a, &b);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff[0].path, "entities.ego");
+        assert!(diff[0].is_removal());
+        assert_eq!(diff[1].path, "entities.hero");
+        assert!(diff[1].is_addition());
+        assert_eq!(diff[1].new.as_deref(), Some("vehicle"));
+    }
+
+    #[test]
+    fn test_renamed_vehicle_and_header_change() {
+        let a = scenario();
+        let mut b = scenario();
+        b.file_header.author = OSString::literal("Generator".to_string());
+        b.entities.as_mut().unwrap().scenario_objects[0]
+            .vehicle
+            .as_mut()
+            .unwrap()
+            .name = OSString::literal("NewCar".to_string());
+
+        let diff = scenario_diff(&a, &b);
+        assert_eq!(
+            diff,
+            vec![
+                DiffEntry {
+                    path: "file_header.author".to_string(),
+                    old: Some("Unknown".to_string()),
+                    new: Some("Generator".to_string()),
+                },
+                DiffEntry {
+                    path: "entities.ego.vehicle.name".to_string(),
+                    old: Some("EgoCar".to_string()),
+                    new: Some("NewCar".to_string()),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_added_and_changed_parameter() {
+        let a = scenario();
+        let mut b = scenario();
+        let declarations = &mut b
+            .parameter_declarations
+            .as_mut()
+            .unwrap()
+            .parameter_declarations;
+        declarations[0].value = OSString::literal("25".to_string());
+        declarations.push(ParameterDeclaration::new(
+            "TargetGap".to_string(),
+            ParameterType::Double,
+            "40".to_string(),
+        ));
+
+        let diff = scenario_diff(&a, &b);
+        assert_eq!(diff.len(), 2);
+        assert_eq!(diff[0].path, "parameter_declarations.EgoSpeed.value");
+        assert_eq!(diff[0].old.as_deref(), Some("30"));
+        assert_eq!(diff[0].new.as_deref(), Some("25"));
+        assert_eq!(diff[1].path, "parameter_declarations.TargetGap");
+        assert!(diff[1].is_addition());
+        assert_eq!(diff[1].new.as_deref(), Some("40"));
+        assert_eq!(
+            diff[1].to_string(),
+            "+ parameter_declarations.TargetGap: 40"
+        );
+    }
+}
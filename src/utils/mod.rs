@@ -0,0 +1,12 @@
+//! Utilities for working with complete OpenSCENARIO documents
+//!
+//! This module contains helpers that operate on whole documents rather than
+//! on individual types, such as structural comparison of two scenarios.
+
+pub mod diff;
+mod positions;
+mod rename;
+
+pub use diff::{scenario_diff, DiffEntry};
+pub(crate) use positions::collect_storyboard_positions;
+pub(crate) use rename::{rename_storyboard_references, visit_act_references};
@@ -0,0 +1,204 @@
+//! Position collection across a storyboard
+//!
+//! This file contains:
+//! - collect_storyboard_positions listing every position in a storyboard
+//! - PositionCollector walking init actions, stories, triggers and actions
+//!
+//! Each position is paired with a dotted path built from element names, e.g.
+//! `init.Ego.teleport` or `stop_trigger.EndReached.reach_position`. List
+//! elements without names (route waypoints, trajectory vertices) use their
+//! index. Positions inside catalog references are not visited.
+
+use crate::types::actions::movement::{RoutingAction, SynchronizeAction, Trajectory};
+use crate::types::actions::wrappers::EntityActionChoice;
+use crate::types::conditions::EntityCondition;
+use crate::types::positions::Position;
+use crate::types::routing::RouteRef;
+use crate::types::scenario::storyboard::Storyboard;
+use crate::types::scenario::triggers::Trigger;
+
+/// List every position in the storyboard together with the path to it
+///
+/// Covers init teleports, added entities, route waypoints, trajectory
+/// vertices, synchronize targets and position-based entity conditions in
+/// the order they appear in the document.
+pub fn collect_storyboard_positions(storyboard: &Storyboard) -> Vec<(String, &Position)> {
+    let mut collector = PositionCollector::default();
+    collector.storyboard(storyboard);
+    collector.positions
+}
+
+/// Walks a storyboard and records every position with its path
+#[derive(Default)]
+struct PositionCollector<'a> {
+    positions: Vec<(String, &'a Position)>,
+}
+
+impl<'a> PositionCollector<'a> {
+    fn push(&mut self, path: String, position: &'a Position) {
+        self.positions.push((path, position));
+    }
+
+    fn storyboard(&mut self, storyboard: &'a Storyboard) {
+        let actions = &storyboard.init.actions;
+        for global in &actions.global_actions {
+            if let Some(entity_action) = &global.entity_action {
+                if let EntityActionChoice::AddEntityAction(add) = &entity_action.action {
+                    let path = format!("init.{}.add_entity", entity_action.entity_ref);
+                    self.push(path, &add.position);
+                }
+            }
+        }
+        for private in &actions.private_actions {
+            let path = format!("init.{}", private.entity_ref);
+            for action in &private.private_actions {
+                if let Some(teleport) = &action.teleport_action {
+                    self.push(format!("{}.teleport", path), &teleport.position);
+                }
+                if let Some(routing) = &action.routing_action {
+                    self.routing(&path, routing);
+                }
+                if let Some(synchronize) = &action.synchronize_action {
+                    self.synchronize(&path, synchronize);
+                }
+            }
+        }
+
+        for story in &storyboard.stories {
+            for act in &story.acts {
+                let act_path = format!("stories.{}.{}", story.name, act.name);
+                for group in &act.maneuver_groups {
+                    for maneuver in &group.maneuvers {
+                        for event in &maneuver.events {
+                            let event_path = format!(
+                                "{}.{}.{}.{}",
+                                act_path, group.name, maneuver.name, event.name
+                            );
+                            for action in &event.actions {
+                                let Some(private) = &action.private_action else {
+                                    continue;
+                                };
+                                let path = format!("{}.{}", event_path, action.name);
+                                if let Some(teleport) = &private.teleport_action {
+                                    self.push(format!("{}.teleport", path), &teleport.position);
+                                }
+                                if let Some(routing) = &private.routing_action {
+                                    self.routing(&path, routing);
+                                }
+                                if let Some(synchronize) = &private.synchronize_action {
+                                    self.synchronize(&path, synchronize);
+                                }
+                            }
+                            self.trigger(
+                                &format!("{}.start_trigger", event_path),
+                                event.start_trigger.as_ref(),
+                            );
+                        }
+                    }
+                }
+                self.trigger(
+                    &format!("{}.start_trigger", act_path),
+                    act.start_trigger.as_ref(),
+                );
+                self.trigger(
+                    &format!("{}.stop_trigger", act_path),
+                    act.stop_trigger.as_ref(),
+                );
+            }
+        }
+        self.trigger("stop_trigger", storyboard.stop_trigger.as_ref());
+    }
+
+    fn routing(&mut self, path: &str, action: &'a RoutingAction) {
+        let routes = action
+            .assign_route_action
+            .iter()
+            .map(|assign| &assign.route)
+            .chain(
+                action
+                    .follow_route_action
+                    .iter()
+                    .map(|follow| &follow.route_ref),
+            );
+        for route in routes {
+            if let RouteRef::Direct(route) = route {
+                for (i, waypoint) in route.waypoints.iter().enumerate() {
+                    self.push(
+                        format!("{}.route.waypoint[{}]", path, i),
+                        &waypoint.position,
+                    );
+                }
+            }
+        }
+
+        if let Some(follow) = &action.follow_trajectory_action {
+            let trajectories = follow.trajectory.iter().chain(
+                follow
+                    .trajectory_ref
+                    .iter()
+                    .filter_map(|r| r.trajectory.as_ref()),
+            );
+            for trajectory in trajectories {
+                self.trajectory(path, trajectory);
+            }
+        }
+    }
+
+    fn trajectory(&mut self, path: &str, trajectory: &'a Trajectory) {
+        let Some(polyline) = &trajectory.shape.polyline else {
+            return;
+        };
+        for (i, vertex) in polyline.vertices.iter().enumerate() {
+            self.push(
+                format!("{}.trajectory.vertex[{}]", path, i),
+                &vertex.position,
+            );
+        }
+    }
+
+    fn synchronize(&mut self, path: &str, action: &'a SynchronizeAction) {
+        self.push(
+            format!("{}.synchronize.target_position_master", path),
+            &action.target_position_master,
+        );
+        self.push(
+            format!("{}.synchronize.target_position", path),
+            &action.target_position,
+        );
+    }
+
+    fn trigger(&mut self, path: &str, trigger: Option<&'a Trigger>) {
+        let Some(trigger) = trigger else {
+            return;
+        };
+        for condition in trigger
+            .condition_groups
+            .iter()
+            .flat_map(|group| &group.conditions)
+        {
+            let Some(by_entity) = &condition.by_entity_condition else {
+                continue;
+            };
+            let path = format!("{}.{}", path, condition.name);
+            match &by_entity.entity_condition {
+                EntityCondition::ReachPosition(reach) => {
+                    self.push(format!("{}.reach_position", path), &reach.position);
+                }
+                EntityCondition::Distance(distance) => {
+                    self.push(format!("{}.distance", path), &distance.position);
+                }
+                EntityCondition::Collision(collision) => {
+                    if let Some(position) = &collision.position {
+                        self.push(format!("{}.collision", path), position);
+                    }
+                }
+                EntityCondition::TimeToCollision(ttc) => {
+                    if let Some(position) = &ttc.target.position {
+                        self.push(format!("{}.time_to_collision", path), position);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+}
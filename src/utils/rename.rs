@@ -0,0 +1,327 @@
+//! Entity reference renaming across a storyboard
+//!
+//! This file contains:
+//! - rename_storyboard_references updating every entity reference in a storyboard
+//! - visit_act_references reading every entity reference in a single act
+//! - RefVisitor and RefReader walking init actions, stories, triggers, actions
+//!   and positions by mutable and shared reference
+//!
+//! Only literal references are renamed. A reference given as `${param}` points
+//! to whatever the parameter resolves to and is left unchanged.
+//!
+//! Story-level actions are modelled as `PrivateAction` only and
+//! `RoutingAction` has no `AcquirePositionAction`, so story-level global
+//! actions and acquire positions cannot be reached and are not visited.
+//! Trajectories and routes referenced from catalogs are not visited either.
+
+use crate::types::actions::movement::{
+    LaneChangeTargetChoice, LaneOffsetTargetChoice, LateralAction, LateralActionChoice,
+    RoutingAction, SynchronizeAction, Trajectory,
+};
+use crate::types::actions::wrappers::EntityActionChoice;
+use crate::types::actions::TrailerAction;
+use crate::types::basic::OSString;
+use crate::types::conditions::{ByEntityCondition, EntityCondition};
+use crate::types::positions::Position;
+use crate::types::routing::RouteRef;
+use crate::types::scenario::init::{LongitudinalAction, PrivateAction};
+use crate::types::scenario::story::{Act, StoryPrivateAction};
+use crate::types::scenario::storyboard::Storyboard;
+use crate::types::scenario::triggers::Trigger;
+
+/// Rename all literal references to entity `old` in the storyboard
+///
+/// Covers init actions, actors, triggering entities, entity conditions,
+/// action entity references and relative positions (including route
+/// waypoints and trajectory vertices).
+/// Returns the number of references that were updated.
+pub fn rename_storyboard_references(storyboard: &mut Storyboard, old: &str, new: &str) -> usize {
+    let mut count = 0;
+    let mut visitor = RefVisitor(|name: &mut OSString| {
+        if name.as_literal().map(String::as_str) == Some(old) {
+            *name = OSString::literal(new.to_string());
+            count += 1;
+        }
+    });
+    visitor.storyboard(storyboard);
+    count
+}
+
+/// Call `visit` on every entity reference in an act
+///
+/// Covers actors, event actions and the start and stop triggers of the act
+/// and its events.
+pub fn visit_act_references(act: &Act, visit: impl FnMut(&OSString)) {
+    RefReader(visit).act(act);
+}
+
+/// Defines a walker that passes every entity reference to its closure
+///
+/// `ref_walker!(Name, mut)` walks by mutable reference and `ref_walker!(Name)`
+/// by shared reference; both visit the same references.
+macro_rules! ref_walker {
+    ($walker:ident $(, $m:tt)?) => {
+        struct $walker<F>(F);
+
+        // Not every entry point is used in both modes
+        #[allow(dead_code)]
+        impl<F: FnMut(&$($m)? OSString)> $walker<F> {
+            fn name(&mut self, name: &$($m)? OSString) {
+                (self.0)(name);
+            }
+
+            fn storyboard(&mut self, storyboard: &$($m)? Storyboard) {
+                let actions = &$($m)? storyboard.init.actions;
+                for global in &$($m)? actions.global_actions {
+                    if let Some(entity_action) = &$($m)? global.entity_action {
+                        self.name(&$($m)? entity_action.entity_ref);
+                        if let EntityActionChoice::AddEntityAction(add) = &$($m)? entity_action.action {
+                            self.position(&$($m)? add.position);
+                        }
+                    }
+                }
+                for private in &$($m)? actions.private_actions {
+                    self.name(&$($m)? private.entity_ref);
+                    for action in &$($m)? private.private_actions {
+                        self.init_private_action(action);
+                    }
+                }
+
+                for story in &$($m)? storyboard.stories {
+                    for act in &$($m)? story.acts {
+                        self.act(act);
+                    }
+                }
+                self.trigger(&$($m)? storyboard.stop_trigger);
+            }
+
+            fn act(&mut self, act: &$($m)? Act) {
+                for group in &$($m)? act.maneuver_groups {
+                    for actor in &$($m)? group.actors.entity_refs {
+                        self.name(&$($m)? actor.entity_ref);
+                    }
+                    for maneuver in &$($m)? group.maneuvers {
+                        for event in &$($m)? maneuver.events {
+                            for action in &$($m)? event.actions {
+                                if let Some(private) = &$($m)? action.private_action {
+                                    self.story_private_action(private);
+                                }
+                            }
+                            self.trigger(&$($m)? event.start_trigger);
+                        }
+                    }
+                }
+                self.trigger(&$($m)? act.start_trigger);
+                self.trigger(&$($m)? act.stop_trigger);
+            }
+
+            fn init_private_action(&mut self, action: &$($m)? PrivateAction) {
+                if let Some(longitudinal) = &$($m)? action.longitudinal_action {
+                    self.longitudinal(longitudinal);
+                }
+                if let Some(lateral) = &$($m)? action.lateral_action {
+                    self.lateral(lateral);
+                }
+                if let Some(teleport) = &$($m)? action.teleport_action {
+                    self.position(&$($m)? teleport.position);
+                }
+                if let Some(routing) = &$($m)? action.routing_action {
+                    self.routing(routing);
+                }
+                if let Some(synchronize) = &$($m)? action.synchronize_action {
+                    self.synchronize(synchronize);
+                }
+            }
+
+            fn story_private_action(&mut self, action: &$($m)? StoryPrivateAction) {
+                if let Some(longitudinal) = &$($m)? action.longitudinal_action {
+                    self.longitudinal(longitudinal);
+                }
+                if let Some(lateral) = &$($m)? action.lateral_action {
+                    self.lateral(lateral);
+                }
+                if let Some(teleport) = &$($m)? action.teleport_action {
+                    self.position(&$($m)? teleport.position);
+                }
+                if let Some(routing) = &$($m)? action.routing_action {
+                    self.routing(routing);
+                }
+                if let Some(synchronize) = &$($m)? action.synchronize_action {
+                    self.synchronize(synchronize);
+                }
+                if let Some(trailer) = &$($m)? action.trailer_action {
+                    self.trailer(trailer);
+                }
+            }
+
+            fn longitudinal(&mut self, action: &$($m)? LongitudinalAction) {
+                if let Some(speed) = &$($m)? action.speed_action {
+                    if let Some(relative) = &$($m)? speed.speed_action_target.relative {
+                        // The target stores a plain name, so walk a copy and write it back
+                        #[allow(unused_mut)]
+                        let mut entity_ref = OSString::literal(relative.entity_ref.clone());
+                        self.name(&$($m)? entity_ref);
+                        $(
+                            let _ = stringify!($m);
+                            if let Some(name) = entity_ref.as_literal() {
+                                relative.entity_ref = name.clone();
+                            }
+                        )?
+                    }
+                }
+                if let Some(distance) = &$($m)? action.longitudinal_distance_action {
+                    self.name(&$($m)? distance.entity_ref);
+                }
+                if let Some(profile) = &$($m)? action.speed_profile_action {
+                    if let Some(entity_ref) = &$($m)? profile.entity_ref {
+                        self.name(entity_ref);
+                    }
+                }
+            }
+
+            fn lateral(&mut self, action: &$($m)? LateralAction) {
+                match &$($m)? action.lateral_choice {
+                    LateralActionChoice::LaneChangeAction(lane_change) => {
+                        if let LaneChangeTargetChoice::RelativeTargetLane(target) =
+                            &$($m)? lane_change.lane_change_target.target_choice
+                        {
+                            self.name(&$($m)? target.entity_ref);
+                        }
+                    }
+                    LateralActionChoice::LaneOffsetAction(lane_offset) => {
+                        if let LaneOffsetTargetChoice::RelativeTargetLaneOffset(target) =
+                            &$($m)? lane_offset.target.target_choice
+                        {
+                            self.name(&$($m)? target.entity_ref);
+                        }
+                    }
+                    LateralActionChoice::LateralDistanceAction(distance) => {
+                        self.name(&$($m)? distance.entity_ref);
+                    }
+                }
+            }
+
+            fn routing(&mut self, action: &$($m)? RoutingAction) {
+                if let Some(assign) = &$($m)? action.assign_route_action {
+                    self.route(&$($m)? assign.route);
+                }
+                if let Some(follow) = &$($m)? action.follow_route_action {
+                    self.route(&$($m)? follow.route_ref);
+                }
+
+                if let Some(follow) = &$($m)? action.follow_trajectory_action {
+                    if let Some(trajectory) = &$($m)? follow.trajectory {
+                        self.trajectory(trajectory);
+                    }
+                    if let Some(trajectory_ref) = &$($m)? follow.trajectory_ref {
+                        if let Some(trajectory) = &$($m)? trajectory_ref.trajectory {
+                            self.trajectory(trajectory);
+                        }
+                    }
+                }
+            }
+
+            fn route(&mut self, route: &$($m)? RouteRef) {
+                if let RouteRef::Direct(route) = route {
+                    for waypoint in &$($m)? route.waypoints {
+                        self.position(&$($m)? waypoint.position);
+                    }
+                }
+            }
+
+            fn trajectory(&mut self, trajectory: &$($m)? Trajectory) {
+                if let Some(polyline) = &$($m)? trajectory.shape.polyline {
+                    for vertex in &$($m)? polyline.vertices {
+                        self.position(&$($m)? vertex.position);
+                    }
+                }
+            }
+
+            fn synchronize(&mut self, action: &$($m)? SynchronizeAction) {
+                self.name(&$($m)? action.master_entity_ref);
+                self.position(&$($m)? action.target_position_master);
+                self.position(&$($m)? action.target_position);
+            }
+
+            fn trailer(&mut self, action: &$($m)? TrailerAction) {
+                if let Some(connect) = &$($m)? action.connect_trailer_action {
+                    self.name(&$($m)? connect.trailer_ref);
+                }
+            }
+
+            fn trigger(&mut self, trigger: &$($m)? Option<Trigger>) {
+                let Some(trigger) = trigger else {
+                    return;
+                };
+                for group in &$($m)? trigger.condition_groups {
+                    for condition in &$($m)? group.conditions {
+                        if let Some(by_entity) = &$($m)? condition.by_entity_condition {
+                            self.entity_condition(by_entity);
+                        }
+                    }
+                }
+            }
+
+            fn entity_condition(&mut self, condition: &$($m)? ByEntityCondition) {
+                for triggering in &$($m)? condition.triggering_entities.entity_refs {
+                    self.name(&$($m)? triggering.entity_ref);
+                }
+
+                match &$($m)? condition.entity_condition {
+                    EntityCondition::Collision(collision) => {
+                        if let Some(target) = &$($m)? collision.target {
+                            self.name(target);
+                        }
+                        if let Some(position) = &$($m)? collision.position {
+                            self.position(position);
+                        }
+                    }
+                    EntityCondition::TimeHeadway(headway) => self.name(&$($m)? headway.entity_ref),
+                    EntityCondition::TimeToCollision(ttc) => {
+                        if let Some(entity) = &$($m)? ttc.target.entity_ref {
+                            self.name(&$($m)? entity.entity_ref);
+                        }
+                        if let Some(position) = &$($m)? ttc.target.position {
+                            self.position(position);
+                        }
+                    }
+                    EntityCondition::Speed(speed) => self.name(&$($m)? speed.entity_ref),
+                    EntityCondition::RelativeSpeed(speed) => self.name(&$($m)? speed.entity_ref),
+                    EntityCondition::ReachPosition(reach) => self.position(&$($m)? reach.position),
+                    EntityCondition::Distance(distance) => self.position(&$($m)? distance.position),
+                    EntityCondition::RelativeDistance(distance) => self.name(&$($m)? distance.entity_ref),
+                    EntityCondition::RelativeClearance(clearance) => {
+                        for entity in &$($m)? clearance.entity_refs {
+                            self.name(&$($m)? entity.entity_ref);
+                        }
+                    }
+                    EntityCondition::RelativeAngle(angle) => self.name(&$($m)? angle.entity_ref),
+                    EntityCondition::EndOfRoad(_)
+                    | EntityCondition::Offroad(_)
+                    | EntityCondition::Acceleration(_)
+                    | EntityCondition::StandStill(_)
+                    | EntityCondition::TraveledDistance(_)
+                    | EntityCondition::Angle(_) => {}
+                }
+            }
+
+            fn position(&mut self, position: &$($m)? Position) {
+                if let Some(relative) = &$($m)? position.relative_world_position {
+                    self.name(&$($m)? relative.entity_ref);
+                }
+                if let Some(relative) = &$($m)? position.relative_object_position {
+                    self.name(&$($m)? relative.entity_ref);
+                }
+                if let Some(relative) = &$($m)? position.relative_road_position {
+                    self.name(&$($m)? relative.entity_ref);
+                }
+                if let Some(relative) = &$($m)? position.relative_lane_position {
+                    self.name(&$($m)? relative.entity_ref);
+                }
+            }
+        }
+    };
+}
+
+ref_walker!(RefVisitor, mut);
+ref_walker!(RefReader);
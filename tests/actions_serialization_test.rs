@@ -304,7 +304,7 @@ fn test_parameter_action_system() {
 
     let serialized = serde_json::to_string(&param_action).unwrap();
     assert!(serialized.contains("modifyParam"));
-    assert!(serialized.contains("ParameterAddValueRule"));
+    assert!(serialized.contains("AddValue"));
 
     // Test ParameterMultiplyByValueRule
     let multiply_rule = ParameterMultiplyByValueRule {
@@ -322,7 +322,7 @@ fn test_parameter_action_system() {
 
     let serialized = serde_json::to_string(&param_action).unwrap();
     assert!(serialized.contains("multiplyParam"));
-    assert!(serialized.contains("ParameterMultiplyByValueRule"));
+    assert!(serialized.contains("MultiplyByValue"));
 }
 
 #[test]
@@ -14,11 +14,12 @@ use openscenario_rs::parser::xml::{parse_catalog_from_str, serialize_catalog_to_
 use openscenario_rs::types::basic::{Directory, Value};
 use openscenario_rs::types::basic::{Double, OSString};
 use openscenario_rs::types::catalogs::{
-    entities::ParameterDefinition,
+    entities::{CatalogVehicle, ParameterDefinition},
     files::CatalogFile,
-    locations::{ControllerCatalogLocation, VehicleCatalogLocation},
+    locations::{CatalogLocations, ControllerCatalogLocation, VehicleCatalogLocation},
     references::{ParameterAssignment, VehicleCatalogReference},
 };
+use openscenario_rs::types::entities::{Entities, ScenarioObject};
 use std::collections::HashMap;
 use std::fs;
 
@@ -178,6 +179,219 @@ fn test_catalog_loader_with_temporary_files() {
     assert_eq!(catalog.vehicles()[0].name, "TempVehicle");
 }
 
+#[test]
+fn test_write_vehicle_catalog_roundtrip() {
+    let template = template_vehicle();
+    let mut sedan = template.clone();
+    sedan.name = "Sedan".to_string();
+    let mut truck = template;
+    truck.name = "Truck".to_string();
+    truck.vehicle_category = OSString::literal("truck".to_string());
+
+    let temp_dir = TempDir::new().unwrap();
+    let catalog_path = temp_dir.path().join("VehicleCatalog.xosc");
+    openscenario_rs::write_vehicle_catalog(&catalog_path, "VehicleCatalog", vec![sedan, truck])
+        .unwrap();
+
+    let catalog = openscenario_rs::parse_catalog_file(&catalog_path).unwrap();
+    assert_eq!(
+        catalog.catalog_name().as_literal().unwrap(),
+        "VehicleCatalog"
+    );
+    assert_eq!(catalog.vehicles().len(), 2);
+    assert_eq!(catalog.vehicles()[0].name, "Sedan");
+    assert_eq!(catalog.vehicles()[1].name, "Truck");
+    assert_eq!(
+        catalog.vehicles()[1].vehicle_category.as_literal().unwrap(),
+        "truck"
+    );
+    assert!(catalog.controllers().is_empty());
+}
+
+/// A catalog vehicle named "Car" to clone into generated catalogs
+fn template_vehicle() -> CatalogVehicle {
+    let template = parse_catalog_from_str(
+        r#"<?xml version="1.0"?>
+    <OpenSCENARIO>
+        <FileHeader author="Test" date="2024-01-01T00:00:00" description="Template" revMajor="1" revMinor="3"/>
+        <Catalog name="Template">
+            <Vehicle name="Car" vehicleCategory="car">
+                <BoundingBox>
+                    <Center x="1.4" y="0.0" z="0.9"/>
+                    <Dimensions width="2.0" length="5.0" height="1.8"/>
+                </BoundingBox>
+                <Performance maxSpeed="69" maxAcceleration="10" maxDeceleration="10"/>
+                <Axles>
+                    <FrontAxle maxSteering="0.5" wheelDiameter="0.6" trackWidth="1.8" positionX="3.1" positionZ="0.3"/>
+                    <RearAxle maxSteering="0.0" wheelDiameter="0.6" trackWidth="1.8" positionX="0.0" positionZ="0.3"/>
+                </Axles>
+            </Vehicle>
+        </Catalog>
+    </OpenSCENARIO>"#,
+    )
+    .unwrap();
+    template.vehicles()[0].clone()
+}
+
+#[test]
+fn test_repeated_resolution_in_large_catalog() {
+    let template = template_vehicle();
+    let vehicles = (0..500)
+        .map(|i| {
+            let mut vehicle = template.clone();
+            vehicle.name = format!("Car{}", i);
+            vehicle.performance.max_speed = Double::literal(i as f64);
+            vehicle
+        })
+        .collect();
+    let temp_dir = TempDir::new().unwrap();
+    openscenario_rs::write_vehicle_catalog(
+        temp_dir.path().join("VehicleCatalog.xosc"),
+        "VehicleCatalog",
+        vehicles,
+    )
+    .unwrap();
+
+    let mut manager = CatalogManager::new();
+    let location = VehicleCatalogLocation::from_path(temp_dir.path().to_string_lossy().to_string());
+    let reference =
+        VehicleCatalogReference::new("VehicleCatalog".to_string(), "Car421".to_string());
+
+    let first = manager
+        .resolve_vehicle_reference(&reference, &location)
+        .unwrap();
+    assert_eq!(first.entity.name.as_literal().unwrap(), "Car421");
+    assert_eq!(
+        first.entity.performance.max_speed.as_literal(),
+        Some(&421.0)
+    );
+    for _ in 0..1000 {
+        let again = manager
+            .resolve_vehicle_reference(&reference, &location)
+            .unwrap();
+        assert_eq!(again.entity, first.entity);
+        assert_eq!(again.metadata.catalog_path, first.metadata.catalog_path);
+    }
+
+    let missing = VehicleCatalogReference::new("VehicleCatalog".to_string(), "Car500".to_string());
+    let Err(err) = manager.resolve_vehicle_reference(&missing, &location) else {
+        panic!("Car500 is not in the catalog");
+    };
+    assert!(err.to_string().contains("Car500"));
+}
+
+#[test]
+fn test_validate_references() {
+    let temp_dir = TempDir::new().unwrap();
+    openscenario_rs::write_vehicle_catalog(
+        temp_dir.path().join("VehicleCatalog.xosc"),
+        "VehicleCatalog",
+        vec![template_vehicle()],
+    )
+    .unwrap();
+
+    let mut scenario = openscenario_rs::parse_file("tests/data/simple_scenario.xosc").unwrap();
+    let mut locations = CatalogLocations::new();
+    locations.vehicle_catalog = Some(VehicleCatalogLocation::from_path(
+        temp_dir.path().to_string_lossy().to_string(),
+    ));
+    scenario.catalog_locations = Some(locations);
+    scenario.entities = Some(Entities {
+        scenario_objects: vec![
+            ScenarioObject::new_vehicle_catalog_reference(
+                "Ego".to_string(),
+                VehicleCatalogReference::new("VehicleCatalog".to_string(), "Car".to_string()),
+            ),
+            ScenarioObject::new_vehicle_catalog_reference(
+                "Target".to_string(),
+                VehicleCatalogReference::new("VehicleCatalog".to_string(), "Truck".to_string()),
+            ),
+        ],
+    });
+
+    let mut manager = CatalogManager::new();
+    let unresolved = manager.validate_references(&scenario).unwrap();
+    assert_eq!(unresolved.len(), 1);
+    assert_eq!(unresolved[0].object_name, "Target");
+    assert_eq!(unresolved[0].catalog_name, "VehicleCatalog");
+    assert_eq!(unresolved[0].entry_name, "Truck");
+    assert!(unresolved[0].reason.contains("Truck"));
+
+    // Validating again reports the same failure rather than a circular dependency
+    assert_eq!(manager.validate_references(&scenario).unwrap(), unresolved);
+}
+
+#[test]
+fn test_resolved_entities_iterates_lazily() {
+    let temp_dir = TempDir::new().unwrap();
+    openscenario_rs::write_vehicle_catalog(
+        temp_dir.path().join("VehicleCatalog.xosc"),
+        "VehicleCatalog",
+        vec![template_vehicle()],
+    )
+    .unwrap();
+
+    let mut scenario = openscenario_rs::parse_file("tests/data/simple_scenario.xosc").unwrap();
+    let mut locations = CatalogLocations::new();
+    locations.vehicle_catalog = Some(VehicleCatalogLocation::from_path(
+        temp_dir.path().to_string_lossy().to_string(),
+    ));
+    scenario.entities = Some(Entities {
+        scenario_objects: vec![
+            ScenarioObject::new_vehicle_catalog_reference(
+                "Ego".to_string(),
+                VehicleCatalogReference::new("VehicleCatalog".to_string(), "Car".to_string()),
+            ),
+            ScenarioObject::new_vehicle_catalog_reference(
+                "Target".to_string(),
+                VehicleCatalogReference::new("VehicleCatalog".to_string(), "Truck".to_string()),
+            ),
+            ScenarioObject::new_vehicle_catalog_reference(
+                "Follower".to_string(),
+                VehicleCatalogReference::new("VehicleCatalog".to_string(), "Car".to_string()),
+            ),
+        ],
+    });
+
+    let mut manager = CatalogManager::new();
+    let mut resolved_names = Vec::new();
+    let mut failures = 0;
+    for resolved in manager.resolved_entities(&scenario, &locations) {
+        match resolved {
+            Ok(entity) => {
+                assert!(entity.object.entity_catalog_reference.is_none());
+                assert_eq!(
+                    entity
+                        .object
+                        .vehicle
+                        .as_ref()
+                        .unwrap()
+                        .name
+                        .as_literal()
+                        .unwrap(),
+                    "Car"
+                );
+                assert_eq!(entity.catalog_paths.len(), 1);
+                resolved_names.push(entity.object.name.to_string());
+            }
+            Err(err) => {
+                assert!(err.to_string().contains("Truck"));
+                failures += 1;
+            }
+        }
+    }
+    assert_eq!(resolved_names, vec!["Ego", "Follower"]);
+    assert_eq!(failures, 1);
+
+    // Only the first object is resolved when the iterator is not driven further
+    let first = manager
+        .resolved_entities(&scenario, &locations)
+        .next()
+        .unwrap()
+        .unwrap();
+    assert_eq!(first.object.name.as_literal().unwrap(), "Ego");
+}
+
 #[test]
 fn test_catalog_reference_creation() {
     // Test creating vehicle catalog references
@@ -208,6 +422,7 @@ fn test_catalog_reference_creation() {
             .parameter_assignments
             .as_ref()
             .unwrap()
+            .assignments
             .len(),
         2
     );
@@ -361,7 +576,12 @@ fn test_catalog_reference_with_many_parameters() {
     );
 
     assert_eq!(
-        vehicle_ref.parameter_assignments.as_ref().unwrap().len(),
+        vehicle_ref
+            .parameter_assignments
+            .as_ref()
+            .unwrap()
+            .assignments
+            .len(),
         50
     );
     assert_eq!(
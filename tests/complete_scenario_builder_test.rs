@@ -38,7 +38,7 @@ mod complete_scenario_tests {
     }
 
     #[test]
-    fn test_minimal_storyboard_creation() {
+    fn test_empty_storyboard_is_rejected() {
         use openscenario_rs::types::scenario::init::{Actions, Init};
 
         // Create truly empty init actions
@@ -49,17 +49,15 @@ mod complete_scenario_tests {
             },
         };
 
-        let scenario = ScenarioBuilder::new()
+        let result = ScenarioBuilder::new()
             .with_header("Test", "Author")
             .with_entities()
             .with_storyboard(|storyboard| storyboard.with_init_actions(empty_init))
-            .build()
-            .unwrap();
+            .build();
 
-        assert!(scenario.storyboard.is_some());
-        let storyboard = scenario.storyboard.unwrap();
-        assert_eq!(storyboard.stories.len(), 0);
-        assert!(storyboard.init.actions.global_actions.is_empty());
-        assert!(storyboard.init.actions.private_actions.is_empty());
+        let err = result.expect_err("storyboard without stories or init actions must fail");
+        let msg = err.to_string();
+        assert!(msg.contains("no stories and no init actions"));
+        assert!(msg.contains(".add_story()"));
     }
 }
@@ -204,3 +204,41 @@ fn test_acceleration_condition_all_directions() {
         assert_eq!(condition.direction, Some(direction));
     }
 }
+
+#[test]
+fn test_lateral_acceleration_condition_xml_roundtrip() {
+    let condition = AccelerationCondition::lateral(3.5, Rule::GreaterThan);
+
+    let xml = quick_xml::se::to_string(&condition).unwrap();
+    assert!(xml.contains(r#"direction="lateral""#));
+
+    let deserialized: AccelerationCondition = quick_xml::de::from_str(&xml).unwrap();
+    assert_eq!(condition, deserialized);
+    assert_eq!(deserialized.direction, Some(DirectionalDimension::Lateral));
+}
+
+#[test]
+fn test_lateral_acceleration_entity_condition_xml_roundtrip() {
+    let xml = r#"<ByEntityCondition>
+        <TriggeringEntities triggeringEntitiesRule="any">
+            <EntityRef entityRef="Ego"/>
+        </TriggeringEntities>
+        <EntityCondition>
+            <AccelerationCondition value="2.0" rule="greaterOrEqual" direction="lateral"/>
+        </EntityCondition>
+    </ByEntityCondition>"#;
+
+    let condition: ByEntityCondition = quick_xml::de::from_str(xml).unwrap();
+    match &condition.entity_condition {
+        EntityCondition::Acceleration(acc) => {
+            assert_eq!(acc.value, Double::literal(2.0));
+            assert_eq!(acc.rule, Rule::GreaterOrEqual);
+            assert_eq!(acc.direction, Some(DirectionalDimension::Lateral));
+        }
+        _ => panic!("Expected Acceleration variant"),
+    }
+
+    let serialized = quick_xml::se::to_string(&condition).unwrap();
+    let reparsed: ByEntityCondition = quick_xml::de::from_str(&serialized).unwrap();
+    assert_eq!(condition, reparsed);
+}
@@ -57,13 +57,11 @@ fn test_follow_trajectory_action_complete() {
     let trajectory_action = FollowTrajectoryAction {
         trajectory: Some(Trajectory::default()),
         catalog_reference: None,
-        time_reference: TimeReference {
-            timing: Timing {
-                domain_absolute_relative: OSString::literal("absolute".to_string()),
-                scale: Double::literal(1.0),
-                offset: Double::literal(0.0),
-            },
-        },
+        time_reference: TimeReference::with_timing(Timing {
+            domain_absolute_relative: OSString::literal("absolute".to_string()),
+            scale: Double::literal(1.0),
+            offset: Double::literal(0.0),
+        }),
         trajectory_ref: None,
         trajectory_following_mode: TrajectoryFollowingMode {
             following_mode: FollowingMode::Follow,
@@ -76,13 +74,11 @@ fn test_follow_trajectory_action_complete() {
     let time_ref_action = FollowTrajectoryAction {
         trajectory: None,
         catalog_reference: None,
-        time_reference: TimeReference {
-            timing: Timing {
-                domain_absolute_relative: OSString::literal("absolute".to_string()),
-                scale: Double::literal(1.0),
-                offset: Double::literal(0.0),
-            },
-        },
+        time_reference: TimeReference::with_timing(Timing {
+            domain_absolute_relative: OSString::literal("absolute".to_string()),
+            scale: Double::literal(1.0),
+            offset: Double::literal(0.0),
+        }),
         trajectory_ref: None,
         trajectory_following_mode: TrajectoryFollowingMode::default(),
         initial_distance_offset: None,
@@ -93,13 +89,11 @@ fn test_follow_trajectory_action_complete() {
     let traj_ref_action = FollowTrajectoryAction {
         trajectory: None,
         catalog_reference: None,
-        time_reference: TimeReference {
-            timing: Timing {
-                domain_absolute_relative: OSString::literal("absolute".to_string()),
-                scale: Double::literal(1.0),
-                offset: Double::literal(0.0),
-            },
-        },
+        time_reference: TimeReference::with_timing(Timing {
+            domain_absolute_relative: OSString::literal("absolute".to_string()),
+            scale: Double::literal(1.0),
+            offset: Double::literal(0.0),
+        }),
         trajectory_ref: Some(TrajectoryRef::default()),
         trajectory_following_mode: TrajectoryFollowingMode::default(),
         initial_distance_offset: None,
@@ -140,6 +134,7 @@ fn test_lane_change_action_serialization_fixes() {
         lane_change_action_dynamics: TransitionDynamics {
             dynamics_dimension: DynamicsDimension::Time,
             dynamics_shape: DynamicsShape::Linear,
+            following_mode: None,
             value: Double::literal(2.0),
         },
         lane_change_target: LaneChangeTarget::relative("Ego", -1),
@@ -156,6 +151,7 @@ fn test_lane_change_action_serialization_fixes() {
         lane_change_action_dynamics: TransitionDynamics {
             dynamics_dimension: DynamicsDimension::Time,
             dynamics_shape: DynamicsShape::Linear,
+            following_mode: None,
             value: Double::literal(2.0),
         },
         lane_change_target: LaneChangeTarget::relative("Ego", -1),
@@ -3006,7 +3006,7 @@ fn resolve_catalog_references_in_scenario(
             }
 
             // Check controller references
-            if let Some(object_controller) = &entity.object_controller {
+            for object_controller in &entity.object_controllers {
                 if let Some(controller_ref) = &object_controller.catalog_reference {
                     result.resolution_attempts += 1;
 